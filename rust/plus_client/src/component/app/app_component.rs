@@ -53,6 +53,10 @@ pub fn AppComponent() -> impl IntoView {
         create_rw_signal(WEB_CURRENCY_EXCHANGE.clone()),
     ));
 
+    // Replace the built in rates with those served once the app runs in the browser
+    #[cfg(feature = "hydrate")]
+    crate::refresh_currency_exchange(app_context.currency_exchange);
+
     provide_context(app_context);
 
     // ω <fn app_component>
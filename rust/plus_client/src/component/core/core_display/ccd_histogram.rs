@@ -10,6 +10,7 @@ use leptos::view;
 use leptos::IntoAttribute;
 use leptos::IntoView;
 use leptos::WriteSignal;
+use plus_modeled::ReturnDistribution;
 use rand::rngs::ThreadRng;
 use rand_distr::Distribution;
 use rand_distr::LogNormal;
@@ -18,7 +19,7 @@ use rand_distr::Normal;
 ////////////////////////////////////////////////////////////////////////////////////
 // --- enums ---
 ////////////////////////////////////////////////////////////////////////////////////
-/// Enumerates normal or log-normal
+/// Enumerates normal, log-normal or a modeled return distribution
 pub enum NormalBasedDist {
     /// A normal distribution
     NormalDist {
@@ -30,6 +31,11 @@ pub enum NormalBasedDist {
         /// Lognormal distribution
         distribution: LogNormal<f64>,
     },
+    /// A modeled return distribution, sampled by inverse transform
+    ReturnDist {
+        /// Return distribution
+        distribution: ReturnDistribution,
+    },
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
@@ -62,12 +68,14 @@ pub fn CcdHistogram(
     // α <fn ccd_histogram>
 
     use crate::utils::block_time::BlockTime;
+    use crate::AppContext;
     use crate::ClientCssClasses;
     use crate::HistogramComponent;
     use crate::SliderWithNumericInput;
     use leptos::create_local_resource;
     use leptos::create_rw_signal;
     use leptos::create_signal;
+    use leptos::expect_context;
     use leptos::IntoAttribute;
     use leptos::MaybeSignal;
     use leptos::Signal;
@@ -79,10 +87,12 @@ pub fn CcdHistogram(
     use plus_lookup::CurrencyValue;
     use plus_modeled::Currency;
     use plus_utils::HistogramEntry;
+    use std::rc::Rc;
 
-    let label_maker = |v: f64| {
+    let lang_selector = expect_context::<Rc<AppContext>>().lang_selector;
+    let label_maker = move |v: f64| {
         CurrencyValue::new(Currency::Usd, v.round())
-            .to_string()
+            .as_money(lang_selector.get())
             .into_view()
     };
 
@@ -229,6 +239,13 @@ impl NormalSeries {
             NormalBasedDist::LognormalDist { distribution } => {
                 distribution.sample(&mut self.thread_rng)
             }
+            NormalBasedDist::ReturnDist { distribution } => {
+                use rand::Rng;
+                use rand_distr::Open01;
+                distribution
+                    .sample(self.thread_rng.sample(Open01))
+                    .unwrap_or_default()
+            }
         }
 
         // ω <fn NormalSeries::next_value>
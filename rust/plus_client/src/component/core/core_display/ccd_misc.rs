@@ -14,7 +14,8 @@ use leptos::WriteSignal;
 // --- functions ---
 ////////////////////////////////////////////////////////////////////////////////////
 /// Miscellaneous components [NormalSpecComponent], [OkCancelComponent]
-/// [SliderWithNumericInput], [YearValueSeriesComponent]
+/// [SliderWithNumericInput], [YearValueSeriesComponent], [StressScenarioComponent],
+/// [CurrencyRiskComponent], [CurrencyExchangeComponent]
 ///
 ///   * **show_update** - Function to display state updates
 ///   * _return_ - View for ccd_misc
@@ -30,9 +31,12 @@ pub fn CcdMisc(
     // α <fn ccd_misc>
 
     use crate::AppContext;
+    use crate::CurrencyExchangeComponent;
+    use crate::CurrencyRiskComponent;
     use crate::NormalSpecComponent;
     use crate::OkCancelComponent;
     use crate::SliderWithNumericInput;
+    use crate::StressScenarioComponent;
     use crate::Updatable;
     use crate::YearValueSeriesComponent;
     use crate::YearValueSeriesType;
@@ -41,7 +45,21 @@ pub fn CcdMisc(
     use leptos::MaybeSignal;
     use leptos::SignalGetUntracked;
     use leptos::SignalSet;
+    use plus_lookup::CurrencyValue;
+    use plus_modeled::historic_returns::historic_correlations;
+    use plus_modeled::historic_returns::historic_normal_spec;
+    use plus_modeled::historic_returns::historic_years;
+    use plus_modeled::Currency;
+    use plus_modeled::CurrencyRiskSpec;
+    use plus_modeled::DistributionInstrument;
+    use plus_modeled::DossierCorrelationEntry;
+    use plus_modeled::DossierCorrelationMatrix;
+    use plus_modeled::DossierItemIndex;
+    use plus_modeled::HistoricSeries;
+    use plus_modeled::ItemIndex;
     use plus_modeled::NormalSpec;
+    use plus_modeled::ReturnDistribution;
+    use plus_modeled::YearRange;
     use plus_modeled::YearValue;
     use std::rc::Rc;
 
@@ -54,20 +72,53 @@ pub fn CcdMisc(
             .to_string(),
     );
 
+    // A 60/40 portfolio of worths with the historic returns of equities and bonds
+    let portfolio_series = [
+        HistoricSeries::UsLargeCapEquity,
+        HistoricSeries::UsTreasuryBond,
+    ];
+    let portfolio_items = (0..portfolio_series.len() as u32)
+        .map(|worth_index| DossierItemIndex {
+            item_index: Some(ItemIndex::WorthIndex(worth_index)),
+        })
+        .collect::<Vec<_>>();
+    let portfolio_normal_spec = historic_correlations(&portfolio_series, &historic_years())
+        .map(|correlations| {
+            DossierCorrelationMatrix::from_correlations(&portfolio_items, &correlations)
+        })
+        .and_then(|correlation_matrix| {
+            let holdings = portfolio_items
+                .iter()
+                .zip(portfolio_series.iter().zip([0.6, 0.4]))
+                .map(|(item, (series, weight))| {
+                    Some((
+                        *item,
+                        weight,
+                        historic_normal_spec(*series, &historic_years())?,
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            NormalSpec::portfolio_of_dossier_items(&holdings, &correlation_matrix)
+        });
+
     let normal_spec_example = move || {
         view! {
             <div>
                 <div class="title">"Normal Spec With Values"</div>
                 <div class="ccd-normal-spec">
-                    <NormalSpecComponent updatable=Updatable::new(
-                        Some(NormalSpec {
-                            mean: 0.1,
-                            std_dev: 0.2,
-                        }),
-                        move |ns: &Option<NormalSpec>| {
-                            show_update.set(format!("Normal Spec -> {ns:?}"))
-                        },
-                    )/>
+                    <NormalSpecComponent
+                        updatable=Updatable::new(
+                            Some(NormalSpec {
+                                mean: 0.1,
+                                std_dev: 0.2,
+                            }),
+                            move |ns: &Option<NormalSpec>| {
+                                show_update.set(format!("Normal Spec -> {ns:?}"))
+                            },
+                        )
+
+                        portfolio_normal_spec=portfolio_normal_spec.map(MaybeSignal::Static)
+                    />
                 </div>
             </div>
         }
@@ -191,12 +242,120 @@ pub fn CcdMisc(
         }
     };
 
+    let stress_scenario_example = move || {
+        view! {
+            <div>
+                <div class="title">"Stress Scenario 60/40 Spending 4%"</div>
+                <StressScenarioComponent
+                    holdings=MaybeSignal::Static(
+                        vec![
+                            (DistributionInstrument::Equity, 600_000.0),
+                            (DistributionInstrument::InterestBearing, 400_000.0),
+                        ],
+                    )
+
+                    annual_spending=MaybeSignal::Static(40_000.0)
+                    forecast_years=MaybeSignal::Static(YearRange {
+                        start: 2025,
+                        end: 2055,
+                    })
+                />
+
+            </div>
+        }
+    };
+
+    // Equities held in EUR, GBP and USD, correlated with each other and with their currencies
+    let currency_risk_specs = vec![
+        CurrencyRiskSpec {
+            currency: Currency::Eur,
+            drift: 0.0,
+            volatility: 0.09,
+        },
+        CurrencyRiskSpec {
+            currency: Currency::Gbp,
+            drift: 0.0,
+            volatility: 0.10,
+        },
+    ];
+    let foreign_equities = [Currency::Eur, Currency::Gbp, Currency::Usd]
+        .into_iter()
+        .zip([500_000.0, 300_000.0, 200_000.0])
+        .enumerate()
+        .map(|(worth_index, (currency, value))| {
+            (
+                DossierItemIndex {
+                    item_index: Some(ItemIndex::WorthIndex(worth_index as u32)),
+                },
+                CurrencyValue::new(currency, value),
+                ReturnDistribution::LogNormal {
+                    mean: 0.07,
+                    std_dev: 0.17,
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let currency_correlation =
+        |row_index: DossierItemIndex, column_index: DossierItemIndex, correlation: f64| {
+            DossierCorrelationEntry {
+                row_index: Some(row_index),
+                column_index: Some(column_index),
+                correlation,
+            }
+        };
+    let currency_correlation_matrix = DossierCorrelationMatrix {
+        mappings: vec![
+            currency_correlation(foreign_equities[0].0, foreign_equities[1].0, 0.8),
+            currency_correlation(foreign_equities[0].0, foreign_equities[2].0, 0.7),
+            currency_correlation(foreign_equities[1].0, foreign_equities[2].0, 0.7),
+            currency_correlation(
+                currency_risk_specs[0].dossier_item_index(),
+                currency_risk_specs[1].dossier_item_index(),
+                0.6,
+            ),
+            currency_correlation(
+                foreign_equities[0].0,
+                currency_risk_specs[0].dossier_item_index(),
+                0.2,
+            ),
+            currency_correlation(
+                foreign_equities[1].0,
+                currency_risk_specs[1].dossier_item_index(),
+                0.2,
+            ),
+        ],
+    };
+
+    let currency_risk_example = move || {
+        view! {
+            <div>
+                <div class="title">"Currency Risk EUR and GBP Holdings"</div>
+                <CurrencyRiskComponent
+                    holdings=MaybeSignal::Static(foreign_equities.clone())
+                    currency_risk_specs=MaybeSignal::Static(currency_risk_specs.clone())
+                    correlation_matrix=MaybeSignal::Static(currency_correlation_matrix.clone())
+                />
+
+            </div>
+        }
+    };
+
+    let currency_exchange_example = move || {
+        view! {
+            <div>
+                <div class="title">"Currency Exchange"</div>
+                <CurrencyExchangeComponent/>
+            </div>
+        }
+    };
+
     // ω <fn ccd_misc>
     view! {
         <div class=SELF_CLASS>
             // α <plus-cm-view>
 
             {normal_spec_example} {ok_cancel_example} {sliders_example} {rate_curve_examples}
+            {stress_scenario_example} {currency_risk_example} {currency_exchange_example}
 
         // ω <plus-cm-view>
         </div>
@@ -0,0 +1,187 @@
+//! Module for currency_exchange_component leptos function/component
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::AppContext;
+use leptos::component;
+use leptos::expect_context;
+use leptos::view;
+#[allow(unused_imports)]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::MaybeSignal;
+use leptos::SignalGet;
+use plus_lookup::CurrencyExchange;
+use plus_lookup::WEB_CURRENCY_EXCHANGE;
+use std::rc::Rc;
+use std::sync::Arc;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Component for editing the exchange rates of the app. Each supported currency shows
+/// its rate to USD, which may be overridden, and the inverse. Edits are written into
+/// [AppContext::currency_exchange] so converted amounts throughout the app update, and
+/// rates changed elsewhere, e.g. served rates arriving after hydration, are shown.
+/// A cross rate table shows the rates between a selected currency and all others.
+///
+///   * **default_exchange** - Rates restored on reset
+///   * _return_ - View for currency_exchange_component
+#[component]
+pub fn CurrencyExchangeComponent(
+    /// Rates restored on reset
+    #[prop(default=MaybeSignal::Static(WEB_CURRENCY_EXCHANGE.clone()))]
+    default_exchange: MaybeSignal<Arc<CurrencyExchange>>,
+) -> impl IntoView {
+    use plus_lookup::i18n::currency_exchange_component::*;
+    pub const SELF_CLASS: &str = "plus-cec";
+    let app_context = expect_context::<Rc<AppContext>>();
+    let lang_selector = app_context.lang_selector;
+    let i18n_exchange_rates = move || i18n_exchange_rates(lang_selector.get());
+    let i18n_currency = move || i18n_currency(lang_selector.get());
+    let i18n_to_usd = move || i18n_to_usd(lang_selector.get());
+    let i18n_from_usd = move || i18n_from_usd(lang_selector.get());
+    let i18n_reset = move || i18n_reset(lang_selector.get());
+    let i18n_cross_rates = move || i18n_cross_rates(lang_selector.get());
+    let component_id = crate::component_id!("`CurrencyExchangeComponent`");
+    #[cfg(debug_assertions)]
+    crate::log_component!(crate::COMPONENT_LOG_LEVEL, component_id);
+    // α <fn currency_exchange_component>
+
+    use crate::ClientCssClasses;
+    use crate::CurrencySelect;
+    use crate::NumericInput;
+    use crate::Updatable;
+    use leptos::create_effect;
+    use leptos::create_rw_signal;
+    use leptos::store_value;
+    use leptos::SignalGetUntracked;
+    use leptos::SignalSet;
+    use leptos::SignalUpdate;
+    use leptos::SignalWith;
+    use plus_lookup::currency_exchange::SUPPORTED_CURRENCIES;
+    use plus_modeled::Currency;
+
+    let currency_exchange = app_context.currency_exchange;
+    // Rates last written by the rate inputs, which show them already
+    let edited_exchange = store_value(None::<Arc<CurrencyExchange>>);
+    // Incremented when the rates change other than through the rate inputs, e.g. on reset
+    // or when served rates arrive, so the inputs are rebuilt showing the new rates
+    let rebuild_count = create_rw_signal(0_u32);
+    let cross_rate_base = create_rw_signal(Currency::Usd);
+
+    create_effect(move |_| {
+        let current_exchange = currency_exchange.get();
+        let is_edited = edited_exchange.with_value(|edited_exchange| {
+            edited_exchange
+                .as_ref()
+                .is_some_and(|edited_exchange| Arc::ptr_eq(edited_exchange, &current_exchange))
+        });
+        if !is_edited {
+            rebuild_count.update(|rebuild_count| *rebuild_count += 1);
+        }
+    });
+
+    let reset = move |_| currency_exchange.set(default_exchange.get_untracked());
+
+    let rate_inputs = move || {
+        rebuild_count.get();
+        SUPPORTED_CURRENCIES
+            .iter()
+            .filter(|currency| **currency != Currency::Usd)
+            .map(|currency| {
+                let currency = *currency;
+                let to_usd = currency_exchange.get_untracked().to_usd_rate(currency);
+                let from_usd = move || {
+                    currency_exchange.with(|currency_exchange| {
+                        format!("{:.4}", currency_exchange.from_usd_rate(currency))
+                    })
+                };
+                view! {
+                    <div class=ClientCssClasses::TxtRightPadLeft
+                        .as_str()>{currency.as_str_name()}</div>
+                    <NumericInput
+                        updatable=Updatable::new(
+                            Some(to_usd),
+                            move |to_usd: &Option<f64>| {
+                                if let Some(to_usd) = to_usd.filter(|to_usd| *to_usd > 0.0) {
+                                    let updated_exchange = Arc::new(
+                                        currency_exchange
+                                            .get_untracked()
+                                            .with_to_usd_rates(&[(currency, to_usd)]),
+                                    );
+                                    edited_exchange.set_value(Some(updated_exchange.clone()));
+                                    currency_exchange.set(updated_exchange);
+                                }
+                            },
+                        )
+
+                        non_negative=true
+                    />
+                    <div class=ClientCssClasses::TxtRightPadLeft.as_str()>{from_usd}</div>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let cross_rates = move || {
+        let base = cross_rate_base.get();
+        currency_exchange.with(|currency_exchange| {
+            SUPPORTED_CURRENCIES
+                .iter()
+                .filter(|currency| **currency != base)
+                .map(|currency| {
+                    let from_base = currency_exchange.exchange_currency_rate(base, *currency);
+                    let to_base = currency_exchange.exchange_currency_rate(*currency, base);
+                    view! {
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>{currency.as_str_name()}</div>
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>{format!("{from_base:.4}")}</div>
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>{format!("{to_base:.4}")}</div>
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let base_name = move || cross_rate_base.get().as_str_name();
+
+    // ω <fn currency_exchange_component>
+    view! {
+        <div class=SELF_CLASS>
+            // α <plus-cec-view>
+
+            <div class=ClientCssClasses::GridLblCtnr.as_str()>
+                <h4>{i18n_exchange_rates}</h4>
+            </div>
+            <div class=ClientCssClasses::HeaderRight.as_str()>{i18n_currency}</div>
+            <div class=ClientCssClasses::HeaderRight.as_str()>{i18n_to_usd}</div>
+            <div class=ClientCssClasses::HeaderRight.as_str()>{i18n_from_usd}</div>
+            {rate_inputs}
+            <div class=ClientCssClasses::GridLblCtnr.as_str()>
+                <button on:click=reset>{i18n_reset}</button>
+            </div>
+            <div class=ClientCssClasses::GridLblCtnr.as_str()>
+                <h4>{i18n_cross_rates}</h4>
+                <CurrencySelect updatable=Updatable::new(
+                    Currency::Usd,
+                    move |currency: &Currency| cross_rate_base.set(*currency),
+                )/>
+            </div>
+            <div class=ClientCssClasses::HeaderRight.as_str()>{i18n_currency}</div>
+            <div class=ClientCssClasses::HeaderRight
+                .as_str()>{move || format!("{} →", base_name())}</div>
+            <div class=ClientCssClasses::HeaderRight
+                .as_str()>{move || format!("→ {}", base_name())}</div>
+            {cross_rates}
+
+        // ω <plus-cec-view>
+        </div>
+    }
+}
+
+// α <mod-def currency_exchange_component>
+// ω <mod-def currency_exchange_component>
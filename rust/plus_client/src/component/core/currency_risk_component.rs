@@ -0,0 +1,197 @@
+//! Module for currency_risk_component leptos function/component
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::AppContext;
+use leptos::component;
+use leptos::expect_context;
+use leptos::view;
+#[allow(unused_imports)]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::MaybeSignal;
+use leptos::SignalGet;
+use plus_lookup::CurrencyValue;
+use plus_modeled::CurrencyRiskSpec;
+use plus_modeled::DossierCorrelationMatrix;
+use plus_modeled::DossierItemIndex;
+use plus_modeled::ReturnDistribution;
+use std::rc::Rc;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Component showing the risk exchange rates pose to holdings in currencies other than
+/// the display currency. Exchange rates are simulated jointly with the returns of the
+/// holdings and the holdings valued in the display currency at the end of each run,
+/// reported at percentiles.
+///
+///   * **holdings** - Item, value in its own currency and annual return distribution of each holding
+///   * **currency_risk_specs** - Exchange rate risk of each currency, including the display currency if not USD
+///   * **correlation_matrix** - Correlations between the holdings and with the currencies
+///   * **num_years** - Number of years simulated
+///   * **run_count** - Number of simulated runs
+///   * **percentiles** - Percentiles of the display currency value to report
+///   * _return_ - View for currency_risk_component
+#[component]
+pub fn CurrencyRiskComponent(
+    /// Item, value in its own currency and annual return distribution of each holding
+    holdings: MaybeSignal<Vec<(DossierItemIndex, CurrencyValue, ReturnDistribution)>>,
+    /// Exchange rate risk of each currency, including the display currency if not USD
+    currency_risk_specs: MaybeSignal<Vec<CurrencyRiskSpec>>,
+    /// Correlations between the holdings and with the currencies
+    #[prop(default=MaybeSignal::Static(DossierCorrelationMatrix::default()))]
+    correlation_matrix: MaybeSignal<DossierCorrelationMatrix>,
+    /// Number of years simulated
+    #[prop(default=MaybeSignal::Static(10))]
+    num_years: MaybeSignal<usize>,
+    /// Number of simulated runs
+    #[prop(default=MaybeSignal::Static(1_000))]
+    run_count: MaybeSignal<usize>,
+    /// Percentiles of the display currency value to report
+    #[prop(default=MaybeSignal::Static(vec![0.05, 0.25, 0.5, 0.75, 0.95]))]
+    percentiles: MaybeSignal<Vec<f64>>,
+) -> impl IntoView {
+    use plus_lookup::i18n::currency_risk_component::*;
+    pub const SELF_CLASS: &str = "plus-crc";
+    let app_context = expect_context::<Rc<AppContext>>();
+    let lang_selector = app_context.lang_selector;
+    let i18n_currency_risk = move || i18n_currency_risk(lang_selector.get());
+    let i18n_today = move || i18n_today(lang_selector.get());
+    let i18n_percentile = move || i18n_percentile(lang_selector.get());
+    let i18n_value = move || i18n_value(lang_selector.get());
+    let component_id = crate::component_id!("`CurrencyRiskComponent`");
+    #[cfg(debug_assertions)]
+    crate::log_component!(crate::COMPONENT_LOG_LEVEL, component_id);
+    // α <fn currency_risk_component>
+
+    use crate::scale_by;
+    use crate::ClientCssClasses;
+    use leptos::create_memo;
+    use leptos::For;
+    use leptos::SignalWith;
+    use plus_modeled::CurrencyRiskSimulator;
+    use rand::Rng;
+    use rand_distr::StandardNormal;
+
+    let display_currency = app_context.display_currency;
+    let currency_exchange = app_context.currency_exchange;
+
+    let today = move || {
+        holdings.with(|holdings| {
+            currency_exchange.get().total_in_currency(
+                &holdings
+                    .iter()
+                    .map(|(_, value, _)| *value)
+                    .collect::<Vec<_>>(),
+                display_currency.get(),
+            )
+        })
+    };
+
+    // Display currency value of the holdings at the end of each run, sorted
+    let simulated_values = create_memo(move |_| {
+        let display_currency = display_currency.get();
+        let currency_exchange = currency_exchange.get();
+        let num_years = num_years.get();
+        let holdings = holdings.get();
+        let items = holdings
+            .iter()
+            .map(|(item, _, _)| *item)
+            .collect::<Vec<_>>();
+        let currency_risk_simulator = currency_risk_specs.with(|currency_risk_specs| {
+            correlation_matrix.with(|correlation_matrix| {
+                CurrencyRiskSimulator::new(currency_risk_specs, &items, correlation_matrix)
+            })
+        });
+
+        let mut values = match currency_risk_simulator {
+            Some(currency_risk_simulator) if num_years > 0 => {
+                let mut thread_rng = rand::thread_rng();
+                (0..run_count.get())
+                    .filter_map(|_| {
+                        let (shocks_by_year, exchange_path) = currency_exchange
+                            .sample_exchange_path(&currency_risk_simulator, num_years, || {
+                                thread_rng.sample(StandardNormal)
+                            })?;
+                        // Each holding grows in its own currency with its correlated shocks
+                        let final_values = holdings
+                            .iter()
+                            .enumerate()
+                            .map(|(i, (_, value, return_distribution))| {
+                                shocks_by_year
+                                    .iter()
+                                    .try_fold(value.value, |value, shocks| {
+                                        Some(
+                                            value
+                                                * (1.0
+                                                    + return_distribution
+                                                        .sample_shock(shocks[i])?),
+                                        )
+                                    })
+                                    .map(|final_value| {
+                                        CurrencyValue::new(value.currency, final_value)
+                                    })
+                            })
+                            .collect::<Option<Vec<_>>>()?;
+                        exchange_path.last().map(|final_exchange| {
+                            final_exchange
+                                .total_in_currency(&final_values, display_currency)
+                                .value
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            }
+            _ => Vec::new(),
+        };
+        values.sort_by(|a, b| a.total_cmp(b));
+        values
+    });
+
+    let value_at_percentile = move |percentile: f64| {
+        simulated_values.with(|values| {
+            if values.is_empty() {
+                String::default()
+            } else {
+                let index = ((percentile * values.len() as f64) as usize).min(values.len() - 1);
+                CurrencyValue::new(display_currency.get(), values[index])
+                    .as_money(lang_selector.get())
+            }
+        })
+    };
+
+    // ω <fn currency_risk_component>
+    view! {
+        <div class=SELF_CLASS>
+            // α <plus-crc-view>
+
+            <div class=ClientCssClasses::GridLblCtnr.as_str()>
+                <h4>{i18n_currency_risk}</h4>
+            </div>
+            <div class=ClientCssClasses::TxtRightPadLeft.as_str()>{i18n_today}</div>
+            <div class=ClientCssClasses::TxtRightPadLeft
+                .as_str()>{move || today().as_money(lang_selector.get())}</div>
+            <div class=ClientCssClasses::HeaderRight.as_str()>{i18n_percentile}</div>
+            <div class=ClientCssClasses::HeaderRight
+                .as_str()>{move || format!("{} (+{})", i18n_value(), num_years.get())}</div>
+            <For
+                each=move || percentiles.get()
+                key=|percentile| { format!("{percentile:?}") }
+                children=move |percentile| {
+                    view! {
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>{move || format!("{:.0}%", scale_by(percentile, 2))}</div>
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>{move || value_at_percentile(percentile)}</div>
+                    }
+                }
+            />
+
+        // ω <plus-crc-view>
+        </div>
+    }
+}
+
+// α <mod-def currency_risk_component>
+// ω <mod-def currency_risk_component>
@@ -31,6 +31,7 @@ use plus_utils::HistogramSpans;
 /// The table captures basic statistic points (min, q1, q2,...).
 /// This allows adding specific id points to the table where the
 /// `u32` represents the id and the [String] is the text.
+///   * **value_at_risk_confidence** - Confidence level of the _historical_ VaR and CVaR rows in the table
 ///   * _return_ - View for histogram_component
 #[component]
 pub fn HistogramComponent<LLM, TLM>(
@@ -55,6 +56,9 @@ pub fn HistogramComponent<LLM, TLM>(
     /// `u32` represents the id and the [String] is the text.
     #[prop(default=Vec::new())]
     descriptive_points: Vec<(u32, String)>,
+    /// Confidence level of the _historical_ VaR and CVaR rows in the table
+    #[prop(default = 0.95)]
+    value_at_risk_confidence: f64,
 ) -> impl IntoView
 where
     LLM: Fn(f64) -> View + Copy + 'static,
@@ -176,6 +180,8 @@ where
             histogram_plot_stored_value.with_value({
                 |histogram_plot| {
                     use plus_utils::DescriptivePoint;
+                    let confidence_pct = format!("{:.0}%", value_at_risk_confidence * 100.0);
+                    let value_at_risk_label = format!("VaR {confidence_pct}");
                     let mut rows = vec![
                         (
                             "Min",
@@ -201,6 +207,12 @@ where
                             "Mean",
                             histogram_plot.get_descriptive_point(DescriptivePoint::Mean),
                         ),
+                        (
+                            value_at_risk_label.as_str(),
+                            histogram_plot.get_descriptive_point(DescriptivePoint::ValueAtRisk(
+                                value_at_risk_confidence,
+                            )),
+                        ),
                     ];
 
                     for (index, label) in descriptive_points.iter() {
@@ -241,6 +253,22 @@ where
                         })
                         .collect::<Vec<_>>();
 
+                    // CVaR is the mean of the tail, not a plotted point
+                    let conditional_value_at_risk_row = histogram_plot
+                        .get_value_at_risk(value_at_risk_confidence)
+                        .map(|value_at_risk| {
+                            view! {
+                                <tr>
+                                    <td>{format!("CVaR {confidence_pct}")}</td>
+                                    <td class="numeric"></td>
+                                    <td class="numeric"></td>
+                                    <td class="numeric">
+                                        {table_label_maker(-value_at_risk.conditional_value_at_risk)}
+                                    </td>
+                                </tr>
+                            }
+                        });
+
                     view! {
                         <table class="content-table">
                             <caption class="tbl-caption">"Descriptive Distribution Points"</caption>
@@ -252,7 +280,7 @@ where
                                     <th class="numeric">"Value"</th>
                                 </tr>
                             </thead>
-                            <tbody>{rows} {selected_point_row}</tbody>
+                            <tbody>{rows} {conditional_value_at_risk_row} {selected_point_row}</tbody>
                         </table>
                     }
                 }
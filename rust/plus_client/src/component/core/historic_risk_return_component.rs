@@ -3,6 +3,7 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use crate::utils::historic_risk_return::HISTORIC_EFFICIENT_FRONTIER;
 use crate::AppContext;
 use leptos::component;
 use leptos::expect_context;
@@ -22,16 +23,25 @@ use std::rc::Rc;
 /// various historic `(risk/return)` values for equities, bonds, ...
 ///
 ///   * **normal_spec** - The normal to plot
+///   * **portfolio_normal_spec** - Combined return of the user's portfolio, plotted as its own point
+///   * **efficient_frontier** - Points of the efficient frontier, drawn as a curve; defaults to that of the historic values
 ///   * _return_ - View for historic_risk_return_component
 #[component]
 pub fn HistoricRiskReturnComponent(
     /// The normal to plot
     normal_spec: MaybeSignal<NormalSpec>,
+    /// Combined return of the user's portfolio, plotted as its own point
+    #[prop(default=None)]
+    portfolio_normal_spec: Option<MaybeSignal<NormalSpec>>,
+    /// Points of the efficient frontier, drawn as a curve; defaults to that of the historic values
+    #[prop(default=MaybeSignal::Static(HISTORIC_EFFICIENT_FRONTIER.clone()))]
+    efficient_frontier: MaybeSignal<Vec<NormalSpec>>,
 ) -> impl IntoView {
     use plus_lookup::i18n::historic_risk_return_component::*;
     pub const SELF_CLASS: &str = "plus-hrrc";
     let lang_selector = expect_context::<Rc<AppContext>>().lang_selector;
     let i18n_holding_type = move || i18n_holding_type(lang_selector.get());
+    let i18n_portfolio = move || i18n_portfolio(lang_selector.get());
     let component_id = crate::component_id!("`HistoricRiskReturnComponent`");
     #[cfg(debug_assertions)]
     crate::log_component!(crate::COMPONENT_LOG_LEVEL, component_id);
@@ -43,8 +53,44 @@ pub fn HistoricRiskReturnComponent(
     use leptos::For;
     use leptos::SignalWith;
 
-    let plot =
-        move || normal_spec.with(|ns| ns.get_historic_plot(&*HISTORIC_RISK_RETURN_SAMPLES, false));
+    let portfolio_legend_spec = portfolio_normal_spec.clone();
+    let plot = move || {
+        let portfolio = portfolio_normal_spec
+            .as_ref()
+            .map(|portfolio_normal_spec| portfolio_normal_spec.get());
+        let portfolio_label = i18n_portfolio();
+        efficient_frontier.with(|efficient_frontier| {
+            normal_spec.with(|ns| {
+                ns.get_historic_plot(
+                    &*HISTORIC_RISK_RETURN_SAMPLES,
+                    false,
+                    portfolio
+                        .as_ref()
+                        .map(|portfolio| (portfolio_label.as_str(), portfolio)),
+                    efficient_frontier,
+                )
+            })
+        })
+    };
+
+    let portfolio_legend = move || {
+        portfolio_legend_spec.as_ref().map(|portfolio_normal_spec| {
+            view! {
+                <div class=ClientCssClasses::HrrcCtnr.as_str()>
+                    <div
+                        class=ClientCssClasses::HrrcDot.as_str()
+                        style="background-color: rgb(0, 0, 0);"
+                    ></div>
+                    <div class=ClientCssClasses::HrrcLblCtnr.as_str()>
+                        <div class=ClientCssClasses::HrrcLbl
+                            .as_str()>
+                            {format!("{} - {}", i18n_portfolio(), portfolio_normal_spec.get())}
+                        </div>
+                    </div>
+                </div>
+            }
+        })
+    };
 
     // ω <fn historic_risk_return_component>
     view! {
@@ -83,6 +129,7 @@ pub fn HistoricRiskReturnComponent(
                         }
                     }
                 />
+                {portfolio_legend}
 
             </div>
             <div class=ClientCssClasses::HrrcPlot.as_str() inner_html=plot></div>
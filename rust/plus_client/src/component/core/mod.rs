@@ -8,6 +8,8 @@ pub mod collection_grid_component;
 pub mod core_component_display;
 pub mod core_display;
 pub mod css_show;
+pub mod currency_exchange_component;
+pub mod currency_risk_component;
 pub mod currency_select;
 pub mod date_input;
 pub mod dispose_test;
@@ -26,6 +28,7 @@ pub mod ok_cancel_component;
 pub mod one_of_component;
 pub mod percent_input;
 pub mod slider_with_numeric_input;
+pub mod stress_scenario_component;
 pub mod toggle_image_button;
 pub mod toggle_image_button_control;
 pub mod year_currency_value_input;
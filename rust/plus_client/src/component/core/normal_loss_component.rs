@@ -22,6 +22,7 @@ use std::rc::Rc;
 ///
 ///   * **normal_spec** - The normal to plot
 ///   * **loss_vec** - Vector of returns to report probability of loss
+///   * **value_at_risk_confidences** - Confidence levels to report VaR and CVaR for
 ///   * _return_ - View for normal_loss_component
 #[component]
 pub fn NormalLossComponent(
@@ -30,6 +31,9 @@ pub fn NormalLossComponent(
     /// Vector of returns to report probability of loss
     #[prop(default=MaybeSignal::Static(vec![0.7, 0.3, 0.1, 0.05, 0.01, 0.0, -0.01, -0.05, -0.1, -0.3, -0.7]))]
     loss_vec: MaybeSignal<Vec<f64>>,
+    /// Confidence levels to report VaR and CVaR for
+    #[prop(default=MaybeSignal::Static(vec![0.95, 0.99]))]
+    value_at_risk_confidences: MaybeSignal<Vec<f64>>,
 ) -> impl IntoView {
     use plus_lookup::i18n::normal_loss_component::*;
     pub const SELF_CLASS: &str = "plus-nlc";
@@ -40,6 +44,9 @@ pub fn NormalLossComponent(
     let i18n_cdf_sample = move || i18n_cdf_sample(lang_selector.get());
     let i18n_gain_prefix = move || i18n_gain_prefix(lang_selector.get());
     let i18n_loss_table = move || i18n_loss_table(lang_selector.get());
+    let i18n_value_at_risk = move || i18n_value_at_risk(lang_selector.get());
+    let i18n_conditional_value_at_risk =
+        move || i18n_conditional_value_at_risk(lang_selector.get());
     let component_id = crate::component_id!("`NormalLossComponent`");
     #[cfg(debug_assertions)]
     crate::log_component!(crate::COMPONENT_LOG_LEVEL, component_id);
@@ -58,9 +65,8 @@ pub fn NormalLossComponent(
     use leptos::SignalWith;
     use leptos::SignalWithUntracked;
 
-    let (sample_loss_read, sample_loss_write) = create_signal(
-        normal_spec.with_untracked(|normal_spec| normal_spec.cdf_sigmoid_approx(0.0)),
-    );
+    let (sample_loss_read, sample_loss_write) =
+        create_signal(normal_spec.with_untracked(|normal_spec| normal_spec.cdf(0.0)));
     let sample_loss_updatable = Updatable::new(Some(0.0), move |loss| {
         sample_loss_write.update(|sample_loss| *sample_loss = *loss);
     });
@@ -96,7 +102,7 @@ pub fn NormalLossComponent(
                                 normal_spec
                                     .with(|normal_spec| {
                                         normal_spec
-                                            .cdf_sigmoid_approx(cdf_input)
+                                            .cdf(cdf_input)
                                             .map_or_else(
                                                 || String::default(),
                                                 |loss| format!("{:.2}%", scale_by(loss, 2)),
@@ -109,6 +115,71 @@ pub fn NormalLossComponent(
                 }
             />
 
+            <div>
+                <hr/>
+            </div>
+            <div>
+                <hr/>
+            </div>
+            <For
+                each=move || value_at_risk_confidences.get()
+                key=|item| { format!("{item:?}") }
+                children=move |confidence| {
+                    let value_at_risk = move || {
+                        normal_spec
+                            .with(|normal_spec| {
+                                plus_utils::normal_value_at_risk(
+                                    confidence,
+                                    normal_spec.mean,
+                                    normal_spec.std_dev,
+                                )
+                            })
+                    };
+                    let confidence_pct = format!("({:.0}%)", scale_by(confidence, 2));
+                    let cvar_confidence_pct = confidence_pct.clone();
+                    view! {
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>{move || format!("{} {}", i18n_value_at_risk(), confidence_pct)}</div>
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>
+                            {move || {
+                                value_at_risk()
+                                    .map_or_else(
+                                        || String::default(),
+                                        |value_at_risk| {
+                                            format!("{:.2}%", scale_by(-value_at_risk.value_at_risk, 2))
+                                        },
+                                    )
+                            }}
+
+                        </div>
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>
+                            {move || {
+                                format!("{} {}", i18n_conditional_value_at_risk(), cvar_confidence_pct)
+                            }}
+
+                        </div>
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>
+                            {move || {
+                                value_at_risk()
+                                    .map_or_else(
+                                        || String::default(),
+                                        |value_at_risk| {
+                                            format!(
+                                                "{:.2}%",
+                                                scale_by(-value_at_risk.conditional_value_at_risk, 2)
+                                            )
+                                        },
+                                    )
+                            }}
+
+                        </div>
+                    }
+                }
+            />
+
             <div>
                 <hr/>
             </div>
@@ -138,7 +209,7 @@ pub fn NormalLossComponent(
                                 .map(|loss| {
                                     let loss = scale_by(loss, -2);
                                     normal_spec
-                                        .cdf_sigmoid_approx(loss)
+                                        .cdf(loss)
                                         .map_or_else(
                                             || String::default(),
                                             |probability| {
@@ -3,6 +3,7 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use crate::utils::historic_risk_return::HISTORIC_EFFICIENT_FRONTIER;
 use crate::AppContext;
 use crate::Modification;
 use crate::NumericInput;
@@ -13,6 +14,7 @@ use leptos::view;
 #[allow(unused_imports)]
 use leptos::IntoAttribute;
 use leptos::IntoView;
+use leptos::MaybeSignal;
 use leptos::SignalGet;
 use plus_modeled::core::NormalSpec;
 use std::rc::Rc;
@@ -24,6 +26,8 @@ use std::rc::Rc;
 ///
 ///   * **updatable** - The normal spec being edited
 ///   * **non_negative_mean** - If set, negative values are disallowed for the mean.
+///   * **portfolio_normal_spec** - Combined return of the user's portfolio, shown among the historic values
+///   * **efficient_frontier** - Points of the efficient frontier, shown among the historic values; defaults to that of the historic values
 ///   * _return_ - View for normal_spec_component
 #[component]
 pub fn NormalSpecComponent(
@@ -32,6 +36,12 @@ pub fn NormalSpecComponent(
     /// If set, negative values are disallowed for the mean.
     #[prop(default = false)]
     non_negative_mean: bool,
+    /// Combined return of the user's portfolio, shown among the historic values
+    #[prop(default=None)]
+    portfolio_normal_spec: Option<MaybeSignal<NormalSpec>>,
+    /// Points of the efficient frontier, shown among the historic values; defaults to that of the historic values
+    #[prop(default=MaybeSignal::Static(HISTORIC_EFFICIENT_FRONTIER.clone()))]
+    efficient_frontier: MaybeSignal<Vec<NormalSpec>>,
 ) -> impl IntoView {
     use plus_lookup::i18n::normal_spec_component::*;
     pub const SELF_CLASS: &str = "plus-nsc";
@@ -52,7 +62,6 @@ pub fn NormalSpecComponent(
     use crate::NormalLossComponent;
     use leptos::create_signal;
     use leptos::store_value;
-    use leptos::MaybeSignal;
     use leptos::Show;
     use leptos::Signal;
     use leptos::SignalUpdate;
@@ -82,6 +91,8 @@ pub fn NormalSpecComponent(
     let (spec_signal, set_spec_signal) =
         create_signal(updatable.value.as_ref().map(|ns| *ns).unwrap_or_default());
 
+    let portfolio_normal_spec = store_value(portfolio_normal_spec);
+    let efficient_frontier = store_value(efficient_frontier);
     let normal_bits_stored_value = store_value(NormalBits {
         mean: initial_mean,
         std_dev: initial_std_dev,
@@ -234,9 +245,11 @@ pub fn NormalSpecComponent(
 
                                         fallback=|| ()
                                     >
-                                        <HistoricRiskReturnComponent normal_spec=MaybeSignal::Dynamic(
-                                            spec_signal.into(),
-                                        )/>
+                                        <HistoricRiskReturnComponent
+                                            normal_spec=MaybeSignal::Dynamic(spec_signal.into())
+                                            portfolio_normal_spec=portfolio_normal_spec.get_value()
+                                            efficient_frontier=efficient_frontier.get_value()
+                                        />
                                     </Show>
                                     <Show
                                         when=move || {
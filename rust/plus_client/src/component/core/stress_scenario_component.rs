@@ -0,0 +1,156 @@
+//! Module for stress_scenario_component leptos function/component
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::AppContext;
+use leptos::component;
+use leptos::expect_context;
+use leptos::view;
+#[allow(unused_imports)]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::MaybeSignal;
+use leptos::SignalGet;
+use plus_modeled::DistributionInstrument;
+use plus_modeled::YearRange;
+use std::rc::Rc;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Component listing historic stress scenarios by name and showing whether, and when,
+/// a plan fails replaying the selected one from a chosen forecast year.
+///
+///   * **holdings** - Instrument and starting value of each holding
+///   * **annual_spending** - Spending in the first forecast year, grown by replayed inflation
+///   * **forecast_years** - Years of the forecast
+///   * _return_ - View for stress_scenario_component
+#[component]
+pub fn StressScenarioComponent(
+    /// Instrument and starting value of each holding
+    holdings: MaybeSignal<Vec<(DistributionInstrument, f64)>>,
+    /// Spending in the first forecast year, grown by replayed inflation
+    annual_spending: MaybeSignal<f64>,
+    /// Years of the forecast
+    forecast_years: MaybeSignal<YearRange>,
+) -> impl IntoView {
+    use plus_lookup::i18n::stress_scenario_component::*;
+    pub const SELF_CLASS: &str = "plus-ssc";
+    let lang_selector = expect_context::<Rc<AppContext>>().lang_selector;
+    let i18n_scenario = move || i18n_scenario(lang_selector.get());
+    let i18n_start_year = move || i18n_start_year(lang_selector.get());
+    let i18n_survives = move || i18n_survives(lang_selector.get());
+    let i18n_fails_in = move || i18n_fails_in(lang_selector.get());
+    let i18n_year = move || i18n_year(lang_selector.get());
+    let i18n_balance = move || i18n_balance(lang_selector.get());
+    let component_id = crate::component_id!("`StressScenarioComponent`");
+    #[cfg(debug_assertions)]
+    crate::log_component!(crate::COMPONENT_LOG_LEVEL, component_id);
+    // α <fn stress_scenario_component>
+
+    use crate::ClientCssClasses;
+    use crate::EnumSelect;
+    use crate::Updatable;
+    use crate::YearInput;
+    use leptos::create_memo;
+    use leptos::create_rw_signal;
+    use leptos::For;
+    use leptos::Signal;
+    use leptos::SignalGetUntracked;
+    use leptos::SignalSet;
+    use leptos::SignalWith;
+    use leptos::SignalWithUntracked;
+    use plus_lookup::I18nEnums;
+    use plus_modeled::StressScenario;
+    use plus_utils::commify_int;
+
+    let initial_scenario = StressScenario::GreatDepression;
+    let initial_start_year = forecast_years.with_untracked(|forecast_years| forecast_years.start);
+    let scenario = create_rw_signal(initial_scenario);
+    let start_year = create_rw_signal(initial_start_year);
+
+    let outcome = create_memo(move |_| {
+        holdings.with(|holdings| {
+            forecast_years.with(|forecast_years| {
+                scenario.get().replay(
+                    holdings,
+                    annual_spending.get(),
+                    forecast_years,
+                    start_year.get(),
+                )
+            })
+        })
+    });
+
+    let scenario_updatable = Updatable::new(initial_scenario, move |selected| {
+        scenario.set(*selected);
+    });
+    let start_year_updatable = Updatable::new(Some(initial_start_year), move |year| {
+        if let Some(year) = year {
+            start_year.set(*year);
+        }
+    });
+
+    // ω <fn stress_scenario_component>
+    view! {
+        <div class=SELF_CLASS>
+            // α <plus-ssc-view>
+
+            <div class=ClientCssClasses::GridLblCtnr.as_str()>
+                <h4>{i18n_scenario}</h4>
+            </div>
+            <div class=ClientCssClasses::GridLblCtnr.as_str()>
+                <EnumSelect
+                    updatable=scenario_updatable
+                    column_count=1
+                    label=Some(
+                        Box::new(move |scenario: &StressScenario| {
+                            I18nEnums::StressScenario(lang_selector.get_untracked(), scenario)
+                                .to_string()
+                        }),
+                    )
+
+                />
+            </div>
+            <div>{i18n_start_year}</div>
+            <div>
+                <YearInput
+                    updatable=start_year_updatable
+                    year_range=forecast_years.get_untracked()
+                    placeholder=Signal::derive(i18n_start_year)
+                />
+            </div>
+            <div class=ClientCssClasses::GridLblCtnr.as_str()>
+                <h4>
+                    {move || {
+                        outcome
+                            .with(|outcome| match outcome.first_insolvency {
+                                Some(year) => format!("{} {year}", i18n_fails_in()),
+                                None => i18n_survives(),
+                            })
+                    }}
+
+                </h4>
+            </div>
+            <div class=ClientCssClasses::HeaderRight.as_str()>{i18n_year}</div>
+            <div class=ClientCssClasses::HeaderRight.as_str()>{i18n_balance}</div>
+            <For
+                each=move || outcome.with(|outcome| outcome.balances.clone())
+                key=|year_value| { format!("{year_value:?}") }
+                children=move |year_value| {
+                    view! {
+                        <div class=ClientCssClasses::TxtRightPadLeft.as_str()>{year_value.year}</div>
+                        <div class=ClientCssClasses::TxtRightPadLeft
+                            .as_str()>{commify_int(year_value.value.round() as i64)}</div>
+                    }
+                }
+            />
+
+        // ω <plus-ssc-view>
+        </div>
+    }
+}
+
+// α <mod-def stress_scenario_component>
+// ω <mod-def stress_scenario_component>
@@ -50,6 +50,8 @@ pub enum ClientCssClasses {
     TibLbl,
     /// Style grid label in various CollectionGridComponents
     GridLbl,
+    /// Label container spanning all columns of a component grid
+    GridLblCtnr,
     /// Style header to left
     HeaderLeft,
     /// Style header to right
@@ -274,6 +276,7 @@ impl ClientCssClasses {
             ClientCssClasses::TibImg => "tib-img",
             ClientCssClasses::TibLbl => "tib-lbl",
             ClientCssClasses::GridLbl => "grid-lbl",
+            ClientCssClasses::GridLblCtnr => "grid-lbl-ctnr",
             ClientCssClasses::HeaderLeft => "header-left",
             ClientCssClasses::HeaderRight => "header-right",
             ClientCssClasses::ContentTable => "content-table",
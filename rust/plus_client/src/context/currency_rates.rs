@@ -0,0 +1,110 @@
+//! Serving the active currency exchange rates to the client
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use leptos::server;
+use leptos::RwSignal;
+use leptos::ServerFnError;
+use plus_lookup::CurrencyExchange;
+use std::sync::Arc;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Environment variable naming a local CSV or JSON rate file for the server to serve.
+pub const CURRENCY_RATES_FILE_VAR: &str = "PLUS_CURRENCY_RATES_FILE";
+
+/// Age in days beyond which the server reports a rate from the rate file as stale.
+pub const CURRENCY_RATES_MAX_AGE_DAYS: i64 = 7;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Get the active exchange rates: those of the rate file named by [CURRENCY_RATES_FILE_VAR]
+/// or, without one, those of [plus_lookup::WEB_CURRENCY_EXCHANGE_HISTORY].
+/// Problems found in the rate file are logged on the server. The file may be partial:
+/// clients keep their own rates for currencies it has no rate for.
+///
+///   * _return_ - The rates as a JSON rate file
+#[server(GetCurrencyRates, "/api")]
+pub async fn get_currency_rates() -> Result<String, ServerFnError> {
+    // α <fn get_currency_rates>
+
+    use chrono::Datelike;
+    use plus_lookup::currency_rate_file::check_rates;
+    use plus_lookup::currency_rate_file::exchange_rate_records;
+    use plus_lookup::currency_rate_file::rates_to_json;
+    use plus_lookup::currency_rate_file::read_rates_file;
+    use plus_lookup::WEB_CURRENCY_EXCHANGE_HISTORY;
+    use plus_modeled::Date;
+
+    let Ok(path) = std::env::var(CURRENCY_RATES_FILE_VAR) else {
+        return Ok(rates_to_json(
+            &WEB_CURRENCY_EXCHANGE_HISTORY
+                .latest()
+                .map(|(date, currency_exchange)| exchange_rate_records(currency_exchange, date))
+                .unwrap_or_default(),
+        ));
+    };
+
+    let (rate_records, errors) = read_rates_file(std::path::Path::new(&path));
+    let today = chrono::Utc::now().date_naive();
+    let as_of = Date {
+        year: today.year() as u32,
+        month: today.month(),
+        day: today.day(),
+    };
+    for error in errors
+        .iter()
+        .chain(check_rates(&rate_records, &as_of, CURRENCY_RATES_MAX_AGE_DAYS).iter())
+    {
+        tracing::warn!("Rate file `{path}`: {error}");
+    }
+
+    if rate_records.is_empty() {
+        Err(ServerFnError::ServerError(format!(
+            "No valid rates in rate file `{path}`"
+        )))
+    } else {
+        Ok(rates_to_json(&rate_records))
+    }
+
+    // ω <fn get_currency_rates>
+}
+
+/// Replace the rates of the client with the latest active rates served.
+/// On failure the current rates are kept.
+///
+///   * **currency_exchange** - Rates to refresh, e.g. [crate::AppContext::currency_exchange].
+pub fn refresh_currency_exchange(currency_exchange: RwSignal<Arc<CurrencyExchange>>) {
+    // α <fn refresh_currency_exchange>
+
+    use leptos::SignalGetUntracked;
+    use leptos::SignalSet;
+    use plus_lookup::currency_rate_file::parse_rates_json;
+    use plus_lookup::currency_rate_file::rates_history;
+
+    leptos::spawn_local(async move {
+        match get_currency_rates().await {
+            Ok(rates_json) => {
+                let (rate_records, errors) = parse_rates_json(&rates_json);
+                for error in errors {
+                    tracing::warn!("Served currency rates: {error}");
+                }
+                // Served rates replace only their own currencies in the current rates
+                let current_exchange = currency_exchange.get_untracked();
+                if let Some((_, latest)) = rates_history(&rate_records, &current_exchange).latest()
+                {
+                    currency_exchange.set(Arc::new(latest.clone()));
+                }
+            }
+            Err(err) => tracing::warn!("Could not refresh currency rates: {err}"),
+        }
+    });
+
+    // ω <fn refresh_currency_exchange>
+}
+
+// α <mod-def currency_rates>
+// ω <mod-def currency_rates>
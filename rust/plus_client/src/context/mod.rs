@@ -4,6 +4,7 @@
 // --- mod decls ---
 ////////////////////////////////////////////////////////////////////////////////////
 pub mod app_context;
+pub mod currency_rates;
 
 // α <mod-def context>
 // ω <mod-def context>
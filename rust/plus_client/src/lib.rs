@@ -27,6 +27,8 @@ pub use self::component::core::toggle_image_button_control::ToggleState;
 pub use self::component::core::year_value_series_component::YearValueSeriesType;
 pub use self::component::ClientCssClasses;
 pub use self::context::app_context::AppContext;
+pub use self::context::currency_rates::refresh_currency_exchange;
+pub use self::context::currency_rates::GetCurrencyRates;
 pub use self::enums::SelectDirection;
 pub use self::enums::ViewSide;
 pub use self::utils::distribution_cdf::DistributionCdf;
@@ -59,6 +61,8 @@ pub use component::core::core_display::ccd_years_and_date::CcdYearsAndDate;
 pub use component::core::core_display::nested_widget_grid::NestedWidgetGrid;
 pub use component::core::core_display::sample_widget_grid::SampleWidgetGrid;
 pub use component::core::css_show::CssShow;
+pub use component::core::currency_exchange_component::CurrencyExchangeComponent;
+pub use component::core::currency_risk_component::CurrencyRiskComponent;
 pub use component::core::currency_select::CurrencySelect;
 pub use component::core::date_input::DateInput;
 pub use component::core::dispose_test::DisposeTest;
@@ -77,6 +81,7 @@ pub use component::core::ok_cancel_component::OkCancelComponent;
 pub use component::core::one_of_component::OneOfComponent;
 pub use component::core::percent_input::PercentInput;
 pub use component::core::slider_with_numeric_input::SliderWithNumericInput;
+pub use component::core::stress_scenario_component::StressScenarioComponent;
 pub use component::core::toggle_image_button::ToggleImageButton;
 pub use component::core::toggle_image_button_control::ToggleImageButtonControl;
 pub use component::core::year_currency_value_input::YearCurrencyValueInput;
@@ -25,6 +25,8 @@ async fn main() {
 
     // build our application with a route
     let app = Router::new()
+        // Server functions, e.g. the active currency rates used to refresh `AppContext`
+        .route("/api/*fn_name", post(leptos_axum::handle_server_fns))
         .leptos_routes(&leptos_options, routes, AppComponent)
         .fallback(file_and_error_handler)
         .with_state(leptos_options);
@@ -4,6 +4,7 @@
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
 use plus_modeled::NormalSpec;
+use plus_modeled::ReturnDistribution;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- traits ---
@@ -20,9 +21,72 @@ pub trait DistributionCdf {
 
     /// Get _cdf(x)_
     ///
-    ///   * **x** - Value to get _pdf(z)_
-    ///   * _return_ - The point on the _pdf_ for x.
-    fn cdf_sigmoid_approx(&self, x: f64) -> Option<f64>;
+    ///   * **x** - Value to get _cdf(x)_
+    ///   * _return_ - The point on the _cdf_ for x.
+    fn cdf(&self, x: f64) -> Option<f64>;
+
+    /// Get the quantile (inverse _cdf_) for probability `p`
+    ///
+    ///   * **p** - Probability in (0, 1)
+    ///   * _return_ - The x with _cdf(x)_ = p.
+    fn quantile(&self, p: f64) -> Option<f64>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Draw the chart of a _cdf_.
+///
+///   * **title** - Title of the chart
+///   * **x_vec** - Increasing values the _cdf_ is evaluated at
+///   * **y_vec** - The _cdf_ at each value
+///   * _return_ - SVG image of distribution
+fn cdf_chart(title: &str, x_vec: &[f64], y_vec: &[f64]) -> String {
+    // α <fn cdf_chart>
+
+    use crate::scale_by;
+    use crate::utils::constants::PLOT_TEXT_STYLE;
+    use plotters::prelude::*;
+
+    let mut plot_buff = String::with_capacity(2 ^ 11);
+    {
+        let root = SVGBackend::with_string(&mut plot_buff, (300, 275))
+            .into_drawing_area()
+            .titled(title, PLOT_TEXT_STYLE.clone())
+            .expect("");
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(4)
+            .set_label_area_size(LabelAreaPosition::Left, 40)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .set_label_area_size(LabelAreaPosition::Right, 0)
+            .build_cartesian_2d((x_vec[0])..(x_vec[x_vec.len() - 1]), 0f64..1.05)
+            .unwrap();
+
+        chart
+            .configure_mesh()
+            .x_labels(10)
+            .y_labels(5)
+            .disable_mesh()
+            .x_label_formatter(&|v| format!("{:.1}%", scale_by(*v, 2)))
+            .y_label_formatter(&|v| format!("{:.1}", v))
+            .draw()
+            .unwrap();
+
+        chart
+            .draw_series(
+                LineSeries::new(x_vec.iter().enumerate().map(|(i, x)| (*x, y_vec[i])), &BLUE)
+                    .point_size(1),
+            )
+            .unwrap()
+            .label("CDF");
+
+        root.present().expect("Should present");
+    }
+
+    plot_buff
+
+    // ω <fn cdf_chart>
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
@@ -38,10 +102,6 @@ impl DistributionCdf for NormalSpec {
     fn get_cdf_chart(&self, num_points: usize) -> String {
         // α <fn DistributionCdf::get_cdf_chart for NormalSpec>
 
-        use crate::scale_by;
-        use crate::utils::constants::PLOT_TEXT_STYLE;
-        use plotters::prelude::*;
-
         // Cap the number of points to range (32, 1024)
         let num_points = num_points.max(32).min(1024);
 
@@ -58,8 +118,8 @@ impl DistributionCdf for NormalSpec {
         for i in 0..(num_points / 2) {
             let x_lhs = self.mean - self.std_dev * num_sigmas;
             let x_rhs = self.mean + self.std_dev * num_sigmas;
-            let yr = self.cdf_sigmoid_approx(x_lhs);
-            let yl = self.cdf_sigmoid_approx(x_rhs);
+            let yr = self.cdf(x_lhs);
+            let yl = self.cdf(x_rhs);
             let rhs = num_points - i - 1;
 
             x_vec[i] = x_lhs;
@@ -73,66 +133,80 @@ impl DistributionCdf for NormalSpec {
         tracing::debug!("X -> {x_vec:?}");
         tracing::debug!("Y -> {y_vec:?}");
 
-        let mut plot_buff = String::with_capacity(2 ^ 11);
-        {
-            let root = SVGBackend::with_string(&mut plot_buff, (300, 275))
-                .into_drawing_area()
-                .titled(&format!("CDF {}", self), PLOT_TEXT_STYLE.clone())
-                .expect("");
-
-            let mut chart = ChartBuilder::on(&root)
-                .margin(4)
-                .set_label_area_size(LabelAreaPosition::Left, 40)
-                .set_label_area_size(LabelAreaPosition::Bottom, 40)
-                .set_label_area_size(LabelAreaPosition::Right, 0)
-                .build_cartesian_2d(
-                    (x_vec[0])..(x_vec[num_points - 1]),
-                    0f64..y_vec[num_points / 2] * 2.1,
-                )
-                .unwrap();
-
-            chart
-                .configure_mesh()
-                .x_labels(10)
-                .y_labels(5)
-                .disable_mesh()
-                .x_label_formatter(&|v| format!("{:.1}%", scale_by(*v, 2)))
-                .y_label_formatter(&|v| format!("{:.1}", v))
-                .draw()
-                .unwrap();
-
-            chart
-                .draw_series(
-                    LineSeries::new(x_vec.iter().enumerate().map(|(i, x)| (*x, y_vec[i])), &BLUE)
-                        .point_size(1),
-                )
-                .unwrap()
-                .label("CDF");
-
-            root.present().expect("Should present");
-        }
-
-        plot_buff
+        cdf_chart(&format!("CDF {}", self), &x_vec, &y_vec)
         // ω <fn DistributionCdf::get_cdf_chart for NormalSpec>
     }
 
     /// Get _cdf(x)_
     ///
-    ///   * **x** - Value to get _pdf(z)_
-    ///   * _return_ - The point on the _pdf_ for x.
+    ///   * **x** - Value to get _cdf(x)_
+    ///   * _return_ - The point on the _cdf_ for x.
     #[inline]
-    fn cdf_sigmoid_approx(&self, x: f64) -> Option<f64> {
-        // α <fn DistributionCdf::cdf_sigmoid_approx for NormalSpec>
-
-        if self.std_dev > 0.0 {
-            let correction = 1.70175;
-            let temp = (correction * (x - self.mean) / self.std_dev).exp();
-            Some(temp / (1.0 + temp))
-        } else {
-            None
-        }
+    fn cdf(&self, x: f64) -> Option<f64> {
+        // α <fn DistributionCdf::cdf for NormalSpec>
+        plus_utils::normal_cdf(x, self.mean, self.std_dev)
+        // ω <fn DistributionCdf::cdf for NormalSpec>
+    }
 
-        // ω <fn DistributionCdf::cdf_sigmoid_approx for NormalSpec>
+    /// Get the quantile (inverse _cdf_) for probability `p`
+    ///
+    ///   * **p** - Probability in (0, 1)
+    ///   * _return_ - The x with _cdf(x)_ = p.
+    #[inline]
+    fn quantile(&self, p: f64) -> Option<f64> {
+        // α <fn DistributionCdf::quantile for NormalSpec>
+        plus_utils::normal_quantile(p, self.mean, self.std_dev)
+        // ω <fn DistributionCdf::quantile for NormalSpec>
+    }
+}
+
+impl DistributionCdf for ReturnDistribution {
+    /// Get a chart representing the *cdf* of the distribution, between its 0.1% and 99.9% quantiles.
+    ///
+    ///   * **num_points** - Number of points to pull from the distribution
+    ///   * _return_ - SVG image of distribution
+    fn get_cdf_chart(&self, num_points: usize) -> String {
+        // α <fn DistributionCdf::get_cdf_chart for ReturnDistribution>
+
+        // Cap the number of points to range (32, 1024)
+        let num_points = num_points.max(32).min(1024);
+
+        let x_min = self.quantile(0.001).unwrap_or_default();
+        let x_max = self.quantile(0.999).unwrap_or_default();
+        let step = (x_max - x_min) / (num_points - 1) as f64;
+        let x_vec = (0..num_points)
+            .map(|i| x_min + step * i as f64)
+            .collect::<Vec<_>>();
+        let y_vec = x_vec
+            .iter()
+            .map(|x| self.cdf(*x).unwrap_or(0.0))
+            .collect::<Vec<_>>();
+
+        cdf_chart(&format!("CDF {}", self), &x_vec, &y_vec)
+
+        // ω <fn DistributionCdf::get_cdf_chart for ReturnDistribution>
+    }
+
+    /// Get _cdf(x)_
+    ///
+    ///   * **x** - Value to get _cdf(x)_
+    ///   * _return_ - The point on the _cdf_ for x.
+    #[inline]
+    fn cdf(&self, x: f64) -> Option<f64> {
+        // α <fn DistributionCdf::cdf for ReturnDistribution>
+        ReturnDistribution::cdf(self, x)
+        // ω <fn DistributionCdf::cdf for ReturnDistribution>
+    }
+
+    /// Get the quantile (inverse _cdf_) for probability `p`
+    ///
+    ///   * **p** - Probability in (0, 1)
+    ///   * _return_ - The x with _cdf(x)_ = p.
+    #[inline]
+    fn quantile(&self, p: f64) -> Option<f64> {
+        // α <fn DistributionCdf::quantile for ReturnDistribution>
+        ReturnDistribution::quantile(self, p)
+        // ω <fn DistributionCdf::quantile for ReturnDistribution>
     }
 }
 
@@ -151,25 +225,45 @@ pub mod unit_tests {
         // --- functions ---
         ////////////////////////////////////////////////////////////////////////////////////
         #[test]
-        fn cdf_sigmoid_approx() {
-            // α <fn test DistributionCdf::cdf_sigmoid_approx on NormalSpec>
+        fn cdf() {
+            // α <fn test DistributionCdf::cdf on NormalSpec>
 
             let normal_spec = NormalSpec {
                 mean: 10.0,
                 std_dev: 1.0,
             };
-            assert_eq!(Some(0.5), normal_spec.cdf_sigmoid_approx(10.0));
-            assert_eq!(Some(1.0), normal_spec.cdf_sigmoid_approx(100.0));
+            assert_eq!(Some(0.5), normal_spec.cdf(10.0));
+            assert_eq!(Some(1.0), normal_spec.cdf(100.0));
+            assert!((normal_spec.cdf(9.0).unwrap() - 0.15865525393145707).abs() < 1e-15);
+            assert!((normal_spec.cdf(13.0).unwrap() - 0.9986501019683699).abs() < 1e-15);
             assert_eq!(
                 None,
                 NormalSpec {
                     mean: 10.0,
                     std_dev: 0.0
                 }
-                .cdf_sigmoid_approx(10.0)
+                .cdf(10.0)
+            );
+
+            // ω <fn test DistributionCdf::cdf on NormalSpec>
+        }
+
+        #[test]
+        fn quantile() {
+            // α <fn test DistributionCdf::quantile on NormalSpec>
+
+            let normal_spec = NormalSpec {
+                mean: 10.0,
+                std_dev: 2.0,
+            };
+            assert_eq!(Some(10.0), normal_spec.quantile(0.5));
+            assert!((normal_spec.quantile(0.025).unwrap() - 6.0800720309).abs() < 1e-9);
+            assert!(
+                (normal_spec.cdf(normal_spec.quantile(0.9).unwrap()).unwrap() - 0.9).abs() < 1e-14
             );
+            assert_eq!(None, normal_spec.quantile(0.0));
 
-            // ω <fn test DistributionCdf::cdf_sigmoid_approx on NormalSpec>
+            // ω <fn test DistributionCdf::quantile on NormalSpec>
         }
 
         // α <mod-def test_distribution_cdf_on_normal_spec>
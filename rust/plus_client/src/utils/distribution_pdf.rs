@@ -4,6 +4,7 @@
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
 use plus_modeled::NormalSpec;
+use plus_modeled::ReturnDistribution;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- traits ---
@@ -25,6 +26,65 @@ pub trait DistributionPdf {
     fn pdf(&self, z: f64) -> f64;
 }
 
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Draw the chart of a _pdf_.
+///
+///   * **title** - Title of the chart
+///   * **x_vec** - Increasing values the _pdf_ is evaluated at
+///   * **y_vec** - The _pdf_ at each value
+///   * _return_ - SVG image of distribution
+fn pdf_chart(title: &str, x_vec: &[f64], y_vec: &[f64]) -> String {
+    // α <fn pdf_chart>
+
+    use crate::scale_by;
+    use crate::utils::constants::PLOT_TEXT_STYLE;
+    use plotters::prelude::*;
+
+    let y_max = y_vec.iter().cloned().fold(0.0, f64::max);
+
+    let mut plot_buff = String::with_capacity(2 ^ 11);
+    {
+        let root = SVGBackend::with_string(&mut plot_buff, (300, 275))
+            .into_drawing_area()
+            .titled(title, PLOT_TEXT_STYLE.clone())
+            .expect("");
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(4)
+            .set_label_area_size(LabelAreaPosition::Left, 40)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .set_label_area_size(LabelAreaPosition::Right, 0)
+            .build_cartesian_2d((x_vec[0])..(x_vec[x_vec.len() - 1]), 0f64..y_max * 1.1)
+            .unwrap();
+
+        chart
+            .configure_mesh()
+            .x_labels(10)
+            //.y_labels(5)
+            .disable_mesh()
+            .x_label_formatter(&|v| format!("{:.1}%", scale_by(*v, 2)))
+            .y_label_formatter(&|_v| String::default())
+            .draw()
+            .unwrap();
+
+        chart
+            .draw_series(
+                LineSeries::new(x_vec.iter().enumerate().map(|(i, x)| (*x, y_vec[i])), &RED)
+                    .point_size(1),
+            )
+            .unwrap()
+            .label("PDF");
+
+        root.present().expect("Should present");
+    }
+
+    plot_buff
+
+    // ω <fn pdf_chart>
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 // --- trait impls ---
 ////////////////////////////////////////////////////////////////////////////////////
@@ -37,9 +97,6 @@ impl DistributionPdf for NormalSpec {
     ///   * _return_ - SVG image of distribution
     fn get_pdf_chart(&self, num_points: usize) -> String {
         // α <fn DistributionPdf::get_pdf_chart for NormalSpec>
-        use crate::scale_by;
-        use crate::utils::constants::PLOT_TEXT_STYLE;
-        use plotters::prelude::*;
 
         // Cap the number of points to range (32, 1024)
         let num_points = num_points.max(32).min(1024);
@@ -71,46 +128,7 @@ impl DistributionPdf for NormalSpec {
         tracing::debug!("{self:?} -> X -> {x_vec:?}");
         tracing::debug!("Y -> {y_vec:?}");
 
-        let mut plot_buff = String::with_capacity(2 ^ 11);
-        {
-            let root = SVGBackend::with_string(&mut plot_buff, (300, 275))
-                .into_drawing_area()
-                .titled(&format!("PDF {}", self), PLOT_TEXT_STYLE.clone())
-                .expect("");
-
-            let mut chart = ChartBuilder::on(&root)
-                .margin(4)
-                .set_label_area_size(LabelAreaPosition::Left, 40)
-                .set_label_area_size(LabelAreaPosition::Bottom, 40)
-                .set_label_area_size(LabelAreaPosition::Right, 0)
-                .build_cartesian_2d(
-                    (x_vec[0])..(x_vec[num_points - 1]),
-                    0f64..y_vec[num_points / 2] * 1.1,
-                )
-                .unwrap();
-
-            chart
-                .configure_mesh()
-                .x_labels(10)
-                //.y_labels(5)
-                .disable_mesh()
-                .x_label_formatter(&|v| format!("{:.1}%", scale_by(*v, 2)))
-                .y_label_formatter(&|_v| String::default())
-                .draw()
-                .unwrap();
-
-            chart
-                .draw_series(
-                    LineSeries::new(x_vec.iter().enumerate().map(|(i, x)| (*x, y_vec[i])), &RED)
-                        .point_size(1),
-                )
-                .unwrap()
-                .label("PDF");
-
-            root.present().expect("Should present");
-        }
-
-        plot_buff
+        pdf_chart(&format!("PDF {}", self), &x_vec, &y_vec)
 
         // ω <fn DistributionPdf::get_pdf_chart for NormalSpec>
     }
@@ -130,6 +148,45 @@ impl DistributionPdf for NormalSpec {
     }
 }
 
+impl DistributionPdf for ReturnDistribution {
+    /// Get a chart representing the distribution, between its 0.1% and 99.9% quantiles.
+    ///
+    ///   * **num_points** - Number of points to pull from the distribution
+    ///   * _return_ - SVG image of distribution
+    fn get_pdf_chart(&self, num_points: usize) -> String {
+        // α <fn DistributionPdf::get_pdf_chart for ReturnDistribution>
+
+        // Cap the number of points to range (32, 1024)
+        let num_points = num_points.max(32).min(1024);
+
+        let x_min = self.quantile(0.001).unwrap_or_default();
+        let x_max = self.quantile(0.999).unwrap_or_default();
+        let step = (x_max - x_min) / (num_points - 1) as f64;
+        let x_vec = (0..num_points)
+            .map(|i| x_min + step * i as f64)
+            .collect::<Vec<_>>();
+        let y_vec = x_vec
+            .iter()
+            .map(|x| DistributionPdf::pdf(self, *x))
+            .collect::<Vec<_>>();
+
+        pdf_chart(&format!("PDF {}", self), &x_vec, &y_vec)
+
+        // ω <fn DistributionPdf::get_pdf_chart for ReturnDistribution>
+    }
+
+    /// Get _pdf(z)_
+    ///
+    ///   * **z** - Value to get _pdf(z)_
+    ///   * _return_ - The point on the _pdf_ for z.
+    #[inline]
+    fn pdf(&self, z: f64) -> f64 {
+        // α <fn DistributionPdf::pdf for ReturnDistribution>
+        ReturnDistribution::pdf(self, z).unwrap_or_default()
+        // ω <fn DistributionPdf::pdf for ReturnDistribution>
+    }
+}
+
 /// Unit tests for `distribution_pdf`
 #[cfg(test)]
 pub mod unit_tests {
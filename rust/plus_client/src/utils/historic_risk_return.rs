@@ -16,11 +16,15 @@ pub trait HistoricRiskReturnPlot {
     ///
     ///   * **historic_values** - Set of historic risk return values.
     ///   * **include_labels** - If set will include labels with the dots.
+    ///   * **portfolio** - Label and combined return of a portfolio to plot as its own point.
+    ///   * **efficient_frontier** - Points of an efficient frontier to draw as a curve, empty for none.
     ///   * _return_ - SVG image of the points on a plot.
     fn get_historic_plot(
         &self,
         historic_values: &[HistoricRiskReturn],
         include_labels: bool,
+        portfolio: Option<(&str, &NormalSpec)>,
+        efficient_frontier: &[NormalSpec],
     ) -> String;
 }
 
@@ -47,11 +51,15 @@ impl HistoricRiskReturnPlot for NormalSpec {
     ///
     ///   * **historic_values** - Set of historic risk return values.
     ///   * **include_labels** - If set will include labels with the dots.
+    ///   * **portfolio** - Label and combined return of a portfolio to plot as its own point.
+    ///   * **efficient_frontier** - Points of an efficient frontier to draw as a curve, empty for none.
     ///   * _return_ - SVG image of the points on a plot.
     fn get_historic_plot(
         &self,
         historic_values: &[HistoricRiskReturn],
         include_labels: bool,
+        portfolio: Option<(&str, &NormalSpec)>,
+        efficient_frontier: &[NormalSpec],
     ) -> String {
         // α <fn HistoricRiskReturnPlot::get_historic_plot for NormalSpec>
         use crate::scale_by;
@@ -63,11 +71,15 @@ impl HistoricRiskReturnPlot for NormalSpec {
         let x_vec = historic_values
             .iter()
             .map(|hv| hv.risk_return.0)
+            .chain(portfolio.map(|(_, portfolio)| portfolio.std_dev))
+            .chain(efficient_frontier.iter().map(|point| point.std_dev))
             .collect::<Vec<_>>();
 
         let y_vec = historic_values
             .iter()
             .map(|hv| hv.risk_return.1)
+            .chain(portfolio.map(|(_, portfolio)| portfolio.mean))
+            .chain(efficient_frontier.iter().map(|point| point.mean))
             .collect::<Vec<_>>();
 
         let max_x = x_vec
@@ -106,6 +118,17 @@ impl HistoricRiskReturnPlot for NormalSpec {
 
             let user_points = vec![(self.std_dev, self.mean)];
 
+            if !efficient_frontier.is_empty() {
+                chart
+                    .draw_series(LineSeries::new(
+                        efficient_frontier
+                            .iter()
+                            .map(|point| (point.std_dev, point.mean)),
+                        &BLACK,
+                    ))
+                    .unwrap();
+            }
+
             for hrr in HISTORIC_RISK_RETURN_SAMPLES.iter() {
                 chart
                     .draw_series(PointSeries::of_element(
@@ -155,6 +178,26 @@ impl HistoricRiskReturnPlot for NormalSpec {
                     },
                 ))
                 .unwrap();
+
+            if let Some((portfolio_label, portfolio)) = portfolio {
+                chart
+                    .draw_series(PointSeries::of_element(
+                        vec![(portfolio.std_dev, portfolio.mean)].into_iter(),
+                        6,
+                        &BLACK,
+                        &|c, s, st| {
+                            EmptyElement::at(c)
+                                + TriangleMarker::new((0, 0), s, st.filled())
+                                + Text::new(
+                                    format!("{portfolio_label} {portfolio}"),
+                                    (5, 5),
+                                    ("sans-serif", FONT_SIZE).into_font(),
+                                )
+                        },
+                    ))
+                    .unwrap();
+            }
+
             root.present().expect("Should present");
         }
         plot_buff
@@ -166,46 +209,158 @@ impl HistoricRiskReturnPlot for NormalSpec {
 /// Unit tests for `historic_risk_return`
 #[cfg(test)]
 pub mod unit_tests {
+
+    /// Test trait historic_risk_return_plot on NormalSpec
+    pub mod test_historic_risk_return_plot_on_normal_spec {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn get_historic_plot() {
+            // α <fn test HistoricRiskReturnPlot::get_historic_plot on NormalSpec>
+
+            let normal_spec = NormalSpec {
+                mean: 0.1,
+                std_dev: 0.2,
+            };
+            assert!(HISTORIC_EFFICIENT_FRONTIER.len() > 1);
+
+            // The curve is a polyline through the frontier points, besides those of the axes
+            let polylines = |plot: &str| plot.matches("<polyline").count();
+            let without_frontier =
+                normal_spec.get_historic_plot(&HISTORIC_RISK_RETURN_SAMPLES, false, None, &[]);
+            let with_frontier = normal_spec.get_historic_plot(
+                &HISTORIC_RISK_RETURN_SAMPLES,
+                false,
+                None,
+                &HISTORIC_EFFICIENT_FRONTIER,
+            );
+            assert_eq!(polylines(&without_frontier) + 1, polylines(&with_frontier));
+
+            let curve = with_frontier
+                .split("<polyline")
+                .last()
+                .and_then(|polyline| polyline.split("points=\"").nth(1))
+                .and_then(|points| points.split('"').next())
+                .unwrap_or_default();
+            assert_eq!(
+                HISTORIC_EFFICIENT_FRONTIER.len(),
+                curve.split_whitespace().count()
+            );
+
+            let portfolio = NormalSpec {
+                mean: 0.07,
+                std_dev: 0.11,
+            };
+            let with_portfolio = normal_spec.get_historic_plot(
+                &HISTORIC_RISK_RETURN_SAMPLES,
+                false,
+                Some(("Portefeuille", &portfolio)),
+                &[],
+            );
+            assert!(with_portfolio.contains(&format!("Portefeuille {portfolio}")));
+
+            // ω <fn test HistoricRiskReturnPlot::get_historic_plot on NormalSpec>
+        }
+
+        // α <mod-def test_historic_risk_return_plot_on_normal_spec>
+        use super::*;
+        // ω <mod-def test_historic_risk_return_plot_on_normal_spec>
+    }
+
     // α <mod-def unit_tests>
+    use super::*;
     // ω <mod-def unit_tests>
 }
 
 // α <mod-def historic_risk_return>
 
 use once_cell::sync::Lazy;
-use plotters::prelude::{BLUE, GREEN, MAGENTA, RED, YELLOW};
+use plotters::prelude::{BLUE, CYAN, GREEN, MAGENTA, RED, YELLOW};
+use plus_modeled::historic_returns::{historic_correlations, historic_normal_spec, historic_years};
+use plus_modeled::HistoricSeries;
+use plus_modeled::MeanVarianceOptimizer;
+use strum::IntoEnumIterator;
 
-/// Historic risk return samples to give context to a candidate normal spec
+/// Historic risk return samples to give context to a candidate normal spec, estimated
+/// from the full history of each series of the embedded annual returns
+/// (see `plus_modeled::historic_returns`), followed by published values for asset
+/// classes not in the embedded returns
 pub static HISTORIC_RISK_RETURN_SAMPLES: Lazy<Vec<HistoricRiskReturn>> = Lazy::new(|| {
-    vec![
-        // Samples pulled from here: https://www.bogleheads.org/wiki/Historical_and_expected_returns
-        //x ->risk/ st. dev y -> return/ mean
-        HistoricRiskReturn {
-            risk_return: (0.202, 0.123),
-            label: "US Large Cap".into(),
-            color: GREEN,
-        },
-        HistoricRiskReturn {
-            risk_return: (0.329, 0.174),
-            label: "US Small Cap".into(),
-            color: MAGENTA,
-        },
-        HistoricRiskReturn {
-            risk_return: (0.057, 0.055),
-            label: "US Bonds".into(),
-            color: YELLOW,
-        },
-        HistoricRiskReturn {
-            risk_return: (0.171, 0.085),
-            label: "REITS".into(),
-            color: BLUE,
-        },
-        HistoricRiskReturn {
-            risk_return: (0.288, 0.1212),
-            label: "Emerging Mkts".into(),
-            color: RED,
-        },
-    ]
+    let years = historic_years();
+    HistoricSeries::iter()
+        // Inflation is not an investment
+        .filter(|series| *series != HistoricSeries::UsInflation)
+        .filter_map(|series| {
+            let color = match series {
+                HistoricSeries::UsLargeCapEquity => GREEN,
+                HistoricSeries::UsMidCapEquity => CYAN,
+                HistoricSeries::UsSmallCapEquity => MAGENTA,
+                HistoricSeries::UsTreasuryBill => RGBColor(128, 0, 128),
+                HistoricSeries::UsTreasuryBond => YELLOW,
+                HistoricSeries::UsCorporateBond | HistoricSeries::UsInflation => {
+                    RGBColor(255, 140, 0)
+                }
+                HistoricSeries::Gold => RGBColor(212, 175, 55),
+            };
+            //x ->risk/ st. dev y -> return/ mean
+            historic_normal_spec(series, &years).map(|normal_spec| HistoricRiskReturn {
+                risk_return: (normal_spec.std_dev, normal_spec.mean),
+                label: series.label().into(),
+                color,
+            })
+        })
+        .chain([
+            // Samples pulled from here: https://www.bogleheads.org/wiki/Historical_and_expected_returns
+            HistoricRiskReturn {
+                risk_return: (0.171, 0.085),
+                label: "REITS".into(),
+                color: BLUE,
+            },
+            HistoricRiskReturn {
+                risk_return: (0.288, 0.1212),
+                label: "Emerging Mkts".into(),
+                color: RED,
+            },
+        ])
+        .collect()
+});
+
+/// Efficient frontier of the stock and bond series of the embedded annual returns of
+/// [HISTORIC_RISK_RETURN_SAMPLES], those with a `DistributionInstrument`, from the returns
+/// over the full history of each series and their correlations over the years all series cover
+pub static HISTORIC_EFFICIENT_FRONTIER: Lazy<Vec<NormalSpec>> = Lazy::new(|| {
+    const FRONTIER_POINTS: usize = 20;
+    let years = historic_years();
+    let (series, (instruments, normal_specs)): (Vec<_>, (Vec<_>, Vec<_>)) = HistoricSeries::iter()
+        .filter_map(|series| {
+            Some((
+                series,
+                (
+                    series.distribution_instrument()?,
+                    historic_normal_spec(series, &years)?,
+                ),
+            ))
+        })
+        .unzip();
+
+    historic_correlations(&series, &years)
+        .and_then(|correlations| {
+            MeanVarianceOptimizer::new(instruments, normal_specs, &correlations, 1.0)
+        })
+        .map(|optimizer| {
+            optimizer
+                .efficient_frontier(FRONTIER_POINTS)
+                .into_iter()
+                .map(|allocation| allocation.normal_spec)
+                .collect()
+        })
+        .unwrap_or_default()
 });
 
 // ω <mod-def historic_risk_return>
@@ -198,6 +198,18 @@ currency = Currency
     .rub = Rub
     .krw = Krw
     .sek = Sek
+currency_exchange_component = currency_exchange_component
+    .exchange_rates = Exchange Rates
+    .currency = Currency
+    .to_usd = To USD
+    .from_usd = From USD
+    .reset = Reset
+    .cross_rates = Cross Rates
+currency_risk_component = currency_risk_component
+    .currency_risk = Currency Risk
+    .today = Today
+    .percentile = Percentile
+    .value = Value
 distribution_instrument = Distribution Instrument
     .equity = Equity
     .interest_bearing = Interest Bearing
@@ -316,6 +328,7 @@ growth_assumption_component = growth_assumption_component
     .fixed_rate_curve = Fixed Rate Curve
 historic_risk_return_component = historic_risk_return_component
     .holding_type = {common_strings.holding_type}
+    .portfolio = Portfolio
 holding_component = holding_component
     .cost = {common_strings.cost}
     .current_price = {common_strings.current_price}
@@ -399,6 +412,8 @@ normal_loss_component = normal_loss_component
     .cdf_sample = cdf sample
     .gain_prefix = gain < 
     .loss_table = Loss Table For
+    .value_at_risk = VaR
+    .conditional_value_at_risk = CVaR
 normal_spec_component = normal_spec_component
     .mean_placeholder = {common_strings.mean_placeholder}
     .std_dev_placeholder = {common_strings.std_dev_placeholder}
@@ -494,6 +509,18 @@ state_of_residence = State Of Residence
     .wv = Wv
     .wi = Wi
     .wy = Wy
+stress_scenario = Stress Scenario
+    .great_depression = Great Depression (1929-1945)
+    .stagflation = Stagflation (1966-1982)
+    .lost_decade = Lost Decade (2000-2012)
+    .financial_crisis = Financial Crisis (2008)
+stress_scenario_component = stress_scenario_component
+    .scenario = Scenario
+    .start_year = Start Year
+    .survives = Survives
+    .fails_in = Fails In
+    .year = Year
+    .balance = Balance
 symbol_input = symbol_input
     .symbol = Symbol
 tax_determinants_component = tax_determinants_component
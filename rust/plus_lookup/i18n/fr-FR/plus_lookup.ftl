@@ -220,6 +220,18 @@ currency = Currency
     .rub = Rub
     .krw = Krw
     .sek = Sek
+currency_exchange_component = 🇫🇷currency_exchange_component
+    .exchange_rates = 🇫🇷Exchange Rates
+    .currency = 🇫🇷Currency
+    .to_usd = 🇫🇷To USD
+    .from_usd = 🇫🇷From USD
+    .reset = 🇫🇷Reset
+    .cross_rates = 🇫🇷Cross Rates
+currency_risk_component = 🇫🇷currency_risk_component
+    .currency_risk = 🇫🇷Currency Risk
+    .today = 🇫🇷Today
+    .percentile = 🇫🇷Percentile
+    .value = 🇫🇷Value
 distribution_instrument = 🇫🇷Distribution Instrument
     .equity = 🇫🇷Equity
     .interest_bearing = 🇫🇷Interest Bearing
@@ -357,6 +369,7 @@ growth_assumption_component = 🇫🇷growth_assumption_component
     .normal_spec = 🇫🇷Normal Spec
 historic_risk_return_component = 🇫🇷historic_risk_return_component
     .holding_type = { common_strings.holding_type }
+    .portfolio = 🇫🇷Portfolio
 holding_component = holding_component
     .cost = { common_strings.cost }
     .current_price = { common_strings.current_price }
@@ -441,11 +454,13 @@ named_rate_curve = 🇫🇷Named Rate Curve
     .report_inflation = 🇫🇷Report Inflation
 normal_loss_component = 🇫🇷normal_loss_component
     .cdf_sample = 🇫🇷cdf sample
+    .conditional_value_at_risk = 🇫🇷CVaR
     .gain_pct = 🇫🇷Gain %
     .gain_prefix = 🇫🇷gain <
     .loss_table = 🇫🇷Loss Table For
     .prob_abbrev = 🇫🇷Prob.
     .prob_pct = 🇫🇷Prob. %
+    .value_at_risk = 🇫🇷VaR
 normal_spec_component = 🇫🇷normal_spec_component
     .mean_placeholder = { common_strings.mean_placeholder }
     .std_dev = { common_strings.std_dev }
@@ -550,6 +565,18 @@ state_of_residence = State Of Residence
     .wv = Wv
     .wi = Wi
     .wy = Wy
+stress_scenario = 🇫🇷Stress Scenario
+    .great_depression = 🇫🇷Great Depression (1929-1945)
+    .stagflation = 🇫🇷Stagflation (1966-1982)
+    .lost_decade = 🇫🇷Lost Decade (2000-2012)
+    .financial_crisis = 🇫🇷Financial Crisis (2008)
+stress_scenario_component = 🇫🇷stress_scenario_component
+    .scenario = 🇫🇷Scenario
+    .start_year = 🇫🇷Start Year
+    .survives = 🇫🇷Survives
+    .fails_in = 🇫🇷Fails In
+    .year = 🇫🇷Year
+    .balance = 🇫🇷Balance
 symbol_input = 🇫🇷symbol_input
     .symbol = 🇫🇷Symbol
 tax_determinants_component = 🇫🇷tax_determinants_component
@@ -4,6 +4,7 @@
 use crate::CurrencyValue;
 use once_cell::sync::Lazy;
 use plus_modeled::Currency;
+use plus_modeled::CurrencyRiskSimulator;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -207,6 +208,89 @@ impl CurrencyExchange {
 
         // ω <fn CurrencyExchange::exchange_currency_value>
     }
+
+    /// Copy with the rates to USD of some currencies replaced, e.g. by simulated rates.
+    ///
+    ///   * **to_usd_rates** - Currencies with their new rate to `USD`.
+    ///   * _return_ - The new [CurrencyExchange]
+    pub fn with_to_usd_rates(&self, to_usd_rates: &[(Currency, f64)]) -> CurrencyExchange {
+        // α <fn CurrencyExchange::with_to_usd_rates>
+
+        let mut exchange_rates = self.exchange_rates.clone();
+        for (currency, to_usd) in to_usd_rates {
+            if *currency != Currency::Usd {
+                exchange_rates[*currency as usize] = (*to_usd, 1.0 / to_usd);
+            }
+        }
+        CurrencyExchange { exchange_rates }
+
+        // ω <fn CurrencyExchange::with_to_usd_rates>
+    }
+
+    /// Simulate a path of exchange rates starting from these rates.
+    ///
+    ///   * **currency_risk_simulator** - Simulator of the currencies at risk.
+    ///   * **num_years** - Number of years in the path.
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - Shocks of the simulator's items and the [CurrencyExchange] for each year of the path, `None` as for `CurrencyRiskSimulator::sample_path`.
+    pub fn sample_exchange_path(
+        &self,
+        currency_risk_simulator: &CurrencyRiskSimulator,
+        num_years: usize,
+        standard_normal: impl FnMut() -> f64,
+    ) -> Option<(Vec<Vec<f64>>, Vec<CurrencyExchange>)> {
+        // α <fn CurrencyExchange::sample_exchange_path>
+
+        let currencies = currency_risk_simulator
+            .currency_risk_specs
+            .iter()
+            .map(|currency_risk_spec| currency_risk_spec.currency)
+            .collect::<Vec<_>>();
+        let to_usd_rates = currencies
+            .iter()
+            .map(|currency| self.to_usd_rate(*currency))
+            .collect::<Vec<_>>();
+
+        let (shocks_by_year, rates_by_year) =
+            currency_risk_simulator.sample_path(&to_usd_rates, num_years, standard_normal)?;
+        Some((
+            shocks_by_year
+                .into_iter()
+                .map(|shocks| shocks.to_vec())
+                .collect(),
+            rates_by_year
+                .into_iter()
+                .map(|rates| {
+                    self.with_to_usd_rates(
+                        &currencies.iter().copied().zip(rates).collect::<Vec<_>>(),
+                    )
+                })
+                .collect(),
+        ))
+
+        // ω <fn CurrencyExchange::sample_exchange_path>
+    }
+
+    /// Total of values in several currencies converted to one.
+    ///
+    ///   * **values** - Values to total.
+    ///   * **to_currency** - Currency to convert to, e.g. the display currency.
+    ///   * _return_ - The total.
+    pub fn total_in_currency(
+        &self,
+        values: &[CurrencyValue],
+        to_currency: Currency,
+    ) -> CurrencyValue {
+        // α <fn CurrencyExchange::total_in_currency>
+        CurrencyValue::new(
+            to_currency,
+            values
+                .iter()
+                .map(|value| self.exchange_currency_value(*value, to_currency).value)
+                .sum(),
+        )
+        // ω <fn CurrencyExchange::total_in_currency>
+    }
 }
 
 /// Accessors for [CurrencyExchange] fields
@@ -276,6 +360,79 @@ pub mod unit_tests {
             // ω <fn test CurrencyExchange::exchange_rates>
         }
 
+        #[test]
+        fn with_to_usd_rates() {
+            // α <fn test CurrencyExchange::with_to_usd_rates>
+
+            let currency_exchange =
+                CurrencyExchange::from_subset(HashMap::from([(Currency::Eur, 1.06227)]))
+                    .with_to_usd_rates(&[(Currency::Eur, 1.2), (Currency::Usd, 2.0)]);
+            assert_eq!(1.2, currency_exchange.to_usd_rate(Currency::Eur));
+            assert_eq!(1.0 / 1.2, currency_exchange.from_usd_rate(Currency::Eur));
+            assert_eq!(1.0, currency_exchange.to_usd_rate(Currency::Usd));
+
+            // ω <fn test CurrencyExchange::with_to_usd_rates>
+        }
+
+        #[test]
+        fn sample_exchange_path() {
+            // α <fn test CurrencyExchange::sample_exchange_path>
+
+            use plus_modeled::CurrencyRiskSpec;
+            use plus_modeled::DossierCorrelationMatrix;
+
+            let currency_risk_simulator = CurrencyRiskSimulator::new(
+                &[CurrencyRiskSpec {
+                    currency: Currency::Gbp,
+                    drift: 0.0,
+                    volatility: 0.1,
+                }],
+                &[],
+                &DossierCorrelationMatrix::default(),
+            )
+            .unwrap();
+
+            let currency_exchange =
+                CurrencyExchange::from_subset(HashMap::from([(Currency::Gbp, 1.25)]));
+            let (shocks, path) = currency_exchange
+                .sample_exchange_path(&currency_risk_simulator, 2, || 1.0)
+                .unwrap();
+            assert_eq!(vec![Vec::<f64>::new(); 2], shocks);
+            assert_eq!(2, path.len());
+            let first_year = 1.25 * (0.1f64 - 0.005).exp();
+            assert!((path[0].to_usd_rate(Currency::Gbp) - first_year).abs() < 1e-12);
+            assert!(
+                (path[1].to_usd_rate(Currency::Gbp) - first_year * (0.1f64 - 0.005).exp()).abs()
+                    < 1e-12
+            );
+            // Currencies not at risk keep their rate
+            assert_eq!(1.0, path[1].to_usd_rate(Currency::Eur));
+
+            // ω <fn test CurrencyExchange::sample_exchange_path>
+        }
+
+        #[test]
+        fn total_in_currency() {
+            // α <fn test CurrencyExchange::total_in_currency>
+
+            let currency_exchange = CurrencyExchange::from_subset(HashMap::from([
+                (Currency::Eur, 1.1),
+                (Currency::Gbp, 1.25),
+            ]));
+            let total = currency_exchange.total_in_currency(
+                &[
+                    CurrencyValue::new(Currency::Eur, 100.0),
+                    CurrencyValue::new(Currency::Gbp, 100.0),
+                    CurrencyValue::new(Currency::Usd, 100.0),
+                ],
+                Currency::Usd,
+            );
+            assert_eq!(Currency::Usd, total.currency);
+            assert!((total.value - 335.0).abs() < 1e-9);
+
+            // ω <fn test CurrencyExchange::total_in_currency>
+        }
+
         // α <mod-def test_currency_exchange>
         use super::*;
 
@@ -0,0 +1,415 @@
+//! Exchange rates indexed by date, for converting values at the rates of their time
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::currency_exchange::SUPPORTED_CURRENCIES;
+use crate::CurrencyExchange;
+use crate::CurrencyValue;
+use crate::WEB_CURRENCY_EXCHANGE;
+use once_cell::sync::Lazy;
+use plus_modeled::Currency;
+use plus_modeled::Date;
+use plus_modeled::YearCurrencyValue;
+use plus_utils::day_number;
+use std::sync::Arc;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- lazy inits ---
+////////////////////////////////////////////////////////////////////////////////////
+/// History holding the single snapshot of [WEB_CURRENCY_EXCHANGE]
+pub static WEB_CURRENCY_EXCHANGE_HISTORY: Lazy<Arc<CurrencyExchangeHistory>> = Lazy::new(|| {
+    Arc::new(CurrencyExchangeHistory::new(vec![(
+        Date {
+            year: 2022,
+            month: 12,
+            day: 23,
+        },
+        WEB_CURRENCY_EXCHANGE.as_ref().clone(),
+    )]))
+});
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// How to find the rates for a date that may not have been observed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RateLookup {
+    /// Only rates observed on the date.
+    Exact,
+    /// Rates observed on the date or, failing that, the latest observed before it.
+    #[default]
+    Previous,
+    /// Rates linearly interpolated by day between the observations around the date.
+    /// Dates after the last observation take the last rates.
+    Interpolated,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Snapshots of [CurrencyExchange] by date of observation.
+#[derive(Debug, Clone, Default)]
+pub struct CurrencyExchangeHistory {
+    /// Snapshots sorted by date, at most one per date.
+    dated_exchanges: Vec<(Date, CurrencyExchange)>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Key ordering dates chronologically.
+///
+///   * **date** - Date to order.
+///   * _return_ - The (year, month, day) key
+#[inline]
+pub(crate) fn date_key(date: &Date) -> (u32, u32, u32) {
+    // α <fn date_key>
+    (date.year, date.month, date.day)
+    // ω <fn date_key>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl CurrencyExchangeHistory {
+    /// Create a new [CurrencyExchangeHistory] from snapshots in any order.
+    /// Of several snapshots on one date the last is kept.
+    ///
+    ///   * **dated_exchanges** - Exchange rates with their date of observation.
+    ///   * _return_ - The new [CurrencyExchangeHistory]
+    pub fn new(dated_exchanges: Vec<(Date, CurrencyExchange)>) -> CurrencyExchangeHistory {
+        // α <fn CurrencyExchangeHistory::new>
+
+        let mut currency_exchange_history = CurrencyExchangeHistory::default();
+        for (date, currency_exchange) in dated_exchanges {
+            currency_exchange_history.insert(date, currency_exchange);
+        }
+        currency_exchange_history
+
+        // ω <fn CurrencyExchangeHistory::new>
+    }
+
+    /// Add the rates observed on a date, replacing any already held for the date.
+    ///
+    ///   * **date** - Date of observation.
+    ///   * **currency_exchange** - Rates observed.
+    pub fn insert(&mut self, date: Date, currency_exchange: CurrencyExchange) {
+        // α <fn CurrencyExchangeHistory::insert>
+
+        match self
+            .dated_exchanges
+            .binary_search_by_key(&date_key(&date), |(dated, _)| date_key(dated))
+        {
+            Ok(i) => self.dated_exchanges[i].1 = currency_exchange,
+            Err(i) => self.dated_exchanges.insert(i, (date, currency_exchange)),
+        }
+
+        // ω <fn CurrencyExchangeHistory::insert>
+    }
+
+    /// Get the rates for a date.
+    ///
+    ///   * **date** - Date of the rates.
+    ///   * **rate_lookup** - How to treat a date without an observation.
+    ///   * _return_ - The rates, `None` if the lookup finds no observation
+    pub fn exchange_on(&self, date: &Date, rate_lookup: RateLookup) -> Option<CurrencyExchange> {
+        // α <fn CurrencyExchangeHistory::exchange_on>
+
+        let i = match self
+            .dated_exchanges
+            .binary_search_by_key(&date_key(date), |(dated, _)| date_key(dated))
+        {
+            Ok(i) => return Some(self.dated_exchanges[i].1.clone()),
+            Err(i) => i,
+        };
+
+        match rate_lookup {
+            RateLookup::Exact => None,
+            RateLookup::Previous => i
+                .checked_sub(1)
+                .map(|previous| self.dated_exchanges[previous].1.clone()),
+            RateLookup::Interpolated => {
+                let (before_date, before) = self.dated_exchanges.get(i.checked_sub(1)?)?;
+                let Some((after_date, after)) = self.dated_exchanges.get(i) else {
+                    return Some(before.clone());
+                };
+                let before_day = day_number(before_date.year, before_date.month, before_date.day)?;
+                let after_day = day_number(after_date.year, after_date.month, after_date.day)?;
+                let weight = (day_number(date.year, date.month, date.day)? - before_day) as f64
+                    / (after_day - before_day) as f64;
+                Some(CurrencyExchange::new(
+                    &SUPPORTED_CURRENCIES
+                        .iter()
+                        .map(|currency| {
+                            let before_rate = before.to_usd_rate(*currency);
+                            before_rate + weight * (after.to_usd_rate(*currency) - before_rate)
+                        })
+                        .collect::<Vec<_>>(),
+                ))
+            }
+        }
+
+        // ω <fn CurrencyExchangeHistory::exchange_on>
+    }
+
+    /// Get the rates of a year, those for its last day.
+    /// With [RateLookup::Previous] this is the latest observation in or before the year.
+    ///
+    ///   * **year** - Year of the rates.
+    ///   * **rate_lookup** - How to treat the last day of the year without an observation.
+    ///   * _return_ - The rates, `None` if the lookup finds no observation
+    #[inline]
+    pub fn exchange_in_year(&self, year: u32, rate_lookup: RateLookup) -> Option<CurrencyExchange> {
+        // α <fn CurrencyExchangeHistory::exchange_in_year>
+        self.exchange_on(
+            &Date {
+                year,
+                month: 12,
+                day: 31,
+            },
+            rate_lookup,
+        )
+        // ω <fn CurrencyExchangeHistory::exchange_in_year>
+    }
+
+    /// Convert a value of a past year to `to_currency` at the rates of that year.
+    ///
+    ///   * **year_currency_value** - Value to convert.
+    ///   * **to_currency** - Currency to convert to.
+    ///   * **rate_lookup** - How to find the rates of the year.
+    ///   * _return_ - The converted value of the same year, `None` if no rates are found
+    pub fn exchange_year_currency_value(
+        &self,
+        year_currency_value: &YearCurrencyValue,
+        to_currency: Currency,
+        rate_lookup: RateLookup,
+    ) -> Option<YearCurrencyValue> {
+        // α <fn CurrencyExchangeHistory::exchange_year_currency_value>
+
+        let from_currency = Currency::from_i32(year_currency_value.currency)?;
+        let currency_exchange = self.exchange_in_year(year_currency_value.year, rate_lookup)?;
+
+        Some(YearCurrencyValue {
+            year: year_currency_value.year,
+            currency: to_currency as i32,
+            value: currency_exchange
+                .exchange_currency_value(
+                    CurrencyValue::new(from_currency, year_currency_value.value),
+                    to_currency,
+                )
+                .value,
+        })
+
+        // ω <fn CurrencyExchangeHistory::exchange_year_currency_value>
+    }
+
+    /// Gain in `to_currency` from a cost basis to a market value, each converted at the
+    /// rates of its own year. The gain therefore includes the move in exchange rates.
+    ///
+    ///   * **cost_basis** - Value paid, with the year of purchase.
+    ///   * **market_value** - Value now, with the year of valuation.
+    ///   * **to_currency** - Currency of the gain.
+    ///   * **rate_lookup** - How to find the rates of each year.
+    ///   * _return_ - The gain, `None` if rates for either year are not found
+    pub fn gain_in_currency(
+        &self,
+        cost_basis: &YearCurrencyValue,
+        market_value: &YearCurrencyValue,
+        to_currency: Currency,
+        rate_lookup: RateLookup,
+    ) -> Option<CurrencyValue> {
+        // α <fn CurrencyExchangeHistory::gain_in_currency>
+
+        let cost_basis = self.exchange_year_currency_value(cost_basis, to_currency, rate_lookup)?;
+        let market_value =
+            self.exchange_year_currency_value(market_value, to_currency, rate_lookup)?;
+        Some(CurrencyValue::new(
+            to_currency,
+            market_value.value - cost_basis.value,
+        ))
+
+        // ω <fn CurrencyExchangeHistory::gain_in_currency>
+    }
+
+    /// Get the most recent rates.
+    ///
+    ///   * _return_ - The latest snapshot with its date, `None` if empty
+    #[inline]
+    pub fn latest(&self) -> Option<&(Date, CurrencyExchange)> {
+        // α <fn CurrencyExchangeHistory::latest>
+        self.dated_exchanges.last()
+        // ω <fn CurrencyExchangeHistory::latest>
+    }
+}
+
+/// Accessors for [CurrencyExchangeHistory] fields
+impl CurrencyExchangeHistory {
+    #[inline]
+    pub fn get_dated_exchanges(&self) -> &Vec<(Date, CurrencyExchange)> {
+        &self.dated_exchanges
+    }
+}
+
+/// Unit tests for `currency_exchange_history`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type CurrencyExchangeHistory
+    mod test_currency_exchange_history {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn exchange_on() {
+            // α <fn test CurrencyExchangeHistory::exchange_on>
+
+            let history = sample_history();
+            let eur_on = |date: Date, rate_lookup| {
+                history
+                    .exchange_on(&date, rate_lookup)
+                    .map(|currency_exchange| currency_exchange.to_usd_rate(Currency::Eur))
+            };
+
+            assert_eq!(Some(1.10), eur_on(date(2015, 1, 1), RateLookup::Exact));
+            assert_eq!(None, eur_on(date(2015, 7, 1), RateLookup::Exact));
+
+            assert_eq!(Some(1.10), eur_on(date(2015, 7, 1), RateLookup::Previous));
+            assert_eq!(None, eur_on(date(2014, 12, 31), RateLookup::Previous));
+            assert_eq!(Some(1.20), eur_on(date(2030, 1, 1), RateLookup::Previous));
+
+            // 2016 is a leap year, the midpoint of 2016 falls on July 2
+            let midpoint = eur_on(date(2016, 7, 2), RateLookup::Interpolated).unwrap();
+            assert!((midpoint - 1.15).abs() < 1e-12);
+            assert_eq!(None, eur_on(date(2014, 12, 31), RateLookup::Interpolated));
+            assert_eq!(
+                Some(1.20),
+                eur_on(date(2030, 1, 1), RateLookup::Interpolated)
+            );
+
+            // ω <fn test CurrencyExchangeHistory::exchange_on>
+        }
+
+        #[test]
+        fn insert() {
+            // α <fn test CurrencyExchangeHistory::insert>
+
+            let mut history = sample_history();
+            history.insert(date(2015, 1, 1), eur_exchange(1.05));
+            history.insert(date(2010, 1, 1), eur_exchange(1.40));
+            assert_eq!(
+                vec![(2010, 1.40), (2015, 1.05), (2016, 1.10), (2017, 1.20)],
+                history
+                    .get_dated_exchanges()
+                    .iter()
+                    .map(|(date, currency_exchange)| (
+                        date.year,
+                        currency_exchange.to_usd_rate(Currency::Eur)
+                    ))
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(2017, history.latest().unwrap().0.year);
+
+            // ω <fn test CurrencyExchangeHistory::insert>
+        }
+
+        #[test]
+        fn exchange_year_currency_value() {
+            // α <fn test CurrencyExchangeHistory::exchange_year_currency_value>
+
+            let history = sample_history();
+            let converted = history
+                .exchange_year_currency_value(
+                    &YearCurrencyValue {
+                        year: 2015,
+                        currency: Currency::Eur as i32,
+                        value: 100.0,
+                    },
+                    Currency::Usd,
+                    RateLookup::Previous,
+                )
+                .unwrap();
+            assert_eq!(2015, converted.year);
+            assert_eq!(Currency::Usd as i32, converted.currency);
+            assert!((converted.value - 110.0).abs() < 1e-9);
+
+            assert_eq!(
+                None,
+                history.exchange_year_currency_value(
+                    &YearCurrencyValue {
+                        year: 2014,
+                        currency: Currency::Eur as i32,
+                        value: 100.0,
+                    },
+                    Currency::Usd,
+                    RateLookup::Previous,
+                )
+            );
+
+            // ω <fn test CurrencyExchangeHistory::exchange_year_currency_value>
+        }
+
+        #[test]
+        fn gain_in_currency() {
+            // α <fn test CurrencyExchangeHistory::gain_in_currency>
+
+            let history = sample_history();
+            let cost_basis = YearCurrencyValue {
+                year: 2015,
+                currency: Currency::Eur as i32,
+                value: 100.0,
+            };
+            let market_value = YearCurrencyValue {
+                year: 2017,
+                ..cost_basis
+            };
+
+            // No gain in euros, but the euro gained against the dollar
+            let gain = history
+                .gain_in_currency(
+                    &cost_basis,
+                    &market_value,
+                    Currency::Usd,
+                    RateLookup::Previous,
+                )
+                .unwrap();
+            assert!((gain.value - 10.0).abs() < 1e-9);
+
+            // ω <fn test CurrencyExchangeHistory::gain_in_currency>
+        }
+
+        // α <mod-def test_currency_exchange_history>
+        use super::*;
+        use std::collections::HashMap;
+
+        fn date(year: u32, month: u32, day: u32) -> Date {
+            Date { year, month, day }
+        }
+
+        fn eur_exchange(to_usd: f64) -> CurrencyExchange {
+            CurrencyExchange::from_subset(HashMap::from([(Currency::Eur, to_usd)]))
+        }
+
+        fn sample_history() -> CurrencyExchangeHistory {
+            CurrencyExchangeHistory::new(vec![
+                (date(2017, 1, 1), eur_exchange(1.20)),
+                (date(2015, 1, 1), eur_exchange(1.10)),
+                (date(2016, 1, 1), eur_exchange(1.10)),
+            ])
+        }
+
+        // ω <mod-def test_currency_exchange_history>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def currency_exchange_history>
+// ω <mod-def currency_exchange_history>
@@ -0,0 +1,740 @@
+//! Import and export of exchange rates in CSV and JSON rate files.
+//!
+//! A CSV rate file has one rate per line: `currency code, date, rate to USD`, e.g.
+//! `EUR,2024-01-31,1.0816`. Blank lines, `#` comments and a leading header line are skipped.
+//! A JSON rate file is an array of `{"currency": "EUR", "date": "2024-01-31", "to_usd": 1.0816}`.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::currency_exchange::SUPPORTED_CURRENCIES;
+use crate::currency_exchange_history::date_key;
+use crate::CurrencyExchange;
+use crate::CurrencyExchangeHistory;
+use plus_modeled::Currency;
+use plus_modeled::Date;
+use plus_utils::day_number;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Problem found reading or checking a rate file.
+/// The `line` is the record of the file, 1 based: the line of a CSV file or the entry of a JSON array.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateFileError {
+    /// The file could not be read, or not parsed as a whole into records.
+    Unreadable {
+        /// Reason given by the system or parser.
+        reason: String,
+    },
+    /// Line is not a rate record.
+    Malformed {
+        /// Line of the record.
+        line: usize,
+        /// Reason the record was rejected.
+        reason: String,
+    },
+    /// Currency code is not one of the [SUPPORTED_CURRENCIES].
+    UnsupportedCurrency {
+        /// Line of the record.
+        line: usize,
+        /// The currency code as given.
+        code: String,
+    },
+    /// Date is not a valid `YYYY-MM-DD` date.
+    InvalidDate {
+        /// Line of the record.
+        line: usize,
+        /// The date as given.
+        text: String,
+    },
+    /// Rate is not a positive number.
+    InvalidRate {
+        /// Line of the record.
+        line: usize,
+        /// The rate as given.
+        text: String,
+    },
+    /// A supported currency has no rate.
+    MissingRate {
+        /// Currency without a rate.
+        currency: Currency,
+    },
+    /// The latest rate of a currency is older than allowed.
+    StaleRate {
+        /// Currency with the stale rate.
+        currency: Currency,
+        /// Date of its latest rate.
+        date: Date,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Rate of one currency to USD observed on a date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateRecord {
+    /// Currency of the rate.
+    pub currency: Currency,
+    /// Date the rate was observed.
+    pub date: Date,
+    /// Rate converting the currency to `USD`.
+    pub to_usd: f64,
+}
+
+/// Shape of an entry in a JSON rate file.
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonRateRecord {
+    /// Currency code, e.g. `EUR`.
+    currency: String,
+    /// Date in `YYYY-MM-DD` form.
+    date: String,
+    /// Rate converting the currency to `USD`.
+    to_usd: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Parse the records of a CSV rate file, keeping the valid records and reporting the rest.
+///
+///   * **text** - Contents of the CSV rate file.
+///   * _return_ - The valid records and the errors of the invalid lines
+pub fn parse_rates_csv(text: &str) -> (Vec<RateRecord>, Vec<RateFileError>) {
+    // α <fn parse_rates_csv>
+
+    let mut rate_records = Vec::new();
+    let mut errors = Vec::new();
+    let mut is_first_record = true;
+
+    for (i, text_line) in text.lines().enumerate() {
+        let line = i + 1;
+        let text_line = text_line.trim();
+        if text_line.is_empty() || text_line.starts_with('#') {
+            continue;
+        }
+        let fields = text_line.split(',').map(str::trim).collect::<Vec<_>>();
+        if is_first_record {
+            is_first_record = false;
+            if fields[0].eq_ignore_ascii_case("currency") {
+                continue;
+            }
+        }
+
+        match fields.as_slice() {
+            [code, date, to_usd] => {
+                match parse_rate_record(line, code, date, to_usd.parse().ok(), to_usd) {
+                    Ok(rate_record) => rate_records.push(rate_record),
+                    Err(error) => errors.push(error),
+                }
+            }
+            _ => errors.push(RateFileError::Malformed {
+                line,
+                reason: format!(
+                    "expected `currency,date,to_usd`, found {} fields",
+                    fields.len()
+                ),
+            }),
+        }
+    }
+
+    (rate_records, errors)
+
+    // ω <fn parse_rates_csv>
+}
+
+/// Parse the records of a JSON rate file, keeping the valid records and reporting the rest.
+///
+///   * **text** - Contents of the JSON rate file.
+///   * _return_ - The valid records and the errors of the invalid entries
+pub fn parse_rates_json(text: &str) -> (Vec<RateRecord>, Vec<RateFileError>) {
+    // α <fn parse_rates_json>
+
+    let entries = match serde_json::from_str::<Vec<serde_json::Value>>(text) {
+        Ok(entries) => entries,
+        Err(err) => {
+            return (
+                Vec::new(),
+                vec![RateFileError::Unreadable {
+                    reason: err.to_string(),
+                }],
+            )
+        }
+    };
+
+    let mut rate_records = Vec::new();
+    let mut errors = Vec::new();
+    for (i, entry) in entries.into_iter().enumerate() {
+        let line = i + 1;
+        let parsed = match serde_json::from_value::<JsonRateRecord>(entry) {
+            Ok(json_rate_record) => parse_rate_record(
+                line,
+                &json_rate_record.currency,
+                &json_rate_record.date,
+                Some(json_rate_record.to_usd),
+                &json_rate_record.to_usd.to_string(),
+            ),
+            Err(err) => Err(RateFileError::Malformed {
+                line,
+                reason: err.to_string(),
+            }),
+        };
+        match parsed {
+            Ok(rate_record) => rate_records.push(rate_record),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    (rate_records, errors)
+
+    // ω <fn parse_rates_json>
+}
+
+/// Read a local rate file, as JSON if it has a `.json` extension and as CSV otherwise.
+///
+///   * **path** - Path to the rate file.
+///   * _return_ - The valid records and the errors found
+pub fn read_rates_file(path: &Path) -> (Vec<RateRecord>, Vec<RateFileError>) {
+    // α <fn read_rates_file>
+
+    match std::fs::read_to_string(path) {
+        Ok(text) => {
+            if path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+            {
+                parse_rates_json(&text)
+            } else {
+                parse_rates_csv(&text)
+            }
+        }
+        Err(err) => (
+            Vec::new(),
+            vec![RateFileError::Unreadable {
+                reason: format!("{}: {err}", path.display()),
+            }],
+        ),
+    }
+
+    // ω <fn read_rates_file>
+}
+
+/// Write records as a CSV rate file, with header.
+///
+///   * **rate_records** - Records to write.
+///   * _return_ - Contents of the CSV rate file
+pub fn rates_to_csv(rate_records: &[RateRecord]) -> String {
+    // α <fn rates_to_csv>
+
+    std::iter::once("currency,date,to_usd".to_string())
+        .chain(rate_records.iter().map(|rate_record| {
+            format!(
+                "{},{},{}",
+                rate_record.currency.as_str_name(),
+                format_date(&rate_record.date),
+                rate_record.to_usd
+            )
+        }))
+        .map(|line| line + "\n")
+        .collect()
+
+    // ω <fn rates_to_csv>
+}
+
+/// Write records as a JSON rate file.
+///
+///   * **rate_records** - Records to write.
+///   * _return_ - Contents of the JSON rate file
+pub fn rates_to_json(rate_records: &[RateRecord]) -> String {
+    // α <fn rates_to_json>
+
+    serde_json::to_string_pretty(
+        &rate_records
+            .iter()
+            .map(|rate_record| JsonRateRecord {
+                currency: rate_record.currency.as_str_name().to_string(),
+                date: format_date(&rate_record.date),
+                to_usd: rate_record.to_usd,
+            })
+            .collect::<Vec<_>>(),
+    )
+    .unwrap_or_default()
+
+    // ω <fn rates_to_json>
+}
+
+/// Records of the rates of a [CurrencyExchange], all dated as observed on `date`.
+///
+///   * **currency_exchange** - Rates to record.
+///   * **date** - Date the rates were observed.
+///   * _return_ - A record for each supported currency other than `USD`
+pub fn exchange_rate_records(currency_exchange: &CurrencyExchange, date: &Date) -> Vec<RateRecord> {
+    // α <fn exchange_rate_records>
+
+    SUPPORTED_CURRENCIES
+        .iter()
+        .filter(|currency| **currency != Currency::Usd)
+        .map(|currency| RateRecord {
+            currency: *currency,
+            date: date.clone(),
+            to_usd: currency_exchange.to_usd_rate(*currency),
+        })
+        .collect()
+
+    // ω <fn exchange_rate_records>
+}
+
+/// Check the records cover every supported currency with a recent rate.
+///
+///   * **rate_records** - Records to check.
+///   * **as_of** - Date the rates are needed for.
+///   * **max_age_days** - Age in days beyond which the latest rate of a currency is stale.
+///   * _return_ - Errors for each currency with a missing or stale rate
+pub fn check_rates(
+    rate_records: &[RateRecord],
+    as_of: &Date,
+    max_age_days: i64,
+) -> Vec<RateFileError> {
+    // α <fn check_rates>
+
+    let latest_dates = latest_dates(rate_records);
+    let as_of_day = day_number(as_of.year, as_of.month, as_of.day);
+
+    SUPPORTED_CURRENCIES
+        .iter()
+        .filter(|currency| **currency != Currency::Usd)
+        .filter_map(|currency| match latest_dates.get(currency) {
+            None => Some(RateFileError::MissingRate {
+                currency: *currency,
+            }),
+            Some(date) => {
+                let age = as_of_day
+                    .zip(day_number(date.year, date.month, date.day))
+                    .map(|(as_of_day, day)| as_of_day - day);
+                age.is_some_and(|age| age > max_age_days)
+                    .then(|| RateFileError::StaleRate {
+                        currency: *currency,
+                        date: date.clone(),
+                    })
+            }
+        })
+        .collect()
+
+    // ω <fn check_rates>
+}
+
+/// Build the history of rates from records, one snapshot per date observed.
+/// Each snapshot holds, for every currency, its latest rate observed on or before the date.
+/// Currencies not yet observed keep their rate in `base_exchange`, so a partial file
+/// only changes the currencies it has rates for.
+///
+///   * **rate_records** - Records in any order.
+///   * **base_exchange** - Rates of the currencies not in the records, e.g. the current rates.
+///   * _return_ - The [CurrencyExchangeHistory] of the records
+pub fn rates_history(
+    rate_records: &[RateRecord],
+    base_exchange: &CurrencyExchange,
+) -> CurrencyExchangeHistory {
+    // α <fn rates_history>
+
+    let mut sorted_records = rate_records.iter().collect::<Vec<_>>();
+    sorted_records.sort_by_key(|rate_record| date_key(&rate_record.date));
+
+    let mut currency_exchange_history = CurrencyExchangeHistory::default();
+    let mut to_usd_rates = HashMap::new();
+    for (i, rate_record) in sorted_records.iter().enumerate() {
+        to_usd_rates.insert(rate_record.currency, rate_record.to_usd);
+        let is_last_of_date = sorted_records
+            .get(i + 1)
+            .map(|next_record| date_key(&next_record.date))
+            != Some(date_key(&rate_record.date));
+        if is_last_of_date {
+            currency_exchange_history.insert(
+                rate_record.date.clone(),
+                base_exchange.with_to_usd_rates(
+                    &to_usd_rates
+                        .iter()
+                        .map(|(currency, to_usd)| (*currency, *to_usd))
+                        .collect::<Vec<_>>(),
+                ),
+            );
+        }
+    }
+
+    currency_exchange_history
+
+    // ω <fn rates_history>
+}
+
+/// Validate the fields of one record.
+///
+///   * **line** - Line of the record.
+///   * **code** - Currency code.
+///   * **date** - Date in `YYYY-MM-DD` form.
+///   * **to_usd** - Rate to `USD`, `None` if not a number.
+///   * **to_usd_text** - Rate as given, for reporting.
+///   * _return_ - The record or the first problem found
+fn parse_rate_record(
+    line: usize,
+    code: &str,
+    date: &str,
+    to_usd: Option<f64>,
+    to_usd_text: &str,
+) -> Result<RateRecord, RateFileError> {
+    // α <fn parse_rate_record>
+
+    let currency = Currency::from_str_name(&code.to_ascii_uppercase())
+        .filter(|currency| SUPPORTED_CURRENCIES.contains(currency))
+        .ok_or_else(|| RateFileError::UnsupportedCurrency {
+            line,
+            code: code.to_string(),
+        })?;
+    let parsed_date = parse_date(date).ok_or_else(|| RateFileError::InvalidDate {
+        line,
+        text: date.to_string(),
+    })?;
+    let to_usd = to_usd
+        .filter(|to_usd| to_usd.is_finite() && *to_usd > 0.0)
+        .ok_or_else(|| RateFileError::InvalidRate {
+            line,
+            text: to_usd_text.to_string(),
+        })?;
+
+    Ok(RateRecord {
+        currency,
+        date: parsed_date,
+        to_usd,
+    })
+
+    // ω <fn parse_rate_record>
+}
+
+/// Parse a `YYYY-MM-DD` date.
+///
+///   * **text** - Date to parse.
+///   * _return_ - The date, `None` if not a valid date
+fn parse_date(text: &str) -> Option<Date> {
+    // α <fn parse_date>
+
+    let mut parts = text.split('-').map(|part| part.parse::<u32>().ok());
+    let date = Date {
+        year: parts.next()??,
+        month: parts.next()??,
+        day: parts.next()??,
+    };
+    (parts.next().is_none() && day_number(date.year, date.month, date.day).is_some())
+        .then_some(date)
+
+    // ω <fn parse_date>
+}
+
+/// Format a date as `YYYY-MM-DD`.
+///
+///   * **date** - Date to format.
+///   * _return_ - The formatted date
+#[inline]
+fn format_date(date: &Date) -> String {
+    // α <fn format_date>
+    format!("{:04}-{:02}-{:02}", date.year, date.month, date.day)
+    // ω <fn format_date>
+}
+
+/// Date of the latest record of each currency.
+///
+///   * **rate_records** - Records in any order.
+///   * _return_ - Latest date by currency
+fn latest_dates(rate_records: &[RateRecord]) -> HashMap<Currency, Date> {
+    // α <fn latest_dates>
+
+    let mut latest_dates: HashMap<Currency, Date> = HashMap::new();
+    for rate_record in rate_records {
+        let is_later = latest_dates
+            .get(&rate_record.currency)
+            .filter(|date| date_key(date) >= date_key(&rate_record.date))
+            .is_none();
+        if is_later {
+            latest_dates.insert(rate_record.currency, rate_record.date.clone());
+        }
+    }
+    latest_dates
+
+    // ω <fn latest_dates>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl Display for RateFileError {
+    /// Format instance.
+    ///
+    ///   * **f** - The formatter
+    ///   * _return_ - Formatted instance
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // α <fn Display::fmt for RateFileError>
+        match self {
+            RateFileError::Unreadable { reason } => write!(f, "Unreadable rate file ({reason})"),
+            RateFileError::Malformed { line, reason } => {
+                write!(f, "Line {line}: malformed rate ({reason})")
+            }
+            RateFileError::UnsupportedCurrency { line, code } => {
+                write!(f, "Line {line}: unsupported currency `{code}`")
+            }
+            RateFileError::InvalidDate { line, text } => {
+                write!(f, "Line {line}: invalid date `{text}`")
+            }
+            RateFileError::InvalidRate { line, text } => {
+                write!(f, "Line {line}: invalid rate `{text}`")
+            }
+            RateFileError::MissingRate { currency } => {
+                write!(f, "Missing rate for {}", currency.as_str_name())
+            }
+            RateFileError::StaleRate { currency, date } => write!(
+                f,
+                "Stale rate for {} from {}",
+                currency.as_str_name(),
+                format_date(date)
+            ),
+        }
+        // ω <fn Display::fmt for RateFileError>
+    }
+}
+
+/// Unit tests for `currency_rate_file`
+#[cfg(test)]
+pub mod unit_tests {
+    use test_log::test;
+
+    #[test]
+    fn test_parse_rates_csv() {
+        // α <fn test_parse_rates_csv>
+
+        let (rate_records, errors) = parse_rates_csv(
+            "currency,date,to_usd\n\
+             # Month end rates\n\
+             EUR,2024-01-31,1.0816\n\
+             \n\
+             gbp, 2024-01-31, 1.2711\n\
+             XYZ,2024-01-31,1.0\n\
+             EUR,2024-02-30,1.08\n\
+             EUR,2024-02-29,-1.08\n\
+             EUR,2024-02-29\n",
+        );
+
+        assert_eq!(
+            vec![
+                RateRecord {
+                    currency: Currency::Eur,
+                    date: date(2024, 1, 31),
+                    to_usd: 1.0816,
+                },
+                RateRecord {
+                    currency: Currency::Gbp,
+                    date: date(2024, 1, 31),
+                    to_usd: 1.2711,
+                },
+            ],
+            rate_records
+        );
+        assert_eq!(4, errors.len());
+        assert_eq!(
+            RateFileError::UnsupportedCurrency {
+                line: 6,
+                code: "XYZ".into()
+            },
+            errors[0]
+        );
+        assert_eq!(
+            RateFileError::InvalidDate {
+                line: 7,
+                text: "2024-02-30".into()
+            },
+            errors[1]
+        );
+        assert_eq!(
+            RateFileError::InvalidRate {
+                line: 8,
+                text: "-1.08".into()
+            },
+            errors[2]
+        );
+        assert!(matches!(
+            errors[3],
+            RateFileError::Malformed { line: 9, .. }
+        ));
+
+        // ω <fn test_parse_rates_csv>
+    }
+
+    #[test]
+    fn test_parse_rates_json() {
+        // α <fn test_parse_rates_json>
+
+        let (rate_records, errors) = parse_rates_json(
+            r#"[
+                {"currency": "EUR", "date": "2024-01-31", "to_usd": 1.0816},
+                {"currency": "EUR", "date": "2024-01-31"},
+                {"currency": "USN", "date": "2024-01-31", "to_usd": 1.0}
+            ]"#,
+        );
+        assert_eq!(1, rate_records.len());
+        assert!(matches!(
+            errors[0],
+            RateFileError::Malformed { line: 2, .. }
+        ));
+        assert_eq!(
+            RateFileError::UnsupportedCurrency {
+                line: 3,
+                code: "USN".into()
+            },
+            errors[1]
+        );
+
+        let (rate_records, errors) = parse_rates_json("[{");
+        assert!(rate_records.is_empty());
+        assert!(matches!(errors[0], RateFileError::Unreadable { .. }));
+
+        // ω <fn test_parse_rates_json>
+    }
+
+    #[test]
+    fn test_rates_to_csv() {
+        // α <fn test_rates_to_csv>
+
+        let rate_records = sample_records();
+        let csv = rates_to_csv(&rate_records);
+        assert!(csv.starts_with("currency,date,to_usd\nEUR,2024-01-31,1.08\n"));
+        assert_eq!((rate_records, Vec::new()), parse_rates_csv(&csv));
+
+        // ω <fn test_rates_to_csv>
+    }
+
+    #[test]
+    fn test_rates_to_json() {
+        // α <fn test_rates_to_json>
+
+        let rate_records = sample_records();
+        assert_eq!(
+            (rate_records.clone(), Vec::new()),
+            parse_rates_json(&rates_to_json(&rate_records))
+        );
+
+        // ω <fn test_rates_to_json>
+    }
+
+    #[test]
+    fn test_exchange_rate_records() {
+        // α <fn test_exchange_rate_records>
+
+        let currency_exchange =
+            CurrencyExchange::from_subset(HashMap::from([(Currency::Eur, 1.08)]));
+        let rate_records = exchange_rate_records(&currency_exchange, &date(2024, 1, 31));
+        assert_eq!(SUPPORTED_CURRENCIES.len() - 1, rate_records.len());
+        assert!(rate_records.contains(&RateRecord {
+            currency: Currency::Eur,
+            date: date(2024, 1, 31),
+            to_usd: 1.08,
+        }));
+        assert!(check_rates(&rate_records, &date(2024, 1, 31), 0).is_empty());
+
+        // ω <fn test_exchange_rate_records>
+    }
+
+    #[test]
+    fn test_check_rates() {
+        // α <fn test_check_rates>
+
+        let errors = check_rates(&sample_records(), &date(2024, 3, 1), 7);
+        assert!(errors.contains(&RateFileError::MissingRate {
+            currency: Currency::Jpy
+        }));
+        assert!(errors.contains(&RateFileError::StaleRate {
+            currency: Currency::Gbp,
+            date: date(2024, 1, 31),
+        }));
+        assert!(!errors
+            .iter()
+            .any(|error| matches!(error, RateFileError::StaleRate { currency, .. } if *currency == Currency::Eur)));
+
+        // ω <fn test_check_rates>
+    }
+
+    #[test]
+    fn test_rates_history() {
+        // α <fn test_rates_history>
+
+        let currency_exchange_history = rates_history(
+            &sample_records(),
+            &CurrencyExchange::from_subset(HashMap::new()),
+        );
+        assert_eq!(2, currency_exchange_history.get_dated_exchanges().len());
+        let (latest_date, latest) = currency_exchange_history.latest().unwrap();
+        assert_eq!(date(2024, 2, 29), *latest_date);
+        assert_eq!(1.09, latest.to_usd_rate(Currency::Eur));
+        // GBP carried forward from January
+        assert_eq!(1.27, latest.to_usd_rate(Currency::Gbp));
+
+        // A file with one currency leaves the rates of all others unchanged
+        let base_exchange = CurrencyExchange::from_subset(HashMap::from([
+            (Currency::Eur, 1.08),
+            (Currency::Jpy, 0.0067),
+            (Currency::Krw, 0.00075),
+        ]));
+        let (rate_records, errors) = parse_rates_csv("GBP,2024-01-31,1.27\n");
+        assert!(errors.is_empty());
+        let (_, latest) = rates_history(&rate_records, &base_exchange)
+            .latest()
+            .cloned()
+            .unwrap();
+        assert_eq!(1.27, latest.to_usd_rate(Currency::Gbp));
+        for currency in SUPPORTED_CURRENCIES
+            .iter()
+            .filter(|currency| **currency != Currency::Gbp)
+        {
+            assert_eq!(
+                base_exchange.to_usd_rate(*currency),
+                latest.to_usd_rate(*currency)
+            );
+        }
+
+        // ω <fn test_rates_history>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+
+    fn date(year: u32, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    fn sample_records() -> Vec<RateRecord> {
+        vec![
+            RateRecord {
+                currency: Currency::Eur,
+                date: date(2024, 1, 31),
+                to_usd: 1.08,
+            },
+            RateRecord {
+                currency: Currency::Gbp,
+                date: date(2024, 1, 31),
+                to_usd: 1.27,
+            },
+            RateRecord {
+                currency: Currency::Eur,
+                date: date(2024, 2, 29),
+                to_usd: 1.09,
+            },
+        ]
+    }
+
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def currency_rate_file>
+// ω <mod-def currency_rate_file>
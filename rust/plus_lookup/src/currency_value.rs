@@ -6,6 +6,7 @@
 use ::core::fmt::Display;
 use ::core::fmt::Formatter;
 use plus_modeled::Currency;
+use plus_modeled::LangSelector;
 use serde::Serialize;
 use std::ops::Mul;
 use std::ops::Neg;
@@ -41,27 +42,33 @@ impl CurrencyValue {
         // ω <fn CurrencyValue::new>
     }
 
-    /// Get string representation of [CurrencyValue].
+    /// Get string representation of [CurrencyValue] in the conventions of a language.
     ///
+    ///   * **lang_selector** - Language of the reader.
     ///   * _return_ - String representation of the value with currency symbol.
     #[inline]
-    pub fn as_money(&self) -> String {
+    pub fn as_money(&self, lang_selector: LangSelector) -> String {
         // α <fn CurrencyValue::as_money>
-        self.currency.as_money(self.value)
+        self.currency.as_money(self.value, lang_selector)
         // ω <fn CurrencyValue::as_money>
     }
 
     /// Show single string representation of two currency values representing a conversion from `self` to `to`.
     ///
     ///   * **to** - Converted value.
+    ///   * **lang_selector** - Language of the reader.
     ///   * _return_ - String representation of the original and converted value.
     #[inline]
-    pub fn converted_to(&self, to: CurrencyValue) -> String {
+    pub fn converted_to(&self, to: CurrencyValue, lang_selector: LangSelector) -> String {
         // α <fn CurrencyValue::converted_to>
         if self.currency != to.currency {
-            format!("{}->{}", self.as_money(), to.as_money())
+            format!(
+                "{}->{}",
+                self.as_money(lang_selector),
+                to.as_money(lang_selector)
+            )
         } else {
-            self.as_money()
+            self.as_money(lang_selector)
         }
         // ω <fn CurrencyValue::converted_to>
     }
@@ -100,13 +107,14 @@ impl Neg for CurrencyValue {
 }
 
 impl Display for CurrencyValue {
-    /// Format the instance.
+    /// Format the instance in US English; views format with the reader's language
+    /// through [CurrencyValue::as_money].
     ///
     ///   * **f** - Formatter to push formatted item to.
     ///   * _return_ - Formatted instance
     fn fmt(&self, #[allow(unused)] f: &mut Formatter<'_>) -> ::core::fmt::Result {
         // α <fn Display::fmt for CurrencyValue>
-        write!(f, "{}", self.as_money())
+        write!(f, "{}", self.as_money(LangSelector::UsEnglish))
         // ω <fn Display::fmt for CurrencyValue>
     }
 }
@@ -142,8 +150,12 @@ pub mod unit_tests {
         fn as_money() {
             // α <fn test CurrencyValue::as_money>
             assert_eq!(
-                Currency::Chf.as_money(100.0),
-                CurrencyValue::new(Currency::Chf, 100.0).as_money()
+                Currency::Chf.as_money(100.0, LangSelector::UsEnglish),
+                CurrencyValue::new(Currency::Chf, 100.0).as_money(LangSelector::UsEnglish)
+            );
+            assert_eq!(
+                "1\u{202f}234,56\u{a0}€",
+                CurrencyValue::new(Currency::Eur, 1234.56).as_money(LangSelector::French)
             )
             // ω <fn test CurrencyValue::as_money>
         }
@@ -152,9 +164,11 @@ pub mod unit_tests {
         fn converted_to() {
             // α <fn test CurrencyValue::converted_to>
             assert_eq!(
-                "CHF100->¥14,235",
-                CurrencyValue::new(Currency::Chf, 100.0)
-                    .converted_to(CurrencyValue::new(Currency::Jpy, 14235.0))
+                "CHF\u{a0}100.00->¥14,235",
+                CurrencyValue::new(Currency::Chf, 100.0).converted_to(
+                    CurrencyValue::new(Currency::Jpy, 14235.0),
+                    LangSelector::UsEnglish
+                )
             )
             // ω <fn test CurrencyValue::converted_to>
         }
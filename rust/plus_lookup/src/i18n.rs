@@ -760,6 +760,164 @@ pub mod cash_flow_timeline {
     // ω <mod-def cash_flow_timeline>
 }
 
+/// Functions for i18n strings in CurrencyExchangeComponent
+pub mod currency_exchange_component {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use super::*;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    /// I18n for exchange_rates
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_exchange_rates(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_exchange_component.exchange_rates",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for currency
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_currency(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_exchange_component.currency",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for to_usd
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_to_usd(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_exchange_component.to_usd",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for from_usd
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_from_usd(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_exchange_component.from_usd",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for reset
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_reset(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_exchange_component.reset",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for cross_rates
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_cross_rates(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_exchange_component.cross_rates",
+            )
+            .unwrap_or_default()
+    }
+
+    // α <mod-def currency_exchange_component>
+    // ω <mod-def currency_exchange_component>
+}
+
+/// Functions for i18n strings in CurrencyRiskComponent
+pub mod currency_risk_component {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use super::*;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    /// I18n for currency_risk
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_currency_risk(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_risk_component.currency_risk",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for today
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_today(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_risk_component.today",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for percentile
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_percentile(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_risk_component.percentile",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for value
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_value(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "currency_risk_component.value",
+            )
+            .unwrap_or_default()
+    }
+
+    // α <mod-def currency_risk_component>
+    // ω <mod-def currency_risk_component>
+}
+
 /// Functions for i18n strings in DistributionPolicyComponent
 pub mod distribution_policy_component {
     ////////////////////////////////////////////////////////////////////////////////////
@@ -1639,6 +1797,19 @@ pub mod historic_risk_return_component {
             .unwrap_or_default()
     }
 
+    /// I18n for portfolio
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_portfolio(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "historic_risk_return_component.portfolio",
+            )
+            .unwrap_or_default()
+    }
+
     // α <mod-def historic_risk_return_component>
     // ω <mod-def historic_risk_return_component>
 }
@@ -2272,6 +2443,32 @@ pub mod normal_loss_component {
             .unwrap_or_default()
     }
 
+    /// I18n for value_at_risk
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_value_at_risk(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "normal_loss_component.value_at_risk",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for conditional_value_at_risk
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_conditional_value_at_risk(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "normal_loss_component.conditional_value_at_risk",
+            )
+            .unwrap_or_default()
+    }
+
     // α <mod-def normal_loss_component>
     // ω <mod-def normal_loss_component>
 }
@@ -2763,6 +2960,98 @@ pub mod sales_plan_view {
     // ω <mod-def sales_plan_view>
 }
 
+/// Functions for i18n strings in StressScenarioComponent
+pub mod stress_scenario_component {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use super::*;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    /// I18n for scenario
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_scenario(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "stress_scenario_component.scenario",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for start_year
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_start_year(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "stress_scenario_component.start_year",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for survives
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_survives(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "stress_scenario_component.survives",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for fails_in
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_fails_in(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "stress_scenario_component.fails_in",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for year
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_year(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "stress_scenario_component.year",
+            )
+            .unwrap_or_default()
+    }
+
+    /// I18n for balance
+    ///
+    ///   * **lang_selector** - Language selector
+    ///   * _return_ - The string for language
+    pub fn i18n_balance(lang_selector: LangSelector) -> String {
+        LOCALES
+            .lookup(
+                lang_selector_to_language_id(&lang_selector),
+                "stress_scenario_component.balance",
+            )
+            .unwrap_or_default()
+    }
+
+    // α <mod-def stress_scenario_component>
+    // ω <mod-def stress_scenario_component>
+}
+
 /// Functions for i18n strings in SymbolInput
 pub mod symbol_input {
     ////////////////////////////////////////////////////////////////////////////////////
@@ -24,6 +24,7 @@ use plus_modeled::LangSelector;
 use plus_modeled::NamedRateCurve;
 use plus_modeled::PersonType;
 use plus_modeled::ReturnStatsType;
+use plus_modeled::StressScenario;
 use plus_modeled::TaxTreatment;
 use plus_modeled::TaxUsCategory;
 use plus_modeled::TaxUsFilingStatus;
@@ -292,6 +293,8 @@ pub enum I18nEnums<'a> {
     /// Enumerates supported enums and implements display to dispatch on language and value
     ReturnStatsType(LangSelector, &'a ReturnStatsType),
     /// Enumerates supported enums and implements display to dispatch on language and value
+    StressScenario(LangSelector, &'a StressScenario),
+    /// Enumerates supported enums and implements display to dispatch on language and value
     TaxTreatment(LangSelector, &'a TaxTreatment),
     /// Enumerates supported enums and implements display to dispatch on language and value
     TaxUsCategory(LangSelector, &'a TaxUsCategory),
@@ -926,6 +929,32 @@ impl<'a> Display for I18nEnums<'a> {
                         )
                         .unwrap_or_default(),
                 },
+                I18nEnums::StressScenario(lang_selector, e) => match e {
+                    StressScenario::GreatDepression => LOCALES
+                        .lookup(
+                            lang_selector_to_language_id(lang_selector),
+                            "stress_scenario.great_depression"
+                        )
+                        .unwrap_or_default(),
+                    StressScenario::Stagflation => LOCALES
+                        .lookup(
+                            lang_selector_to_language_id(lang_selector),
+                            "stress_scenario.stagflation"
+                        )
+                        .unwrap_or_default(),
+                    StressScenario::LostDecade => LOCALES
+                        .lookup(
+                            lang_selector_to_language_id(lang_selector),
+                            "stress_scenario.lost_decade"
+                        )
+                        .unwrap_or_default(),
+                    StressScenario::FinancialCrisis => LOCALES
+                        .lookup(
+                            lang_selector_to_language_id(lang_selector),
+                            "stress_scenario.financial_crisis"
+                        )
+                        .unwrap_or_default(),
+                },
                 I18nEnums::TaxTreatment(lang_selector, e) => match e {
                     TaxTreatment::TaxableAccount => LOCALES
                         .lookup(
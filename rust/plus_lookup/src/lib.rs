@@ -6,6 +6,11 @@
 ////////////////////////////////////////////////////////////////////////////////////
 pub use self::currency_exchange::CurrencyExchange;
 pub use self::currency_exchange::WEB_CURRENCY_EXCHANGE;
+pub use self::currency_exchange_history::CurrencyExchangeHistory;
+pub use self::currency_exchange_history::RateLookup;
+pub use self::currency_exchange_history::WEB_CURRENCY_EXCHANGE_HISTORY;
+pub use self::currency_rate_file::RateFileError;
+pub use self::currency_rate_file::RateRecord;
 pub use self::currency_value::CurrencyValue;
 pub use self::i18n_enum_display::CommonStrings;
 pub use self::i18n_enum_display::I18nEnums;
@@ -18,6 +23,8 @@ pub use plus_utils::SystemUnicodes;
 // --- mod decls ---
 ////////////////////////////////////////////////////////////////////////////////////
 pub mod currency_exchange;
+pub mod currency_exchange_history;
+pub mod currency_rate_file;
 pub mod currency_value;
 pub mod i18n;
 pub mod i18n_enum_display;
@@ -5,6 +5,8 @@
 ////////////////////////////////////////////////////////////////////////////////////
 use once_cell::sync::Lazy;
 use plus_modeled::RateCurve;
+use plus_modeled::SimulationMode;
+use plus_modeled::StochasticInflation;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- lazy inits ---
@@ -28,10 +30,15 @@ pub struct SystemDefaults {
     pub display_inflation: RateCurve,
     /// Inflation sometimes used as a default when user does not supply
     pub generic_inflation: RateCurve,
+    /// Inflation drawn per run in a MC forecast, in place of `generic_inflation`
+    pub stochastic_inflation: StochasticInflation,
     /// Cost of capital used to charge deficits in a forecast
     pub cost_of_capital: RateCurve,
     /// Default number of runs in a MC forecast
     pub forecast_count: usize,
+    /// Way a forecast simulates returns, parametric or historic bootstrap, unless its
+    /// `ForecastConfig` selects one
+    pub simulation_mode: SimulationMode,
 }
 
 // α <mod-def system_defaults>
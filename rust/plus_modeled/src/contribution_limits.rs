@@ -0,0 +1,513 @@
+//! Annual IRS contribution limits for tax advantaged accounts.
+//!
+//! Limits are those of tax year 2024 and are projected to later years by indexing with
+//! an assumed inflation, rounded down to the increments the IRS uses. Contributions are
+//! grouped by the limit they count against:
+//!
+//!  - _IRA_ contributions (`TraditionalIra`, or any account with `IraContributions`)
+//!    share one limit, with a catch-up from age 50. Roth IRA contributions
+//!    (`RothIrs401K` with `IraContributions`) are further reduced by the income phase-out.
+//!  - Elective deferrals to `TraditionalIrs401K` and `RothIrs401K` share one limit,
+//!    with a catch-up from age 50.
+//!  - `HealthSavingsAccount` limits depend on single or family coverage, with a
+//!    catch-up from age 55.
+//!  - `CollegeIrs529` has no IRS limit, so the annual gift tax exclusion is used.
+//!  - `RetirementCredits` are limited to the contributions qualifying for the saver's credit.
+//!
+//! The HSA catch-up and the saver's credit contributions are fixed by statute, so unlike the
+//! other limits they are not indexed.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::AccountType;
+use crate::FlowType;
+use crate::PersonType;
+use crate::TaxUsFilingStatus;
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Tax year of the base limits.
+pub const CONTRIBUTION_LIMITS_YEAR: u32 = 2024;
+
+/// Smallest reduced Roth IRA limit allowed when not fully phased out.
+pub const ROTH_IRA_MINIMUM_REDUCED_LIMIT: f64 = 200.0;
+
+/// IRA catch-up from age 50, indexed in $100 increments from 2024 under SECURE 2.0.
+pub const IRA_CATCH_UP: f64 = 1_000.0;
+
+/// HSA catch-up from age 55, fixed by statute.
+pub const HSA_CATCH_UP: f64 = 1_000.0;
+
+/// Contributions qualifying for the saver's credit, fixed by statute.
+pub const SAVERS_CREDIT_CONTRIBUTION_LIMIT: f64 = 2_000.0;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The annual limit a contribution counts against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ContributionLimitGroup {
+    /// Combined traditional and Roth IRA limit.
+    Ira,
+    /// Roth IRA limit after the income phase-out.
+    RothIra,
+    /// Combined traditional and Roth 401K elective deferral limit.
+    ElectiveDeferral,
+    /// Health savings account limit.
+    HealthSavings,
+    /// 529 contributions, limited to the annual gift tax exclusion.
+    College529,
+    /// Contributions qualifying for the saver's credit.
+    SaversCredit,
+}
+
+/// Coverage of the high deductible health plan backing an HSA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HsaCoverage {
+    /// Self-only coverage.
+    SelfOnly,
+    /// Family coverage.
+    Family,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Person making contributions, with what determines their limits.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Contributor {
+    /// Role of the contributor in the dossier.
+    pub person_type: PersonType,
+    /// Year of birth, which determines catch-up eligibility.
+    pub birth_year: u32,
+    /// Filing status, which determines the Roth IRA phase-out range.
+    pub filing_status: TaxUsFilingStatus,
+    /// Modified adjusted gross income in today's dollars, grown with inflation.
+    pub modified_agi: f64,
+    /// Coverage of the health plan, if HSA eligible.
+    pub hsa_coverage: Option<HsaCoverage>,
+}
+
+/// A planned contribution.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Contribution {
+    /// Year of the contribution.
+    pub year: u32,
+    /// Person contributing.
+    pub person_type: PersonType,
+    /// Account contributed to.
+    pub account_type: AccountType,
+    /// Flow type of the contribution.
+    pub flow_type: FlowType,
+    /// Amount contributed.
+    pub amount: f64,
+}
+
+/// Contributions of a person in a year exceeding a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContributionLimitViolation {
+    /// Year of the contributions.
+    pub year: u32,
+    /// Person contributing.
+    pub person_type: PersonType,
+    /// Limit exceeded.
+    pub group: ContributionLimitGroup,
+    /// Total contributed against the limit.
+    pub contributed: f64,
+    /// The limit.
+    pub limit: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The limit of `group` for `contributor` in `year`, including any catch-up and phase-out.
+///
+///   * **group** - Limit of interest.
+///   * **contributor** - Person contributing.
+///   * **year** - Year of the contribution.
+///   * **inflation** - Assumed annual indexing from `CONTRIBUTION_LIMITS_YEAR`.
+///   * _return_ - The limit, 0 if not eligible.
+pub fn contribution_limit(
+    group: ContributionLimitGroup,
+    contributor: &Contributor,
+    year: u32,
+    inflation: f64,
+) -> f64 {
+    // α <fn contribution_limit>
+
+    let age = year.saturating_sub(contributor.birth_year);
+    let index = |amount: f64, increment: f64| {
+        (amount * index_factor(year, inflation) / increment).floor() * increment
+    };
+    let catch_up = |catch_up_age: u32, amount: f64| {
+        if age >= catch_up_age {
+            amount
+        } else {
+            0.0
+        }
+    };
+
+    match group {
+        ContributionLimitGroup::Ira => {
+            index(7_000.0, 500.0) + catch_up(50, index(IRA_CATCH_UP, 100.0))
+        }
+        ContributionLimitGroup::RothIra => {
+            let limit =
+                contribution_limit(ContributionLimitGroup::Ira, contributor, year, inflation);
+            let (lower, upper) = roth_ira_phase_out(contributor.filing_status);
+            let (lower, upper) = (index(lower, 1_000.0), index(upper, 1_000.0));
+            let magi = contributor.modified_agi * index_factor(year, inflation);
+            if magi <= lower {
+                limit
+            } else if magi >= upper {
+                0.0
+            } else {
+                // Reduced limit is rounded up to the next $10
+                let reduced = (limit * (upper - magi) / (upper - lower) / 10.0).ceil() * 10.0;
+                reduced.max(ROTH_IRA_MINIMUM_REDUCED_LIMIT)
+            }
+        }
+        ContributionLimitGroup::ElectiveDeferral => {
+            index(23_000.0, 500.0) + catch_up(50, index(7_500.0, 500.0))
+        }
+        ContributionLimitGroup::HealthSavings => match contributor.hsa_coverage {
+            Some(HsaCoverage::SelfOnly) => index(4_150.0, 50.0) + catch_up(55, HSA_CATCH_UP),
+            Some(HsaCoverage::Family) => index(8_300.0, 50.0) + catch_up(55, HSA_CATCH_UP),
+            None => 0.0,
+        },
+        ContributionLimitGroup::College529 => index(18_000.0, 1_000.0),
+        ContributionLimitGroup::SaversCredit => SAVERS_CREDIT_CONTRIBUTION_LIMIT,
+    }
+
+    // ω <fn contribution_limit>
+}
+
+/// Check contributions against their limits and optionally cap them.
+///
+/// Contributions are applied in order, so when capping, later contributions of a person
+/// in a year are reduced first. Contributions of persons not in `contributors` or to
+/// accounts without a limit are passed through unchecked.
+///
+///   * **contributions** - Planned contributions.
+///   * **contributors** - Persons contributing.
+///   * **inflation** - Assumed annual indexing of limits.
+///   * **cap** - If set contributions are reduced to fit their limits.
+///   * _return_ - The contributions, capped if requested, and the violations of the plan as entered.
+pub fn apply_contribution_limits(
+    contributions: &[Contribution],
+    contributors: &[Contributor],
+    inflation: f64,
+    cap: bool,
+) -> (Vec<Contribution>, Vec<ContributionLimitViolation>) {
+    // α <fn apply_contribution_limits>
+
+    let mut contributed = HashMap::<(u32, PersonType, ContributionLimitGroup), f64>::new();
+    let mut applied = HashMap::<(u32, PersonType, ContributionLimitGroup), f64>::new();
+    let mut limits = HashMap::<(u32, PersonType, ContributionLimitGroup), f64>::new();
+
+    let capped = contributions
+        .iter()
+        .map(|contribution| {
+            let contributor = contributors
+                .iter()
+                .find(|contributor| contributor.person_type == contribution.person_type);
+            let Some(contributor) = contributor else {
+                return *contribution;
+            };
+
+            let keys = ContributionLimitGroup::groups_of(
+                contribution.account_type,
+                contribution.flow_type,
+            )
+            .into_iter()
+            .map(|group| (contribution.year, contribution.person_type, group))
+            .collect::<Vec<_>>();
+
+            let remaining = keys
+                .iter()
+                .map(|key| {
+                    let limit = *limits.entry(*key).or_insert_with(|| {
+                        contribution_limit(key.2, contributor, key.0, inflation)
+                    });
+                    *contributed.entry(*key).or_default() += contribution.amount;
+                    (limit - applied.get(key).copied().unwrap_or_default()).max(0.0)
+                })
+                .fold(f64::MAX, f64::min);
+
+            let amount = if cap {
+                contribution.amount.min(remaining)
+            } else {
+                contribution.amount
+            };
+            keys.iter()
+                .for_each(|key| *applied.entry(*key).or_default() += amount);
+
+            Contribution {
+                amount,
+                ..*contribution
+            }
+        })
+        .collect();
+
+    let mut violations = contributed
+        .into_iter()
+        .filter_map(|(key, contributed)| {
+            let limit = limits[&key];
+            (contributed > limit).then_some(ContributionLimitViolation {
+                year: key.0,
+                person_type: key.1,
+                group: key.2,
+                contributed,
+                limit,
+            })
+        })
+        .collect::<Vec<_>>();
+    violations.sort_by_key(|violation| {
+        (
+            violation.year,
+            violation.person_type as i32,
+            violation.group as i32,
+        )
+    });
+
+    (capped, violations)
+
+    // ω <fn apply_contribution_limits>
+}
+
+/// Factor indexing `CONTRIBUTION_LIMITS_YEAR` amounts to `year`.
+///
+///   * **year** - Year to index to.
+///   * **inflation** - Assumed annual inflation.
+///   * _return_ - The compounded inflation factor.
+#[inline]
+fn index_factor(year: u32, inflation: f64) -> f64 {
+    // α <fn index_factor>
+    (1.0 + inflation).powi(year as i32 - CONTRIBUTION_LIMITS_YEAR as i32)
+    // ω <fn index_factor>
+}
+
+/// Modified AGI range over which the Roth IRA limit phases out.
+///
+///   * **filing_status** - Filing status of the contributor.
+///   * _return_ - The start and end of the phase-out.
+fn roth_ira_phase_out(filing_status: TaxUsFilingStatus) -> (f64, f64) {
+    // α <fn roth_ira_phase_out>
+
+    match filing_status {
+        TaxUsFilingStatus::MarriedJoint => (230_000.0, 240_000.0),
+        TaxUsFilingStatus::MarriedSeparate => (0.0, 10_000.0),
+        TaxUsFilingStatus::Single | TaxUsFilingStatus::HeadOfHousehold => (146_000.0, 161_000.0),
+    }
+
+    // ω <fn roth_ira_phase_out>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl ContributionLimitGroup {
+    /// The limits a contribution counts against.
+    ///
+    ///   * **account_type** - Account contributed to.
+    ///   * **flow_type** - Flow type of the contribution.
+    ///   * _return_ - The limits, empty if the contribution is not limited.
+    pub fn groups_of(
+        account_type: AccountType,
+        flow_type: FlowType,
+    ) -> Vec<ContributionLimitGroup> {
+        // α <fn ContributionLimitGroup::groups_of>
+
+        match (account_type, flow_type) {
+            (_, FlowType::RetirementCredits) => vec![ContributionLimitGroup::SaversCredit],
+            (AccountType::RothIrs401K, FlowType::IraContributions) => {
+                vec![ContributionLimitGroup::Ira, ContributionLimitGroup::RothIra]
+            }
+            (AccountType::TraditionalIra, _) | (_, FlowType::IraContributions) => {
+                vec![ContributionLimitGroup::Ira]
+            }
+            (AccountType::TraditionalIrs401K | AccountType::RothIrs401K, _) => {
+                vec![ContributionLimitGroup::ElectiveDeferral]
+            }
+            (AccountType::HealthSavingsAccount, _) => vec![ContributionLimitGroup::HealthSavings],
+            (AccountType::CollegeIrs529, _) => vec![ContributionLimitGroup::College529],
+            _ => Vec::new(),
+        }
+
+        // ω <fn ContributionLimitGroup::groups_of>
+    }
+}
+
+impl ContributionLimitViolation {
+    /// Amount contributed over the limit.
+    ///
+    ///   * _return_ - The excess contribution.
+    #[inline]
+    pub fn excess(&self) -> f64 {
+        // α <fn ContributionLimitViolation::excess>
+        self.contributed - self.limit
+        // ω <fn ContributionLimitViolation::excess>
+    }
+}
+
+/// Unit tests for `contribution_limits`
+#[cfg(test)]
+pub mod unit_tests {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_contribution_limit() {
+        // α <fn test_contribution_limit>
+
+        let mut contributor = sample_contributor();
+        let limit = |group, contributor: &Contributor, year| {
+            contribution_limit(group, contributor, year, 0.0)
+        };
+
+        assert_eq!(
+            7_000.0,
+            limit(ContributionLimitGroup::Ira, &contributor, 2024)
+        );
+        assert_eq!(
+            8_000.0,
+            limit(ContributionLimitGroup::Ira, &contributor, 2030)
+        );
+        assert_eq!(
+            23_000.0,
+            limit(ContributionLimitGroup::ElectiveDeferral, &contributor, 2024)
+        );
+        assert_eq!(
+            30_500.0,
+            limit(ContributionLimitGroup::ElectiveDeferral, &contributor, 2030)
+        );
+        assert_eq!(
+            8_300.0,
+            limit(ContributionLimitGroup::HealthSavings, &contributor, 2024)
+        );
+        assert_eq!(
+            9_300.0,
+            limit(ContributionLimitGroup::HealthSavings, &contributor, 2035)
+        );
+
+        // Indexed limits round down to the IRS increment
+        assert_eq!(
+            7_000.0,
+            contribution_limit(ContributionLimitGroup::Ira, &contributor, 2025, 0.05)
+        );
+        assert_eq!(
+            7_500.0,
+            contribution_limit(ContributionLimitGroup::Ira, &contributor, 2026, 0.05)
+        );
+
+        // The IRA catch-up is indexed in $100 increments, the saver's credit is not indexed
+        assert_eq!(
+            7_500.0 + 1_100.0,
+            contribution_limit(ContributionLimitGroup::Ira, &contributor, 2030, 0.02)
+        );
+        assert_eq!(
+            SAVERS_CREDIT_CONTRIBUTION_LIMIT,
+            contribution_limit(
+                ContributionLimitGroup::SaversCredit,
+                &contributor,
+                2030,
+                0.02
+            )
+        );
+
+        // Roth IRA phases out between 146_000 and 161_000 for single filers
+        assert_eq!(
+            7_000.0,
+            limit(ContributionLimitGroup::RothIra, &contributor, 2024)
+        );
+        contributor.modified_agi = 150_000.0;
+        assert_eq!(
+            5_140.0,
+            limit(ContributionLimitGroup::RothIra, &contributor, 2024)
+        );
+        contributor.modified_agi = 160_900.0;
+        assert_eq!(
+            200.0,
+            limit(ContributionLimitGroup::RothIra, &contributor, 2024)
+        );
+        contributor.modified_agi = 161_000.0;
+        assert_eq!(
+            0.0,
+            limit(ContributionLimitGroup::RothIra, &contributor, 2024)
+        );
+
+        contributor.hsa_coverage = None;
+        assert_eq!(
+            0.0,
+            limit(ContributionLimitGroup::HealthSavings, &contributor, 2024)
+        );
+
+        // ω <fn test_contribution_limit>
+    }
+
+    #[test]
+    fn test_apply_contribution_limits() {
+        // α <fn test_apply_contribution_limits>
+
+        let contribution = |account_type, flow_type, amount| Contribution {
+            year: 2024,
+            person_type: PersonType::PrimaryOwner,
+            account_type,
+            flow_type,
+            amount,
+        };
+        let contributions = [
+            contribution(
+                AccountType::TraditionalIra,
+                FlowType::IraContributions,
+                5_000.0,
+            ),
+            contribution(
+                AccountType::RothIrs401K,
+                FlowType::IraContributions,
+                5_000.0,
+            ),
+            contribution(AccountType::RothIrs401K, FlowType::OtherOutFlow, 20_000.0),
+            contribution(AccountType::Taxable, FlowType::OtherOutFlow, 50_000.0),
+        ];
+        let contributors = [sample_contributor()];
+
+        let (unchanged, violations) =
+            apply_contribution_limits(&contributions, &contributors, 0.0, false);
+        assert_eq!(contributions.to_vec(), unchanged);
+        assert_eq!(1, violations.len());
+        assert_eq!(ContributionLimitGroup::Ira, violations[0].group);
+        assert_eq!(3_000.0, violations[0].excess());
+
+        let (capped, violations) =
+            apply_contribution_limits(&contributions, &contributors, 0.0, true);
+        assert_eq!(1, violations.len());
+        assert_eq!(
+            vec![5_000.0, 2_000.0, 20_000.0, 50_000.0],
+            capped.iter().map(|c| c.amount).collect::<Vec<_>>()
+        );
+
+        // ω <fn test_apply_contribution_limits>
+    }
+
+    fn sample_contributor() -> Contributor {
+        Contributor {
+            person_type: PersonType::PrimaryOwner,
+            birth_year: 1980,
+            filing_status: TaxUsFilingStatus::Single,
+            modified_agi: 100_000.0,
+            hsa_coverage: Some(HsaCoverage::Family),
+        }
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def contribution_limits>
+// ω <mod-def contribution_limits>
@@ -172,18 +172,18 @@ pub struct DossierHoldingIndex {
 pub struct DossierItemIndex {
     ///
     /// Provides an index into an item in the `Dossier` that may have growth characteristics.
-    /// The item may be a `Worth`, `Holding` or `FlowSpec`.
+    /// The item may be a `Worth`, `Holding`, `FlowSpec`, a named rate curve (e.g. inflation) or a currency.
     /// This definition is kept in _core_ because, while it is specific to a specific `dossier`
     /// it is simply an index. The dossier itself may store these indexes in some places to
     /// associate items with accounts and/or specific holdings.
-    #[prost(oneof = "dossier_item_index::ItemIndex", tags = "1, 2, 3")]
+    #[prost(oneof = "dossier_item_index::ItemIndex", tags = "1, 2, 3, 4, 5")]
     pub item_index: ::core::option::Option<dossier_item_index::ItemIndex>,
 }
 /// Nested message and enum types in `DossierItemIndex`.
 pub mod dossier_item_index {
     ///
     /// Provides an index into an item in the `Dossier` that may have growth characteristics.
-    /// The item may be a `Worth`, `Holding` or `FlowSpec`.
+    /// The item may be a `Worth`, `Holding`, `FlowSpec`, a named rate curve (e.g. inflation) or a currency.
     /// This definition is kept in _core_ because, while it is specific to a specific `dossier`
     /// it is simply an index. The dossier itself may store these indexes in some places to
     /// associate items with accounts and/or specific holdings.
@@ -203,6 +203,14 @@ pub mod dossier_item_index {
         /// Index into the `FlowSpecs` of a `dossier`.
         #[prost(uint32, tag = "3")]
         FlowIndex(u32),
+        ///
+        /// A `NamedRateCurve`, allowing e.g. stochastic inflation to be correlated with holdings.
+        #[prost(enumeration = "super::super::core_enums::NamedRateCurve", tag = "4")]
+        RateCurveIndex(i32),
+        ///
+        /// A `Currency`, allowing its exchange rate to USD to be correlated with holdings.
+        #[prost(enumeration = "super::super::core_enums::Currency", tag = "5")]
+        CurrencyIndex(i32),
     }
 }
 ///
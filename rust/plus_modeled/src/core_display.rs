@@ -238,6 +238,20 @@ impl Display for DossierItemIndex {
                 Some(ItemIndex::FlowIndex(f)) => {
                     format!("{}({})", SystemUnicodes::Faucet.as_unicode(), f)
                 }
+                Some(ItemIndex::RateCurveIndex(f)) => format!(
+                    "{}({})",
+                    SystemUnicodes::StockChart.as_unicode(),
+                    crate::NamedRateCurve::from_i32(f)
+                        .map(|named_rate_curve| named_rate_curve.as_str_name())
+                        .unwrap_or_default()
+                ),
+                Some(ItemIndex::CurrencyIndex(f)) => format!(
+                    "{}({})",
+                    SystemUnicodes::SmallDollar.as_unicode(),
+                    crate::Currency::from_i32(f)
+                        .map(|currency| currency.as_str_name())
+                        .unwrap_or_default()
+                ),
                 None => String::default(),
             }
         )
@@ -0,0 +1,289 @@
+//! Correlated shocks to dossier items.
+//!
+//! Simulations of holdings together with other random quantities (inflation, exchange rates)
+//! draw one standard normal shock per item each year. The shocks are correlated as given by a
+//! `DossierCorrelationMatrix`, factored once with `cholesky_decomposition` so each draw is a
+//! single multiplication of independent normals. Each item's `ReturnDistribution` maps its
+//! shock to an annual return, which is how parametric simulations grow items.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::DossierCorrelationMatrix;
+use crate::DossierItemIndex;
+use crate::ReturnDistribution;
+use ndarray::Array1;
+use ndarray::Array2;
+use plus_utils::cholesky_decomposition;
+use plus_utils::correlate_normals;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Samples standard normal shocks to dossier items with their correlations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrelatedShockSampler {
+    /// Items shocked, in the order of the sampled shocks.
+    items: Vec<DossierItemIndex>,
+    /// Cholesky factor of the correlations of the items.
+    cholesky_lower: Array2<f64>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl CorrelatedShockSampler {
+    /// Create a sampler of correlated shocks. Pairs missing from the correlation matrix are
+    /// taken as uncorrelated.
+    ///
+    ///   * **items** - Items to sample shocks for.
+    ///   * **correlation_matrix** - Correlations between the items.
+    ///   * _return_ - The sampler, `None` if the correlations are not a valid correlation matrix.
+    pub fn new(
+        items: &[DossierItemIndex],
+        correlation_matrix: &DossierCorrelationMatrix,
+    ) -> Option<CorrelatedShockSampler> {
+        // α <fn CorrelatedShockSampler::new>
+
+        let correlations = Array2::from_shape_fn((items.len(), items.len()), |(i, j)| {
+            correlation_matrix
+                .correlation(&items[i], &items[j])
+                .unwrap_or_default()
+        });
+
+        Some(CorrelatedShockSampler {
+            items: items.to_vec(),
+            cholesky_lower: cholesky_decomposition(&correlations)?,
+        })
+
+        // ω <fn CorrelatedShockSampler::new>
+    }
+
+    /// Sample a shock for each item.
+    ///
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - Standard normal shock of each item, with the correlations of the items.
+    pub fn sample(&self, mut standard_normal: impl FnMut() -> f64) -> Array1<f64> {
+        // α <fn CorrelatedShockSampler::sample>
+        let draws = Array1::from_shape_fn(self.items.len(), |_| standard_normal());
+        correlate_normals(&self.cholesky_lower, &draws)
+        // ω <fn CorrelatedShockSampler::sample>
+    }
+
+    /// Sample a year of returns, one for each item from its own distribution.
+    ///
+    ///   * **return_distributions** - Distribution of the annual return of each item, in the order of the items.
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - The return of each item, `None` if the distributions are invalid or not one per item.
+    pub fn sample_returns(
+        &self,
+        return_distributions: &[ReturnDistribution],
+        standard_normal: impl FnMut() -> f64,
+    ) -> Option<Vec<f64>> {
+        // α <fn CorrelatedShockSampler::sample_returns>
+
+        if return_distributions.len() != self.items.len() {
+            return None;
+        }
+
+        return_distributions
+            .iter()
+            .zip(self.sample(standard_normal))
+            .map(|(return_distribution, shock)| return_distribution.sample_shock(shock))
+            .collect()
+
+        // ω <fn CorrelatedShockSampler::sample_returns>
+    }
+
+    /// Sample a path of returns.
+    ///
+    ///   * **return_distributions** - Distribution of the annual return of each item, in the order of the items.
+    ///   * **num_years** - Number of years in the path.
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - The returns of the items for each year, `None` as for `sample_returns`.
+    pub fn sample_return_path(
+        &self,
+        return_distributions: &[ReturnDistribution],
+        num_years: usize,
+        mut standard_normal: impl FnMut() -> f64,
+    ) -> Option<Vec<Vec<f64>>> {
+        // α <fn CorrelatedShockSampler::sample_return_path>
+        (0..num_years)
+            .map(|_| self.sample_returns(return_distributions, &mut standard_normal))
+            .collect()
+        // ω <fn CorrelatedShockSampler::sample_return_path>
+    }
+}
+
+/// Accessors for [CorrelatedShockSampler] fields
+impl CorrelatedShockSampler {
+    #[inline]
+    pub fn get_items(&self) -> &[DossierItemIndex] {
+        &self.items
+    }
+}
+
+/// Unit tests for `correlated_shocks`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type CorrelatedShockSampler
+    mod test_correlated_shock_sampler {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::CorrelatedShockSampler;
+        use crate::DossierCorrelationMatrix;
+        use crate::DossierItemIndex;
+        use crate::ItemIndex;
+        use crate::NormalSpec;
+        use crate::ReturnDistribution;
+        use ndarray::array;
+        use ndarray::Array1;
+        use plus_utils::IncrementalPearson;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn new() {
+            // α <fn test CorrelatedShockSampler::new>
+
+            let stocks = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(0)),
+            };
+            let bonds = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(1)),
+            };
+
+            // Missing pairs are uncorrelated
+            let shock_sampler =
+                CorrelatedShockSampler::new(&[stocks, bonds], &DossierCorrelationMatrix::default())
+                    .unwrap();
+            assert_eq!(&[stocks, bonds], shock_sampler.get_items());
+            assert_eq!(
+                array![1.0, 2.0],
+                shock_sampler.sample({
+                    let mut draws = [1.0, 2.0].into_iter();
+                    move || draws.next().unwrap()
+                })
+            );
+
+            // Correlations above one are not valid
+            let correlation_matrix = DossierCorrelationMatrix::from_correlations(
+                &[stocks, bonds],
+                &array![[1.0, 1.5], [1.5, 1.0]],
+            );
+            assert_eq!(
+                None,
+                CorrelatedShockSampler::new(&[stocks, bonds], &correlation_matrix)
+            );
+
+            // ω <fn test CorrelatedShockSampler::new>
+        }
+
+        #[test]
+        fn sample() {
+            // α <fn test CorrelatedShockSampler::sample>
+
+            let stocks = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(0)),
+            };
+            let bonds = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(1)),
+            };
+            let correlation_matrix = DossierCorrelationMatrix::from_correlations(
+                &[stocks, bonds],
+                &array![[1.0, 0.6], [0.6, 1.0]],
+            );
+            let shock_sampler =
+                CorrelatedShockSampler::new(&[stocks, bonds], &correlation_matrix).unwrap();
+
+            // The first item takes its draw, the second mixes in the first's
+            let shocks = shock_sampler.sample({
+                let mut draws = [1.0, 1.0].into_iter();
+                move || draws.next().unwrap()
+            });
+            assert!((shocks[0] - 1.0).abs() < 1e-12);
+            assert!((shocks[1] - (0.6 + 0.8)).abs() < 1e-12);
+
+            // ω <fn test CorrelatedShockSampler::sample>
+        }
+
+        #[test]
+        fn sample_returns() {
+            // α <fn test CorrelatedShockSampler::sample_returns>
+
+            let stocks = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(0)),
+            };
+            let bonds = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(1)),
+            };
+            let correlation_matrix = DossierCorrelationMatrix::from_correlations(
+                &[stocks, bonds],
+                &array![[1.0, 0.6], [0.6, 1.0]],
+            );
+            let shock_sampler =
+                CorrelatedShockSampler::new(&[stocks, bonds], &correlation_matrix).unwrap();
+            let return_distributions = [
+                ReturnDistribution::StudentT {
+                    mean: 0.08,
+                    std_dev: 0.18,
+                    degrees_of_freedom: 4.0,
+                },
+                ReturnDistribution::from(NormalSpec {
+                    mean: 0.03,
+                    std_dev: 0.05,
+                }),
+            ];
+
+            // Each return is its item's correlated shock mapped through the item's distribution
+            let returns = shock_sampler
+                .sample_returns(&return_distributions, || 1.0)
+                .unwrap();
+            assert!(
+                (returns[0] - return_distributions[0].sample_shock(1.0).unwrap()).abs() < 1e-12
+            );
+            assert!((returns[1] - (0.03 + 0.05 * 1.4)).abs() < 1e-9);
+
+            assert_eq!(
+                None,
+                shock_sampler.sample_returns(&return_distributions[..1], || 1.0)
+            );
+
+            // Deterministic stand in for standard normal draws
+            let mut state = 12345_u64;
+            let standard_normal = move || {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let uniform = ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+                plus_utils::standard_normal_inverse_cdf(uniform).unwrap_or_default()
+            };
+
+            // Returns are correlated through the shocks
+            let mut incremental_pearson = IncrementalPearson::new(2);
+            for returns in shock_sampler
+                .sample_return_path(&return_distributions, 20_000, standard_normal)
+                .unwrap()
+            {
+                incremental_pearson.track_row(&Array1::from(returns));
+            }
+            let correlations = incremental_pearson.correlation_matrix().unwrap();
+            assert!(correlations[[0, 1]] > 0.5 && correlations[[0, 1]] < 0.63);
+
+            // ω <fn test CorrelatedShockSampler::sample_returns>
+        }
+
+        // α <mod-def test_correlated_shock_sampler>
+        // ω <mod-def test_correlated_shock_sampler>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def correlated_shocks>
+// ω <mod-def correlated_shocks>
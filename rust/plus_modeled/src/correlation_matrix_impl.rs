@@ -0,0 +1,146 @@
+//! An impl for struct dossier correlation matrix
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::DossierCorrelationEntry;
+use crate::DossierCorrelationMatrix;
+use crate::DossierItemIndex;
+use ndarray::Array2;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl DossierCorrelationMatrix {
+    /// Create from a correlation matrix (e.g. estimated by `IncrementalPearson`).
+    /// Only the entries below the diagonal are stored, as the matrix is symmetric
+    /// with ones on the diagonal.
+    ///
+    ///   * **items** - Dossier item of each row/column of `correlations`.
+    ///   * **correlations** - Square correlation matrix.
+    ///   * _return_ - The dossier correlation matrix.
+    pub fn from_correlations(
+        items: &[DossierItemIndex],
+        correlations: &Array2<f64>,
+    ) -> DossierCorrelationMatrix {
+        // α <fn DossierCorrelationMatrix::from_correlations>
+
+        debug_assert_eq!((items.len(), items.len()), correlations.dim());
+
+        DossierCorrelationMatrix {
+            mappings: items
+                .iter()
+                .enumerate()
+                .flat_map(|(i, row_index)| {
+                    items
+                        .iter()
+                        .take(i)
+                        .enumerate()
+                        .map(move |(j, column_index)| DossierCorrelationEntry {
+                            row_index: Some(*row_index),
+                            column_index: Some(*column_index),
+                            correlation: correlations[(i, j)],
+                        })
+                })
+                .collect(),
+        }
+
+        // ω <fn DossierCorrelationMatrix::from_correlations>
+    }
+
+    /// Get the correlation of a pair of items, in either order.
+    ///
+    ///   * **row_index** - First item.
+    ///   * **column_index** - Second item.
+    ///   * _return_ - The correlation, 1 for an item with itself, `None` if not present.
+    pub fn correlation(
+        &self,
+        row_index: &DossierItemIndex,
+        column_index: &DossierItemIndex,
+    ) -> Option<f64> {
+        // α <fn DossierCorrelationMatrix::correlation>
+
+        if row_index == column_index {
+            Some(1.0)
+        } else {
+            self.mappings
+                .iter()
+                .find(|entry| {
+                    (entry.row_index.as_ref() == Some(row_index)
+                        && entry.column_index.as_ref() == Some(column_index))
+                        || (entry.row_index.as_ref() == Some(column_index)
+                            && entry.column_index.as_ref() == Some(row_index))
+                })
+                .map(|entry| entry.correlation)
+        }
+
+        // ω <fn DossierCorrelationMatrix::correlation>
+    }
+}
+
+/// Unit tests for `correlation_matrix_impl`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type DossierCorrelationMatrix
+    mod test_dossier_correlation_matrix {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn from_correlations() {
+            // α <fn test DossierCorrelationMatrix::from_correlations>
+
+            use crate::ItemIndex;
+            use ndarray::arr2;
+
+            let items = [
+                DossierItemIndex {
+                    item_index: Some(ItemIndex::WorthIndex(0)),
+                },
+                DossierItemIndex {
+                    item_index: Some(ItemIndex::FlowIndex(0)),
+                },
+                DossierItemIndex {
+                    item_index: Some(ItemIndex::FlowIndex(1)),
+                },
+            ];
+            let correlations = arr2(&[[1.0, 0.3, -0.2], [0.3, 1.0, 0.5], [-0.2, 0.5, 1.0]]);
+
+            let matrix = DossierCorrelationMatrix::from_correlations(&items, &correlations);
+            assert_eq!(3, matrix.mappings.len());
+            assert_eq!(Some(1.0), matrix.correlation(&items[1], &items[1]));
+            assert_eq!(Some(0.3), matrix.correlation(&items[0], &items[1]));
+            assert_eq!(Some(0.3), matrix.correlation(&items[1], &items[0]));
+            assert_eq!(Some(-0.2), matrix.correlation(&items[2], &items[0]));
+            assert_eq!(Some(0.5), matrix.correlation(&items[1], &items[2]));
+            assert_eq!(
+                None,
+                matrix.correlation(
+                    &items[0],
+                    &DossierItemIndex {
+                        item_index: Some(ItemIndex::WorthIndex(1)),
+                    }
+                )
+            );
+
+            // ω <fn test DossierCorrelationMatrix::from_correlations>
+        }
+
+        // α <mod-def test_dossier_correlation_matrix>
+        use super::*;
+        // ω <mod-def test_dossier_correlation_matrix>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def correlation_matrix_impl>
+// ω <mod-def correlation_matrix_impl>
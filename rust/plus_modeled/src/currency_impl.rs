@@ -4,46 +4,154 @@
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
 use crate::Currency;
+use crate::LangSelector;
+use num_format::Locale;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Where the currency symbol goes relative to the amount.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolPlacement {
+    /// Symbol precedes the amount, e.g. `$1,234.56`.
+    Before,
+    /// Symbol follows the amount, e.g. `1 234,56 €`.
+    After,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// ISO 4217 and display metadata of a currency.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CurrencyMetadata {
+    /// Alphabetic ISO 4217 code, e.g. `EUR`.
+    pub iso_code: &'static str,
+    /// Numeric ISO 4217 code, e.g. 978 for `EUR`.
+    pub numeric_code: u16,
+    /// Number of decimals of the minor unit, e.g. 2 for cents and 0 for yen.
+    pub minor_units: usize,
+    /// Symbol telling the currency apart from all others, e.g. `A$`.
+    pub symbol: &'static str,
+    /// Narrow symbol for when the currency is clear from context, e.g. `$` for `AUD`.
+    pub narrow_symbol: &'static str,
+    /// Placement of the symbol in US English.
+    pub symbol_placement: SymbolPlacement,
+}
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- type impls ---
 ////////////////////////////////////////////////////////////////////////////////////
 impl Currency {
-    /// Get string representation of value.
+    /// Get string representation of value in the conventions of a language:
+    /// grouping, decimal mark and placement of the symbol. US English places the symbol as
+    /// given by the [CurrencyMetadata], French and German place it after the amount.
+    /// For example 1234.56 euros is `€1,234.56` in US English and `1 234,56 €` in French.
     ///
     ///   * **value** - Value to get as money string.
+    ///   * **lang_selector** - Language of the reader.
     ///   * _return_ - String representation of the value with currency symbol.
     #[inline]
-    pub fn as_money(&self, value: f64) -> String {
+    pub fn as_money(&self, value: f64, lang_selector: LangSelector) -> String {
         // α <fn Currency::as_money>
-
-        use num_format::{Locale, ToFormattedString};
-        let currency_symbol = self.to_currency_symbol();
-        if value < -10.0 {
-            format!(
-                "{}({})",
-                currency_symbol,
-                (-(value.round()) as i64).to_formatted_string(&Locale::en)
-            )
-        } else if value.is_sign_negative() {
-            format!("{}({:.2})", currency_symbol, -value)
-        } else if value < 10.0 {
-            format!("{}{:.2}", currency_symbol, value)
-        } else {
-            format!(
-                "{}{}",
-                currency_symbol,
-                (value.round() as i64).to_formatted_string(&Locale::en)
-            )
+        match lang_selector {
+            LangSelector::UsEnglish => {
+                self.format_money(value, &Locale::en, self.metadata().symbol_placement)
+            }
+            LangSelector::French => self.format_money(value, &Locale::fr, SymbolPlacement::After),
+            LangSelector::German => self.format_money(value, &Locale::de, SymbolPlacement::After),
         }
         // ω <fn Currency::as_money>
     }
 
+    /// Get the ISO 4217 and display metadata of the currency.
+    ///
+    ///   * _return_ - The [CurrencyMetadata]
+    pub fn metadata(&self) -> CurrencyMetadata {
+        // α <fn Currency::metadata>
+
+        use SymbolPlacement::{After, Before};
+
+        let (numeric_code, minor_units, narrow_symbol, symbol_placement) = match self {
+            Currency::Usd => (840, 2, "$", Before),
+            Currency::Eur => (978, 2, "€", Before),
+            Currency::Jpy => (392, 0, "¥", Before),
+            Currency::Gbp => (826, 2, "£", Before),
+            Currency::Aud => (36, 2, "$", Before),
+            Currency::Cad => (124, 2, "$", Before),
+            Currency::Chf => (756, 2, "CHF", Before),
+            Currency::Cny => (156, 2, "¥", Before),
+            Currency::Hkd => (344, 2, "$", Before),
+            Currency::Nzd => (554, 2, "$", Before),
+            Currency::Crc => (188, 2, "₡", Before),
+            Currency::Rub => (643, 2, "₽", After),
+            Currency::Krw => (410, 0, "₩", Before),
+            Currency::Sek => (752, 2, "kr", After),
+        };
+
+        CurrencyMetadata {
+            iso_code: self.as_str_name(),
+            numeric_code,
+            minor_units,
+            symbol: self.to_currency_symbol(),
+            narrow_symbol,
+            symbol_placement,
+        }
+
+        // ω <fn Currency::metadata>
+    }
+
+    /// Format value rounded to the minor unit with the grouping and decimal mark of `locale`.
+    /// Alphabetic symbols before the amount, and all symbols after it, are set apart by a
+    /// non-breaking space so the amount never wraps away from its symbol.
+    ///
+    ///   * **value** - Value to format.
+    ///   * **locale** - Locale supplying grouping and decimal mark.
+    ///   * **symbol_placement** - Where the symbol goes.
+    ///   * _return_ - The formatted money
+    fn format_money(
+        &self,
+        value: f64,
+        locale: &Locale,
+        symbol_placement: SymbolPlacement,
+    ) -> String {
+        // α <fn Currency::format_money>
+
+        use num_format::ToFormattedString;
+        const NO_BREAK_SPACE: char = '\u{a0}';
+
+        let metadata = self.metadata();
+        let rounded = format!("{:.*}", metadata.minor_units, value.abs());
+        let (whole, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+        let mut amount = whole
+            .parse::<u64>()
+            .map(|whole| whole.to_formatted_string(locale))
+            .unwrap_or_else(|_| whole.to_string());
+        if !fraction.is_empty() {
+            amount.push_str(locale.decimal());
+            amount.push_str(fraction);
+        }
+
+        let is_negative = value < 0.0 && rounded.chars().any(|c| c.is_ascii_digit() && c != '0');
+        let sign = if is_negative { "-" } else { "" };
+        let symbol = metadata.symbol;
+        match symbol_placement {
+            SymbolPlacement::Before if symbol.ends_with(char::is_alphabetic) => {
+                format!("{sign}{symbol}{NO_BREAK_SPACE}{amount}")
+            }
+            SymbolPlacement::Before => format!("{sign}{symbol}{amount}"),
+            SymbolPlacement::After => format!("{sign}{amount}{NO_BREAK_SPACE}{symbol}"),
+        }
+
+        // ω <fn Currency::format_money>
+    }
+
     /// Show single value in original currency and its new converted form.
     ///
     ///   * **original_value** - Value before conversion.
     ///   * **original_currency** - Original currency before conversion to `self`.
     ///   * **converted_value** - Converted value.
+    ///   * **lang_selector** - Language of the reader.
     ///   * _return_ - String representation of the original and converted value.
     #[inline]
     pub fn converted_value(
@@ -51,16 +159,17 @@ impl Currency {
         original_value: f64,
         original_currency: Currency,
         converted_value: f64,
+        lang_selector: LangSelector,
     ) -> String {
         // α <fn Currency::converted_value>
         if original_value != converted_value {
             format!(
                 "{}->{}",
-                original_currency.as_money(original_value),
-                self.as_money(converted_value)
+                original_currency.as_money(original_value, lang_selector),
+                self.as_money(converted_value, lang_selector)
             )
         } else {
-            original_currency.as_money(original_value)
+            original_currency.as_money(original_value, lang_selector)
         }
         // ω <fn Currency::converted_value>
     }
@@ -162,16 +271,64 @@ pub mod unit_tests {
         #[test]
         fn as_money() {
             // α <fn test Currency::as_money>
-            assert_eq!("CHF100", Currency::Chf.as_money(100.0));
+            use LangSelector::{French, German, UsEnglish};
+            assert_eq!("CHF\u{a0}100.00", Currency::Chf.as_money(100.0, UsEnglish));
+            assert_eq!("$1,234.56", Currency::Usd.as_money(1234.56, UsEnglish));
+            assert_eq!("-$0.50", Currency::Usd.as_money(-0.5, UsEnglish));
+            assert_eq!("$0.00", Currency::Usd.as_money(-0.001, UsEnglish));
+            assert_eq!("¥1,235", Currency::Jpy.as_money(1234.56, UsEnglish));
+            assert_eq!("€1,234.56", Currency::Eur.as_money(1234.56, UsEnglish));
+            // US English follows the placement of the metadata
+            assert_eq!(
+                "1,234.56\u{a0}kr",
+                Currency::Sek.as_money(1234.56, UsEnglish)
+            );
+            assert_eq!(
+                "1\u{202f}234,56\u{a0}€",
+                Currency::Eur.as_money(1234.56, French)
+            );
+            assert_eq!("-1.234,56\u{a0}€", Currency::Eur.as_money(-1234.56, German));
+            assert_eq!(
+                "1.234.568\u{a0}₩",
+                Currency::Krw.as_money(1234567.8, German)
+            );
+            assert_eq!("1.234,56\u{a0}$", Currency::Usd.as_money(1234.56, German));
             // ω <fn test Currency::as_money>
         }
 
+        #[test]
+        fn metadata() {
+            // α <fn test Currency::metadata>
+            let jpy = Currency::Jpy.metadata();
+            assert_eq!("JPY", jpy.iso_code);
+            assert_eq!(392, jpy.numeric_code);
+            assert_eq!(0, jpy.minor_units);
+            assert_eq!(0, Currency::Krw.metadata().minor_units);
+            assert_eq!(2, Currency::Eur.metadata().minor_units);
+            assert_eq!("$", Currency::Aud.metadata().narrow_symbol);
+            assert_eq!("A$", Currency::Aud.metadata().symbol);
+            assert_eq!(
+                SymbolPlacement::After,
+                Currency::Sek.metadata().symbol_placement
+            );
+            // ω <fn test Currency::metadata>
+        }
+
         #[test]
         fn converted_value() {
             // α <fn test Currency::converted_value>
             assert_eq!(
-                "¥14,235->CHF100",
-                Currency::Chf.converted_value(14235.0, Currency::Jpy, 100.0)
+                "¥14,235->CHF\u{a0}100.00",
+                Currency::Chf.converted_value(
+                    14235.0,
+                    Currency::Jpy,
+                    100.0,
+                    LangSelector::UsEnglish
+                )
+            );
+            assert_eq!(
+                "14.235\u{a0}¥->100,00\u{a0}CHF",
+                Currency::Chf.converted_value(14235.0, Currency::Jpy, 100.0, LangSelector::German)
             );
             // ω <fn test Currency::converted_value>
         }
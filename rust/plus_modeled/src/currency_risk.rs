@@ -0,0 +1,354 @@
+//! Simulation of exchange rates for holdings not in the display currency.
+//!
+//! Each currency's rate to USD follows a lognormal random walk:
+//! `rₜ = rₜ₋₁·exp(ln(1 + drift) - σ²/2 + σ·z)`, so the rate is expected to change by `drift`
+//! a year with volatility `σ`. USD is the numeraire, so the rate between any two currencies
+//! is the ratio of their rates to USD and a display currency other than USD carries its own
+//! risk. The shocks `z` are correlated with holdings through a `DossierCorrelationMatrix` in
+//! which a currency is the item `ItemIndex::CurrencyIndex`.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::CorrelatedShockSampler;
+use crate::Currency;
+use crate::DossierCorrelationMatrix;
+use crate::DossierItemIndex;
+use crate::ItemIndex;
+use ndarray::s;
+use ndarray::Array1;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type aliases ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Shocks of the items and rates to USD of the currencies for each year of a path.
+type SampledPath = (Vec<Array1<f64>>, Vec<Vec<f64>>);
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Risk in the exchange rate of a currency to USD.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CurrencyRiskSpec {
+    /// The currency, not USD.
+    pub currency: Currency,
+    /// Expected annual change in the rate to USD.
+    pub drift: f64,
+    /// Annual volatility of the log of the rate to USD, e.g. 0.08 to 0.10 for EUR and GBP.
+    pub volatility: f64,
+}
+
+/// Samples exchange rate paths jointly with correlated shocks to holdings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyRiskSimulator {
+    /// Currencies simulated, in the order of the sampled rates.
+    pub currency_risk_specs: Vec<CurrencyRiskSpec>,
+    /// Holdings correlated with the currencies, in the order of the sampled shocks.
+    pub items: Vec<DossierItemIndex>,
+    /// Shocks to the items followed by the currencies.
+    shock_sampler: CorrelatedShockSampler,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl CurrencyRiskSpec {
+    /// The item identifying the currency in a `DossierCorrelationMatrix`.
+    ///
+    ///   * _return_ - The dossier item index.
+    pub fn dossier_item_index(&self) -> DossierItemIndex {
+        // α <fn CurrencyRiskSpec::dossier_item_index>
+        DossierItemIndex {
+            item_index: Some(ItemIndex::CurrencyIndex(self.currency as i32)),
+        }
+        // ω <fn CurrencyRiskSpec::dossier_item_index>
+    }
+
+    /// Rate to USD a year after a rate.
+    ///
+    ///   * **to_usd_rate** - Rate to USD of the prior year.
+    ///   * **standard_normal** - Draw from the standard normal driving the change.
+    ///   * _return_ - The rate to USD.
+    #[inline]
+    pub fn next_rate(&self, to_usd_rate: f64, standard_normal: f64) -> f64 {
+        // α <fn CurrencyRiskSpec::next_rate>
+        to_usd_rate
+            * ((1.0 + self.drift).ln() - 0.5 * self.volatility * self.volatility
+                + self.volatility * standard_normal)
+                .exp()
+        // ω <fn CurrencyRiskSpec::next_rate>
+    }
+}
+
+impl CurrencyRiskSimulator {
+    /// Create a simulator of exchange rates correlated with holdings.
+    ///
+    ///   * **currency_risk_specs** - Currencies to simulate.
+    ///   * **items** - Holdings to sample shocks for.
+    ///   * **correlation_matrix** - Correlations between holdings and currencies.
+    ///   * _return_ - The simulator, `None` if USD is included or the correlations are not valid.
+    pub fn new(
+        currency_risk_specs: &[CurrencyRiskSpec],
+        items: &[DossierItemIndex],
+        correlation_matrix: &DossierCorrelationMatrix,
+    ) -> Option<CurrencyRiskSimulator> {
+        // α <fn CurrencyRiskSimulator::new>
+
+        if currency_risk_specs
+            .iter()
+            .any(|currency_risk_spec| currency_risk_spec.currency == Currency::Usd)
+        {
+            return None;
+        }
+
+        let all_items = items
+            .iter()
+            .copied()
+            .chain(
+                currency_risk_specs
+                    .iter()
+                    .map(|currency_risk_spec| currency_risk_spec.dossier_item_index()),
+            )
+            .collect::<Vec<_>>();
+
+        Some(CurrencyRiskSimulator {
+            currency_risk_specs: currency_risk_specs.to_vec(),
+            items: items.to_vec(),
+            shock_sampler: CorrelatedShockSampler::new(&all_items, correlation_matrix)?,
+        })
+
+        // ω <fn CurrencyRiskSimulator::new>
+    }
+
+    /// Sample a year of holding shocks and exchange rates.
+    ///
+    ///   * **to_usd_rates** - Rate to USD of each currency in the prior year.
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - Shock of each item and rate to USD of each currency, `None` if the rates are not one per currency.
+    pub fn sample_year(
+        &self,
+        to_usd_rates: &[f64],
+        mut standard_normal: impl FnMut() -> f64,
+    ) -> Option<(Array1<f64>, Vec<f64>)> {
+        // α <fn CurrencyRiskSimulator::sample_year>
+
+        if to_usd_rates.len() != self.currency_risk_specs.len() {
+            return None;
+        }
+
+        let holding_count = self.items.len();
+        let shocks = self.shock_sampler.sample(&mut standard_normal);
+
+        let rates = self
+            .currency_risk_specs
+            .iter()
+            .zip(to_usd_rates)
+            .zip(shocks.slice(s![holding_count..]))
+            .map(|((currency_risk_spec, to_usd_rate), shock)| {
+                currency_risk_spec.next_rate(*to_usd_rate, *shock)
+            })
+            .collect();
+
+        Some((shocks.slice(s![..holding_count]).to_owned(), rates))
+
+        // ω <fn CurrencyRiskSimulator::sample_year>
+    }
+
+    /// Sample a path of holding shocks and exchange rates.
+    ///
+    ///   * **to_usd_rates** - Rate to USD of each currency today.
+    ///   * **num_years** - Number of years in the path.
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - Shocks of the items and rates to USD of the currencies for each year, `None` as for `sample_year`.
+    pub fn sample_path(
+        &self,
+        to_usd_rates: &[f64],
+        num_years: usize,
+        mut standard_normal: impl FnMut() -> f64,
+    ) -> Option<SampledPath> {
+        // α <fn CurrencyRiskSimulator::sample_path>
+
+        let mut rates = to_usd_rates.to_vec();
+        let mut shocks_by_year = Vec::with_capacity(num_years);
+        let mut rates_by_year = Vec::with_capacity(num_years);
+        for _ in 0..num_years {
+            let (shocks, next_rates) = self.sample_year(&rates, &mut standard_normal)?;
+            rates = next_rates;
+            shocks_by_year.push(shocks);
+            rates_by_year.push(rates.clone());
+        }
+
+        Some((shocks_by_year, rates_by_year))
+
+        // ω <fn CurrencyRiskSimulator::sample_path>
+    }
+}
+
+/// Unit tests for `currency_risk`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type CurrencyRiskSpec
+    mod test_currency_risk_spec {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::Currency;
+        use crate::CurrencyRiskSpec;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn next_rate() {
+            // α <fn test CurrencyRiskSpec::next_rate>
+
+            let currency_risk_spec = CurrencyRiskSpec {
+                currency: Currency::Eur,
+                drift: 0.01,
+                volatility: 0.0,
+            };
+            assert!((currency_risk_spec.next_rate(1.08, 1.5) - 1.08 * 1.01).abs() < 1e-12);
+
+            // The median change is below the drift by the volatility drag
+            let currency_risk_spec = CurrencyRiskSpec {
+                volatility: 0.1,
+                ..currency_risk_spec
+            };
+            assert!(
+                (currency_risk_spec.next_rate(1.0, 0.0) - 1.01 * (-0.005f64).exp()).abs() < 1e-12
+            );
+            assert!(
+                currency_risk_spec.next_rate(1.0, 2.0) > currency_risk_spec.next_rate(1.0, 1.0)
+            );
+
+            // ω <fn test CurrencyRiskSpec::next_rate>
+        }
+
+        // α <mod-def test_currency_risk_spec>
+        // ω <mod-def test_currency_risk_spec>
+    }
+
+    /// Test type CurrencyRiskSimulator
+    mod test_currency_risk_simulator {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::Currency;
+        use crate::CurrencyRiskSimulator;
+        use crate::CurrencyRiskSpec;
+        use crate::DossierCorrelationMatrix;
+        use crate::DossierItemIndex;
+        use crate::ItemIndex;
+        use ndarray::array;
+        use plus_utils::IncrementalStats;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn new() {
+            // α <fn test CurrencyRiskSimulator::new>
+            let usd = CurrencyRiskSpec {
+                currency: Currency::Usd,
+                drift: 0.0,
+                volatility: 0.1,
+            };
+            assert_eq!(
+                None,
+                CurrencyRiskSimulator::new(&[usd], &[], &DossierCorrelationMatrix::default())
+            );
+            // ω <fn test CurrencyRiskSimulator::new>
+        }
+
+        #[test]
+        fn sample_year() {
+            // α <fn test CurrencyRiskSimulator::sample_year>
+
+            let stocks = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(0)),
+            };
+            let eur = CurrencyRiskSpec {
+                currency: Currency::Eur,
+                drift: 0.0,
+                volatility: 0.1,
+            };
+            let gbp = CurrencyRiskSpec {
+                currency: Currency::Gbp,
+                ..eur
+            };
+            let correlation_matrix = DossierCorrelationMatrix::from_correlations(
+                &[stocks, eur.dossier_item_index(), gbp.dossier_item_index()],
+                &array![[1.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            );
+            let simulator =
+                CurrencyRiskSimulator::new(&[eur, gbp], &[stocks], &correlation_matrix).unwrap();
+
+            // EUR moves with stocks, GBP on its own draw
+            let mut draws = [1.0, 5.0, -1.0].into_iter();
+            let (shocks, rates) = simulator
+                .sample_year(&[1.08, 1.25], || draws.next().unwrap())
+                .unwrap();
+            assert_eq!(1, shocks.len());
+            assert!((shocks[0] - 1.0).abs() < 1e-12);
+            assert!((rates[0] - eur.next_rate(1.08, 1.0)).abs() < 1e-12);
+            assert!((rates[1] - gbp.next_rate(1.25, -1.0)).abs() < 1e-12);
+
+            // A rate is required for each currency
+            assert_eq!(None, simulator.sample_year(&[1.08], || 1.0));
+
+            // ω <fn test CurrencyRiskSimulator::sample_year>
+        }
+
+        #[test]
+        fn sample_path() {
+            // α <fn test CurrencyRiskSimulator::sample_path>
+
+            let eur = CurrencyRiskSpec {
+                currency: Currency::Eur,
+                drift: 0.02,
+                volatility: 0.1,
+            };
+            let simulator =
+                CurrencyRiskSimulator::new(&[eur], &[], &DossierCorrelationMatrix::default())
+                    .unwrap();
+
+            // Deterministic stand in for standard normal draws
+            let mut state = 987_u64;
+            let mut standard_normal = move || {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let uniform = ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+                plus_utils::standard_normal_inverse_cdf(uniform).unwrap_or_default()
+            };
+
+            let mut incremental_stats = IncrementalStats::new(0);
+            for _ in 0..20_000 {
+                let (shocks, rates_by_year) = simulator
+                    .sample_path(&[1.0], 3, &mut standard_normal)
+                    .unwrap();
+                assert_eq!(3, shocks.len());
+                assert_eq!(3, rates_by_year.len());
+                incremental_stats.push_value(rates_by_year[2][0]);
+            }
+
+            // Expected rate grows by the drift
+            let mean = incremental_stats.mean().unwrap();
+            assert!((mean - 1.02f64.powi(3)).abs() < 0.005);
+
+            // ω <fn test CurrencyRiskSimulator::sample_path>
+        }
+
+        // α <mod-def test_currency_risk_simulator>
+        // ω <mod-def test_currency_risk_simulator>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def currency_risk>
+// ω <mod-def currency_risk>
@@ -0,0 +1,706 @@
+//! Mean-variance optimization of allocations across instruments.
+//!
+//! Allocations are long-only (no weight below 0), capped by a maximum weight per
+//! instrument and fully invested (weights sum to 1). The minimum-variance allocation
+//! at a target return is a small quadratic program, solved by exact line searches
+//! along elementary feasible directions: pairs of weights when only the budget is
+//! fixed, triplets when the return is fixed too (the SMO approach of support vector
+//! machines). Any improving feasible direction decomposes into such elementary ones,
+//! so the search stops only at the optimum. The efficient frontier is traced by
+//! stepping the target return from the minimum-variance allocation up to the maximum
+//! return, and the maximum-Sharpe allocation is found along it by golden section.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::DistributionInstrument;
+use crate::NormalSpec;
+use ndarray::Array2;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Smallest variance decrease considered an improvement.
+const VARIANCE_TOLERANCE: f64 = 1e-18;
+
+/// Allowed difference between the return of an allocation and its target.
+const RETURN_TOLERANCE: f64 = 1e-10;
+
+/// Bound on the sweeps over directions of a search.
+const MAX_SWEEPS: usize = 10_000;
+
+/// Iterations of the golden section search for the maximum-Sharpe allocation.
+const GOLDEN_SECTION_ITERATIONS: usize = 100;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Finds mean-variance optimal allocations across instruments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeanVarianceOptimizer {
+    /// The instruments allocated to.
+    pub instruments: Vec<DistributionInstrument>,
+    /// Return of each instrument.
+    pub normal_specs: Vec<NormalSpec>,
+    /// Largest weight allowed in any instrument.
+    pub max_weight: f64,
+    /// Covariance of the instruments' returns.
+    covariance: Array2<f64>,
+}
+
+/// An allocation across the instruments of a `MeanVarianceOptimizer`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrontierAllocation {
+    /// Weight of each instrument, in the order of the optimizer's instruments.
+    pub weights: Vec<f64>,
+    /// Return of the allocation.
+    pub normal_spec: NormalSpec,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl MeanVarianceOptimizer {
+    /// Create optimizer for instruments with given returns and correlations.
+    ///
+    ///   * **instruments** - The instruments allocated to.
+    ///   * **normal_specs** - Return of each instrument.
+    ///   * **correlations** - Square matrix of correlations between instruments' returns.
+    ///   * **max_weight** - Largest weight allowed in any instrument, 1 for no cap.
+    ///   * _return_ - The optimizer, `None` if sizes differ or the cap prevents full investment.
+    pub fn new(
+        instruments: Vec<DistributionInstrument>,
+        normal_specs: Vec<NormalSpec>,
+        correlations: &Array2<f64>,
+        max_weight: f64,
+    ) -> Option<MeanVarianceOptimizer> {
+        // α <fn MeanVarianceOptimizer::new>
+
+        let count = normal_specs.len();
+        if count == 0
+            || instruments.len() != count
+            || correlations.dim() != (count, count)
+            || max_weight.is_nan()
+            || max_weight * (count as f64) < 1.0 - RETURN_TOLERANCE
+        {
+            return None;
+        }
+
+        let covariance = Array2::from_shape_fn((count, count), |(i, j)| {
+            normal_specs[i].std_dev * normal_specs[j].std_dev * correlations[[i, j]]
+        });
+
+        Some(MeanVarianceOptimizer {
+            instruments,
+            normal_specs,
+            max_weight: max_weight.min(1.0),
+            covariance,
+        })
+
+        // ω <fn MeanVarianceOptimizer::new>
+    }
+
+    /// The allocation with the lowest variance of any return.
+    ///
+    ///   * _return_ - The global minimum-variance allocation.
+    pub fn minimum_variance(&self) -> FrontierAllocation {
+        // α <fn MeanVarianceOptimizer::minimum_variance>
+
+        let mut weights = self.equal_weights();
+        self.descend(&mut weights, false);
+        self.allocation(weights)
+
+        // ω <fn MeanVarianceOptimizer::minimum_variance>
+    }
+
+    /// The allocation with the lowest variance for a target return.
+    ///
+    ///   * **target_return** - Required mean return of the allocation.
+    ///   * _return_ - The allocation, `None` if the target is not reachable under the constraints.
+    pub fn minimum_variance_at_return(&self, target_return: f64) -> Option<FrontierAllocation> {
+        // α <fn MeanVarianceOptimizer::minimum_variance_at_return>
+
+        let mut weights = self.equal_weights();
+        self.move_to_return(&mut weights, target_return);
+        if (self.mean(&weights) - target_return).abs() > RETURN_TOLERANCE {
+            return None;
+        }
+
+        self.descend(&mut weights, true);
+        Some(self.allocation(weights))
+
+        // ω <fn MeanVarianceOptimizer::minimum_variance_at_return>
+    }
+
+    /// The allocation with the highest return, filling the best instruments up to the cap.
+    ///
+    ///   * _return_ - The maximum-return allocation.
+    pub fn maximum_return(&self) -> FrontierAllocation {
+        // α <fn MeanVarianceOptimizer::maximum_return>
+
+        let mut order = (0..self.normal_specs.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            self.normal_specs[b]
+                .mean
+                .total_cmp(&self.normal_specs[a].mean)
+        });
+
+        let mut weights = vec![0.0; self.normal_specs.len()];
+        let mut remaining = 1.0f64;
+        for index in order {
+            weights[index] = remaining.min(self.max_weight);
+            remaining -= weights[index];
+        }
+
+        self.allocation(weights)
+
+        // ω <fn MeanVarianceOptimizer::maximum_return>
+    }
+
+    /// Points on the efficient frontier, evenly spaced in return from the minimum-variance
+    /// allocation to the maximum-return allocation.
+    ///
+    ///   * **num_points** - Number of points, at least 2.
+    ///   * _return_ - Allocations in increasing order of return and risk.
+    pub fn efficient_frontier(&self, num_points: usize) -> Vec<FrontierAllocation> {
+        // α <fn MeanVarianceOptimizer::efficient_frontier>
+
+        let num_points = num_points.max(2);
+        let low = self.minimum_variance().normal_spec.mean;
+        let high = self.maximum_return().normal_spec.mean;
+
+        (0..num_points)
+            .filter_map(|i| {
+                let target_return = low + (high - low) * i as f64 / (num_points - 1) as f64;
+                self.minimum_variance_at_return(target_return.min(high))
+            })
+            .collect()
+
+        // ω <fn MeanVarianceOptimizer::efficient_frontier>
+    }
+
+    /// The allocation on the efficient frontier with the highest Sharpe ratio,
+    /// `(mean - risk_free_rate) / std_dev`.
+    ///
+    ///   * **risk_free_rate** - Return of the risk free asset.
+    ///   * _return_ - The allocation, `None` if no allocation returns more than `risk_free_rate`.
+    pub fn maximum_sharpe(&self, risk_free_rate: f64) -> Option<FrontierAllocation> {
+        // α <fn MeanVarianceOptimizer::maximum_sharpe>
+
+        let minimum_variance = self.minimum_variance();
+        let maximum_return = self.maximum_return();
+        if maximum_return.normal_spec.mean <= risk_free_rate {
+            return None;
+        }
+
+        let sharpe = |allocation: &FrontierAllocation| {
+            let excess = allocation.normal_spec.mean - risk_free_rate;
+            if allocation.normal_spec.std_dev > 0.0 {
+                excess / allocation.normal_spec.std_dev
+            } else if excess > 0.0 {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            }
+        };
+        let sharpe_at = |target_return: f64| {
+            self.minimum_variance_at_return(target_return)
+                .map(|allocation| (sharpe(&allocation), allocation))
+        };
+
+        // Sharpe ratio is quasi-concave along the frontier
+        let inverse_phi = (5f64.sqrt() - 1.0) / 2.0;
+        let (mut low, mut high) = (
+            minimum_variance.normal_spec.mean.max(risk_free_rate),
+            maximum_return.normal_spec.mean,
+        );
+        let mut left = high - inverse_phi * (high - low);
+        let mut right = low + inverse_phi * (high - low);
+        let mut left_value = sharpe_at(left)?;
+        let mut right_value = sharpe_at(right)?;
+        for _ in 0..GOLDEN_SECTION_ITERATIONS {
+            if high - low <= RETURN_TOLERANCE {
+                break;
+            }
+            if left_value.0 < right_value.0 {
+                low = left;
+                left = right;
+                left_value = right_value;
+                right = low + inverse_phi * (high - low);
+                right_value = sharpe_at(right)?;
+            } else {
+                high = right;
+                right = left;
+                right_value = left_value;
+                left = high - inverse_phi * (high - low);
+                left_value = sharpe_at(left)?;
+            }
+        }
+
+        [
+            left_value,
+            right_value,
+            (sharpe(&minimum_variance), minimum_variance),
+            (sharpe(&maximum_return), maximum_return),
+        ]
+        .into_iter()
+        .filter(|(_, allocation)| allocation.normal_spec.mean >= risk_free_rate)
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, allocation)| allocation)
+
+        // ω <fn MeanVarianceOptimizer::maximum_sharpe>
+    }
+
+    /// Return of an allocation.
+    ///
+    ///   * **weights** - Weight of each instrument.
+    ///   * _return_ - The return, `None` if the number of weights differs from the instruments.
+    pub fn allocation_normal_spec(&self, weights: &[f64]) -> Option<NormalSpec> {
+        // α <fn MeanVarianceOptimizer::allocation_normal_spec>
+
+        if weights.len() != self.normal_specs.len() {
+            None
+        } else {
+            Some(NormalSpec {
+                mean: self.mean(weights),
+                std_dev: self.variance(weights).max(0.0).sqrt(),
+            })
+        }
+
+        // ω <fn MeanVarianceOptimizer::allocation_normal_spec>
+    }
+
+    /// Equal weights in all instruments - always feasible.
+    ///
+    ///   * _return_ - The weights.
+    fn equal_weights(&self) -> Vec<f64> {
+        // α <fn MeanVarianceOptimizer::equal_weights>
+        let count = self.normal_specs.len();
+        vec![1.0 / count as f64; count]
+        // ω <fn MeanVarianceOptimizer::equal_weights>
+    }
+
+    /// Mean return of weights.
+    ///
+    ///   * **weights** - Weight of each instrument.
+    ///   * _return_ - The mean.
+    fn mean(&self, weights: &[f64]) -> f64 {
+        // α <fn MeanVarianceOptimizer::mean>
+        weights
+            .iter()
+            .zip(self.normal_specs.iter())
+            .map(|(weight, normal_spec)| weight * normal_spec.mean)
+            .sum()
+        // ω <fn MeanVarianceOptimizer::mean>
+    }
+
+    /// Variance of the return of weights.
+    ///
+    ///   * **weights** - Weight of each instrument.
+    ///   * _return_ - The variance.
+    fn variance(&self, weights: &[f64]) -> f64 {
+        // α <fn MeanVarianceOptimizer::variance>
+        let weights = ndarray::ArrayView1::from(weights);
+        weights.dot(&self.covariance.dot(&weights))
+        // ω <fn MeanVarianceOptimizer::variance>
+    }
+
+    /// Wrap weights as an allocation.
+    ///
+    ///   * **weights** - Weight of each instrument.
+    ///   * _return_ - The allocation.
+    fn allocation(&self, weights: Vec<f64>) -> FrontierAllocation {
+        // α <fn MeanVarianceOptimizer::allocation>
+        FrontierAllocation {
+            normal_spec: NormalSpec {
+                mean: self.mean(&weights),
+                std_dev: self.variance(&weights).max(0.0).sqrt(),
+            },
+            weights,
+        }
+        // ω <fn MeanVarianceOptimizer::allocation>
+    }
+
+    /// Shift weight between pairs of instruments until the weights have the target return
+    /// or no shift within the constraints moves them closer.
+    ///
+    ///   * **weights** - Feasible weights, updated in place.
+    ///   * **target_return** - Return to move to.
+    fn move_to_return(&self, weights: &mut [f64], target_return: f64) {
+        // α <fn MeanVarianceOptimizer::move_to_return>
+
+        let count = weights.len();
+        for _ in 0..MAX_SWEEPS {
+            let mut moved = false;
+            for i in 0..count {
+                for j in 0..count {
+                    let gap = target_return - self.mean(weights);
+                    let spread = self.normal_specs[i].mean - self.normal_specs[j].mean;
+                    if gap.abs() <= RETURN_TOLERANCE * 0.01 {
+                        return;
+                    }
+                    if i == j || spread == 0.0 {
+                        continue;
+                    }
+
+                    // Move t from j to i
+                    let step = (gap / spread)
+                        .min(self.max_weight - weights[i])
+                        .min(weights[j])
+                        .max(-weights[i])
+                        .max(weights[j] - self.max_weight);
+                    if step != 0.0 {
+                        weights[i] += step;
+                        weights[j] -= step;
+                        moved = true;
+                    }
+                }
+            }
+            if !moved {
+                return;
+            }
+        }
+
+        // ω <fn MeanVarianceOptimizer::move_to_return>
+    }
+
+    /// Reduce the variance of feasible weights by exact line searches along elementary
+    /// directions that keep the weights summing to one and, if set, keep the return.
+    ///
+    ///   * **weights** - Feasible weights, updated in place.
+    ///   * **keep_return** - If set the return of the weights is held fixed.
+    fn descend(&self, weights: &mut [f64], keep_return: bool) {
+        // α <fn MeanVarianceOptimizer::descend>
+
+        let count = weights.len();
+        let means = self
+            .normal_specs
+            .iter()
+            .map(|normal_spec| normal_spec.mean)
+            .collect::<Vec<_>>();
+
+        let mut directions: Vec<Vec<(usize, f64)>> = Vec::new();
+        for i in 0..count {
+            for j in (i + 1)..count {
+                if !keep_return || means[i] == means[j] {
+                    directions.push(vec![(i, 1.0), (j, -1.0)]);
+                }
+                if keep_return {
+                    for k in (j + 1)..count {
+                        let direction = vec![
+                            (i, means[j] - means[k]),
+                            (j, means[k] - means[i]),
+                            (k, means[i] - means[j]),
+                        ];
+                        if direction.iter().any(|(_, d)| *d != 0.0) {
+                            directions.push(direction);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Covariance times the weights - the half gradient of the variance
+        let mut covariance_weights = self
+            .covariance
+            .dot(&ndarray::ArrayView1::from(&*weights))
+            .to_vec();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut decrease = 0.0;
+            for direction in directions.iter() {
+                let slope = direction
+                    .iter()
+                    .map(|(k, d)| d * covariance_weights[*k])
+                    .sum::<f64>();
+                let curvature = direction
+                    .iter()
+                    .flat_map(|(k, d_k)| {
+                        direction
+                            .iter()
+                            .map(move |(l, d_l)| d_k * d_l * self.covariance[[*k, *l]])
+                    })
+                    .sum::<f64>();
+                if curvature <= 0.0 {
+                    continue;
+                }
+
+                // Bound the step to keep every weight in [0, max_weight]
+                let (mut step_low, mut step_high) = (f64::NEG_INFINITY, f64::INFINITY);
+                for (k, d) in direction.iter() {
+                    if *d > 0.0 {
+                        step_low = step_low.max(-weights[*k] / d);
+                        step_high = step_high.min((self.max_weight - weights[*k]) / d);
+                    } else if *d < 0.0 {
+                        step_low = step_low.max((self.max_weight - weights[*k]) / d);
+                        step_high = step_high.min(-weights[*k] / d);
+                    }
+                }
+                let step = (-slope / curvature).clamp(step_low.min(0.0), step_high.max(0.0));
+                let step_decrease = -(2.0 * step * slope + step * step * curvature);
+                if step_decrease <= VARIANCE_TOLERANCE {
+                    continue;
+                }
+
+                for (k, d) in direction.iter() {
+                    weights[*k] = (weights[*k] + step * d).clamp(0.0, self.max_weight);
+                    for (l, covariance_weight) in covariance_weights.iter_mut().enumerate() {
+                        *covariance_weight += step * d * self.covariance[[l, *k]];
+                    }
+                }
+                decrease += step_decrease;
+            }
+            if decrease <= VARIANCE_TOLERANCE {
+                break;
+            }
+        }
+
+        // ω <fn MeanVarianceOptimizer::descend>
+    }
+}
+
+/// Unit tests for `efficient_frontier`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type MeanVarianceOptimizer
+    mod test_mean_variance_optimizer {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::efficient_frontier::FrontierAllocation;
+        use crate::efficient_frontier::MeanVarianceOptimizer;
+        use crate::DistributionInstrument;
+        use crate::NormalSpec;
+        use ndarray::array;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn new() {
+            // α <fn test MeanVarianceOptimizer::new>
+
+            assert!(stocks_and_bonds(1.0).is_some());
+            assert!(stocks_and_bonds(0.5).is_some());
+            // Two instruments capped at 40% cannot be fully invested
+            assert!(stocks_and_bonds(0.4).is_none());
+            assert!(MeanVarianceOptimizer::new(
+                vec![DistributionInstrument::Equity],
+                vec![STOCKS, BONDS],
+                &array![[1.0]],
+                1.0
+            )
+            .is_none());
+
+            // ω <fn test MeanVarianceOptimizer::new>
+        }
+
+        #[test]
+        fn minimum_variance() {
+            // α <fn test MeanVarianceOptimizer::minimum_variance>
+
+            // Uncorrelated: weights inversely proportional to variance
+            let allocation = stocks_and_bonds(1.0).unwrap().minimum_variance();
+            assert_weights(&[0.0025 / 0.0425, 0.04 / 0.0425], &allocation);
+
+            // A cap binds
+            let allocation = three_instruments(0.4).minimum_variance();
+            assert!(allocation
+                .weights
+                .iter()
+                .all(|weight| *weight <= 0.4 + 1e-12));
+            assert!((allocation.weights[2] - 0.4).abs() < 1e-12);
+
+            // ω <fn test MeanVarianceOptimizer::minimum_variance>
+        }
+
+        #[test]
+        fn minimum_variance_at_return() {
+            // α <fn test MeanVarianceOptimizer::minimum_variance_at_return>
+
+            // Two instruments - the return fixes the weights
+            let optimizer = stocks_and_bonds(1.0).unwrap();
+            let allocation = optimizer.minimum_variance_at_return(0.076).unwrap();
+            assert_weights(&[0.6, 0.4], &allocation);
+            assert!((allocation.normal_spec.mean - 0.076).abs() < 1e-10);
+
+            // Beyond the best instrument
+            assert_eq!(None, optimizer.minimum_variance_at_return(0.11));
+            // Cap limits the return to 0.5·10% + 0.5·4%
+            assert_eq!(
+                None,
+                stocks_and_bonds(0.5)
+                    .unwrap()
+                    .minimum_variance_at_return(0.08)
+            );
+
+            // No other feasible allocation with the return has lower variance
+            let optimizer = three_instruments(1.0);
+            let allocation = optimizer.minimum_variance_at_return(0.07).unwrap();
+            assert!((allocation.weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            for shift in [-0.01, 0.01] {
+                let mut weights = allocation.weights.clone();
+                // Direction keeping budget and return for means 10%, 4%, 7%
+                weights[0] += shift * 0.03;
+                weights[1] += shift * 0.03;
+                weights[2] -= shift * 0.06;
+                let perturbed = optimizer.allocation_normal_spec(&weights).unwrap();
+                assert!((perturbed.mean - 0.07).abs() < 1e-10);
+                assert!(perturbed.std_dev >= allocation.normal_spec.std_dev);
+            }
+
+            // ω <fn test MeanVarianceOptimizer::minimum_variance_at_return>
+        }
+
+        #[test]
+        fn maximum_return() {
+            // α <fn test MeanVarianceOptimizer::maximum_return>
+            assert_weights(
+                &[1.0, 0.0],
+                &stocks_and_bonds(1.0).unwrap().maximum_return(),
+            );
+            assert_weights(&[0.4, 0.2, 0.4], &three_instruments(0.4).maximum_return());
+            // ω <fn test MeanVarianceOptimizer::maximum_return>
+        }
+
+        #[test]
+        fn efficient_frontier() {
+            // α <fn test MeanVarianceOptimizer::efficient_frontier>
+
+            let optimizer = three_instruments(0.6);
+            let frontier = optimizer.efficient_frontier(20);
+            assert_eq!(20, frontier.len());
+            assert_eq!(
+                optimizer.minimum_variance().normal_spec.mean,
+                frontier[0].normal_spec.mean
+            );
+
+            // Both return and risk increase along the frontier
+            for pair in frontier.windows(2) {
+                assert!(pair[1].normal_spec.mean > pair[0].normal_spec.mean);
+                assert!(pair[1].normal_spec.std_dev >= pair[0].normal_spec.std_dev - 1e-12);
+            }
+            for allocation in frontier.iter() {
+                assert!((allocation.weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+                assert!(allocation
+                    .weights
+                    .iter()
+                    .all(|weight| (-1e-12..=0.6 + 1e-12).contains(weight)));
+            }
+
+            // ω <fn test MeanVarianceOptimizer::efficient_frontier>
+        }
+
+        #[test]
+        fn maximum_sharpe() {
+            // α <fn test MeanVarianceOptimizer::maximum_sharpe>
+
+            // Uncorrelated, unconstrained tangency: weights ∝ (0.08/0.04, 0.02/0.0025)
+            let optimizer = stocks_and_bonds(1.0).unwrap();
+            let allocation = optimizer.maximum_sharpe(0.02).unwrap();
+            assert_weights(&[0.2, 0.8], &allocation);
+
+            // Every frontier point has no higher Sharpe ratio
+            let sharpe = |normal_spec: &NormalSpec| (normal_spec.mean - 0.02) / normal_spec.std_dev;
+            let optimizer = three_instruments(0.6);
+            let best = sharpe(&optimizer.maximum_sharpe(0.02).unwrap().normal_spec);
+            for allocation in optimizer.efficient_frontier(50) {
+                assert!(sharpe(&allocation.normal_spec) <= best + 1e-9);
+            }
+
+            assert_eq!(None, optimizer.maximum_sharpe(0.2));
+
+            // ω <fn test MeanVarianceOptimizer::maximum_sharpe>
+        }
+
+        #[test]
+        fn allocation_normal_spec() {
+            // α <fn test MeanVarianceOptimizer::allocation_normal_spec>
+
+            let optimizer = stocks_and_bonds(1.0).unwrap();
+            let normal_spec = optimizer.allocation_normal_spec(&[0.5, 0.5]).unwrap();
+            assert!((normal_spec.mean - 0.07).abs() < 1e-12);
+            assert!((normal_spec.std_dev - (0.25f64 * 0.04 + 0.25 * 0.0025).sqrt()).abs() < 1e-12);
+            assert_eq!(None, optimizer.allocation_normal_spec(&[1.0]));
+
+            // ω <fn test MeanVarianceOptimizer::allocation_normal_spec>
+        }
+
+        const STOCKS: NormalSpec = NormalSpec {
+            mean: 0.10,
+            std_dev: 0.20,
+        };
+
+        const BONDS: NormalSpec = NormalSpec {
+            mean: 0.04,
+            std_dev: 0.05,
+        };
+
+        /// Uncorrelated stocks and bonds.
+        ///
+        ///   * **max_weight** - Largest weight allowed in either.
+        ///   * _return_ - The optimizer.
+        fn stocks_and_bonds(max_weight: f64) -> Option<MeanVarianceOptimizer> {
+            MeanVarianceOptimizer::new(
+                vec![
+                    DistributionInstrument::Equity,
+                    DistributionInstrument::InterestBearing,
+                ],
+                vec![STOCKS, BONDS],
+                &array![[1.0, 0.0], [0.0, 1.0]],
+                max_weight,
+            )
+        }
+
+        /// Stocks, bonds and a correlated fund.
+        ///
+        ///   * **max_weight** - Largest weight allowed in any.
+        ///   * _return_ - The optimizer.
+        fn three_instruments(max_weight: f64) -> MeanVarianceOptimizer {
+            MeanVarianceOptimizer::new(
+                vec![
+                    DistributionInstrument::Equity,
+                    DistributionInstrument::InterestBearing,
+                    DistributionInstrument::MutualFund,
+                ],
+                vec![
+                    STOCKS,
+                    BONDS,
+                    NormalSpec {
+                        mean: 0.07,
+                        std_dev: 0.12,
+                    },
+                ],
+                &array![[1.0, 0.1, 0.6], [0.1, 1.0, 0.3], [0.6, 0.3, 1.0]],
+                max_weight,
+            )
+            .unwrap()
+        }
+
+        /// Assert weights of an allocation.
+        ///
+        ///   * **expected** - Expected weights.
+        ///   * **allocation** - Allocation to check.
+        fn assert_weights(expected: &[f64], allocation: &FrontierAllocation) {
+            assert_eq!(expected.len(), allocation.weights.len());
+            for (expected, actual) in expected.iter().zip(allocation.weights.iter()) {
+                assert!(
+                    (expected - actual).abs() < 1e-7,
+                    "expected {expected:?} got {:?}",
+                    allocation.weights
+                );
+            }
+        }
+
+        // α <mod-def test_mean_variance_optimizer>
+        // ω <mod-def test_mean_variance_optimizer>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def efficient_frontier>
+// ω <mod-def efficient_frontier>
@@ -0,0 +1,82 @@
+//! Configuration selected per forecast.
+//!
+//! Settings left unset fall back to the system defaults (see `SystemDefaults` in
+//! `plus_lookup`), so a forecast only records what the user chose for it.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::SimulationMode;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Settings of a single forecast.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForecastConfig {
+    /// How the forecast simulates returns, `None` for the system default.
+    pub simulation_mode: Option<SimulationMode>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl ForecastConfig {
+    /// How the forecast simulates returns.
+    ///
+    ///   * **default_mode** - Mode when the forecast selects none, e.g. `SystemDefaults::simulation_mode`.
+    ///   * _return_ - The selected mode, else `default_mode`.
+    #[inline]
+    pub fn simulation_mode(&self, default_mode: SimulationMode) -> SimulationMode {
+        // α <fn ForecastConfig::simulation_mode>
+        self.simulation_mode.unwrap_or(default_mode)
+        // ω <fn ForecastConfig::simulation_mode>
+    }
+}
+
+/// Unit tests for `forecast_config`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type ForecastConfig
+    mod test_forecast_config {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::ForecastConfig;
+        use crate::SimulationMode;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn simulation_mode() {
+            // α <fn test ForecastConfig::simulation_mode>
+
+            let bootstrap = SimulationMode::Bootstrap { block_length: 5 };
+            assert_eq!(
+                bootstrap,
+                ForecastConfig::default().simulation_mode(bootstrap)
+            );
+            assert_eq!(
+                SimulationMode::Parametric,
+                ForecastConfig {
+                    simulation_mode: Some(SimulationMode::Parametric),
+                }
+                .simulation_mode(bootstrap)
+            );
+
+            // ω <fn test ForecastConfig::simulation_mode>
+        }
+
+        // α <mod-def test_forecast_config>
+        // ω <mod-def test_forecast_config>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def forecast_config>
+// ω <mod-def forecast_config>
@@ -0,0 +1,380 @@
+//! Historical bootstrap simulation of annual returns.
+//!
+//! Instead of drawing each instrument's return from its own parametric distribution, a
+//! bootstrap replays whole historic years of the embedded dataset (see `historic_returns`),
+//! taking the returns of every series from the same year. This keeps the real cross-asset
+//! correlation. A _block_ bootstrap replays runs of contiguous years, which also keeps serial
+//! dependence such as multi-year bear markets and persistent inflation. Blocks wrap around
+//! the end of the window (the _circular_ block bootstrap) so every year is equally likely.
+//! As with `ReturnDistribution::sample`, randomness comes from uniform draws supplied by the
+//! caller.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::historic_returns::clipped_years;
+use crate::HistoricSeries;
+use crate::YearRange;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// How a forecast simulates annual returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulationMode {
+    /// Draw each year's returns from the items' `ReturnDistribution`s with correlated shocks,
+    /// see `CorrelatedShockSampler::sample_return_path`.
+    Parametric,
+    /// Replay blocks of contiguous historic years jointly across all instruments.
+    Bootstrap {
+        /// Number of contiguous years per block - 1 samples independent years.
+        block_length: u32,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Samples paths of annual returns by replaying blocks of historic years.
+/// Created only through `new`, which validates the window and block length.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoricBootstrap {
+    /// Series sampled, in the order of the returns of each sampled year.
+    series: Vec<HistoricSeries>,
+    /// Window of historic years sampled from, within the dataset.
+    years: YearRange,
+    /// Number of contiguous years per block.
+    block_length: u32,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl HistoricBootstrap {
+    /// Create a bootstrap over a window of historic years.
+    ///
+    ///   * **series** - Series to sample jointly.
+    ///   * **years** - Window of years to sample from, `end` exclusive, clipped to the years all series cover.
+    ///   * **block_length** - Number of contiguous years per block.
+    ///   * _return_ - The bootstrap, `None` if no series, no years or a 0 block length.
+    pub fn new(series: &[HistoricSeries], years: &YearRange, block_length: u32) -> Option<Self> {
+        // α <fn HistoricBootstrap::new>
+
+        let years = clipped_years(years);
+        let first_year = series.iter().map(|series| series.first_year()).max();
+        let years =
+            first_year.map_or(years.start, |first_year| years.start.max(first_year))..years.end;
+        if series.is_empty() || years.is_empty() || block_length == 0 {
+            return None;
+        }
+
+        Some(HistoricBootstrap {
+            series: series.to_vec(),
+            years: YearRange {
+                start: years.start,
+                end: years.end,
+            },
+            block_length,
+        })
+
+        // ω <fn HistoricBootstrap::new>
+    }
+
+    /// Sample the historic years replayed by a simulated path.
+    ///
+    ///   * **num_years** - Number of years in the path.
+    ///   * **uniform** - Source of draws from the uniform distribution on [0, 1), one per block.
+    ///   * _return_ - The historic year replayed in each year of the path.
+    pub fn sample_years(&self, num_years: usize, mut uniform: impl FnMut() -> f64) -> Vec<u32> {
+        // α <fn HistoricBootstrap::sample_years>
+
+        let window_length = self.years.end - self.years.start;
+        let mut sampled = Vec::with_capacity(num_years);
+        while sampled.len() < num_years {
+            let block_start =
+                ((uniform().clamp(0.0, 1.0) * window_length as f64) as u32).min(window_length - 1);
+            sampled.extend(
+                (0..self.block_length)
+                    .map(|offset| self.years.start + (block_start + offset) % window_length)
+                    .take(num_years - sampled.len()),
+            );
+        }
+        sampled
+
+        // ω <fn HistoricBootstrap::sample_years>
+    }
+
+    /// Sample a path of returns.
+    ///
+    ///   * **num_years** - Number of years in the path.
+    ///   * **uniform** - Source of draws from the uniform distribution on [0, 1), one per block.
+    ///   * _return_ - For each year of the path the returns of `series`, all from one historic year.
+    pub fn sample_path(&self, num_years: usize, uniform: impl FnMut() -> f64) -> Vec<Vec<f64>> {
+        // α <fn HistoricBootstrap::sample_path>
+        self.sample_years(num_years, uniform)
+            .into_iter()
+            .map(|year| {
+                self.series
+                    .iter()
+                    .map(|series| series.return_in_year(year).unwrap_or_default())
+                    .collect()
+            })
+            .collect()
+        // ω <fn HistoricBootstrap::sample_path>
+    }
+}
+
+/// Accessors for [HistoricBootstrap] fields
+impl HistoricBootstrap {
+    #[inline]
+    pub fn get_series(&self) -> &[HistoricSeries] {
+        &self.series
+    }
+
+    #[inline]
+    pub fn get_years(&self) -> &YearRange {
+        &self.years
+    }
+
+    #[inline]
+    pub fn get_block_length(&self) -> u32 {
+        self.block_length
+    }
+}
+
+impl SimulationMode {
+    /// The bootstrap sampling returns in this mode.
+    ///
+    ///   * **series** - Series to sample jointly.
+    ///   * **years** - Window of years to sample from, `end` exclusive.
+    ///   * _return_ - The bootstrap, `None` when parametric or if `HistoricBootstrap::new` rejects the window.
+    pub fn historic_bootstrap(
+        &self,
+        series: &[HistoricSeries],
+        years: &YearRange,
+    ) -> Option<HistoricBootstrap> {
+        // α <fn SimulationMode::historic_bootstrap>
+        match self {
+            SimulationMode::Parametric => None,
+            SimulationMode::Bootstrap { block_length } => {
+                HistoricBootstrap::new(series, years, *block_length)
+            }
+        }
+        // ω <fn SimulationMode::historic_bootstrap>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl Default for SimulationMode {
+    /// A trait for giving a type a useful default value.
+    ///
+    ///   * _return_ - The new default instance
+    fn default() -> Self {
+        // α <fn Default::default for SimulationMode>
+        SimulationMode::Parametric
+        // ω <fn Default::default for SimulationMode>
+    }
+}
+
+/// Unit tests for `historic_bootstrap`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type HistoricBootstrap
+    mod test_historic_bootstrap {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::historic_returns::historic_years;
+        use crate::HistoricBootstrap;
+        use crate::HistoricSeries;
+        use crate::YearRange;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn new() {
+            // α <fn test HistoricBootstrap::new>
+
+            let series = [HistoricSeries::UsLargeCapEquity];
+            let bootstrap = HistoricBootstrap::new(
+                &series,
+                &YearRange {
+                    start: 2000,
+                    end: 2100,
+                },
+                5,
+            )
+            .unwrap();
+            assert_eq!(
+                YearRange {
+                    start: 2000,
+                    end: 2024
+                },
+                bootstrap.years
+            );
+
+            // Clipped to the years of the mid cap returns
+            let bootstrap = HistoricBootstrap::new(
+                &[
+                    HistoricSeries::UsLargeCapEquity,
+                    HistoricSeries::UsMidCapEquity,
+                ],
+                &historic_years(),
+                5,
+            )
+            .unwrap();
+            assert_eq!(1991, bootstrap.years.start);
+
+            assert_eq!(None, HistoricBootstrap::new(&[], &historic_years(), 5));
+            assert_eq!(None, HistoricBootstrap::new(&series, &historic_years(), 0));
+            assert_eq!(
+                None,
+                HistoricBootstrap::new(
+                    &series,
+                    &YearRange {
+                        start: 1900,
+                        end: 1920
+                    },
+                    1
+                )
+            );
+
+            // ω <fn test HistoricBootstrap::new>
+        }
+
+        #[test]
+        fn sample_years() {
+            // α <fn test HistoricBootstrap::sample_years>
+
+            let bootstrap = HistoricBootstrap::new(
+                &[HistoricSeries::UsLargeCapEquity],
+                &YearRange {
+                    start: 2000,
+                    end: 2010,
+                },
+                4,
+            )
+            .unwrap();
+
+            // Blocks are contiguous, wrap around the window and the last is cut short
+            let mut draws = [0.25, 0.85, 0.0].into_iter();
+            assert_eq!(
+                vec![2002, 2003, 2004, 2005, 2008, 2009, 2000, 2001, 2000, 2001],
+                bootstrap.sample_years(10, || draws.next().unwrap())
+            );
+
+            // Blocks of 1 are independent years
+            let bootstrap = HistoricBootstrap::new(
+                &[HistoricSeries::UsLargeCapEquity],
+                &YearRange {
+                    start: 2000,
+                    end: 2010,
+                },
+                1,
+            )
+            .unwrap();
+            let mut draws = [0.999, 0.5, 0.1].into_iter();
+            assert_eq!(
+                vec![2009, 2005, 2001],
+                bootstrap.sample_years(3, || draws.next().unwrap())
+            );
+
+            assert!(bootstrap.sample_years(0, || 0.5).is_empty());
+
+            // ω <fn test HistoricBootstrap::sample_years>
+        }
+
+        #[test]
+        fn sample_path() {
+            // α <fn test HistoricBootstrap::sample_path>
+
+            let series = [
+                HistoricSeries::UsLargeCapEquity,
+                HistoricSeries::UsInflation,
+            ];
+            let bootstrap = HistoricBootstrap::new(&series, &historic_years(), 3).unwrap();
+            let years = bootstrap.sample_years(30, draws());
+            let path = bootstrap.sample_path(30, draws());
+
+            // Every year's returns come jointly from the same historic year
+            assert_eq!(30, path.len());
+            for (year, returns) in years.iter().zip(&path) {
+                assert_eq!(
+                    vec![
+                        series[0].return_in_year(*year).unwrap(),
+                        series[1].return_in_year(*year).unwrap()
+                    ],
+                    *returns
+                );
+            }
+
+            // ω <fn test HistoricBootstrap::sample_path>
+        }
+
+        /// Repeatable sequence of draws on [0, 1).
+        ///
+        ///   * _return_ - Source of the draws.
+        fn draws() -> impl FnMut() -> f64 {
+            let mut state = 0.37;
+            move || {
+                state = (state * 7.0 + 0.13) % 1.0;
+                state
+            }
+        }
+
+        // α <mod-def test_historic_bootstrap>
+        // ω <mod-def test_historic_bootstrap>
+    }
+
+    /// Test type SimulationMode
+    mod test_simulation_mode {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::historic_returns::historic_years;
+        use crate::HistoricBootstrap;
+        use crate::HistoricSeries;
+        use crate::SimulationMode;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn historic_bootstrap() {
+            // α <fn test SimulationMode::historic_bootstrap>
+
+            let series = [HistoricSeries::UsLargeCapEquity];
+            assert_eq!(
+                None,
+                SimulationMode::Parametric.historic_bootstrap(&series, &historic_years())
+            );
+            assert_eq!(
+                HistoricBootstrap::new(&series, &historic_years(), 5),
+                SimulationMode::Bootstrap { block_length: 5 }
+                    .historic_bootstrap(&series, &historic_years())
+            );
+            assert_eq!(
+                None,
+                SimulationMode::Bootstrap { block_length: 0 }
+                    .historic_bootstrap(&series, &historic_years())
+            );
+
+            // ω <fn test SimulationMode::historic_bootstrap>
+        }
+
+        // α <mod-def test_simulation_mode>
+        // ω <mod-def test_simulation_mode>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def historic_bootstrap>
+// ω <mod-def historic_bootstrap>
@@ -0,0 +1,597 @@
+//! Embedded dataset of historic annual US returns, 1928 through 2023.
+//!
+//! Returns of large cap stocks, bills and bonds are from Aswath Damodaran's _Historical Returns
+//! on Stocks, Bonds and Bills_ (NYU Stern, January 2024 update). Small cap stocks are the
+//! Ibbotson SBBI small company stocks, continued by the Russell 2000 from 2011, and mid cap
+//! stocks the S&P MidCap 400 from 1991, its first full year. Gold is the change in its year-end
+//! price. Inflation is the annual change in the average CPI-U of the US Bureau of Labor
+//! Statistics. Returns are fractions (0.05 is 5%) for calendar years. The dataset is compiled in so estimates work offline;
+//! `HISTORIC_RETURNS_VERSION` identifies it and changes whenever years are added or revised.
+//!
+//! `historic_normal_spec` and `historic_correlations` estimate return distributions from any
+//! window of years by feeding the data through `IncrementalStats` and `IncrementalPearson`.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::DistributionInstrument;
+use crate::NormalSpec;
+use crate::YearRange;
+use crate::YearValue;
+use ndarray::Array1;
+use ndarray::Array2;
+use plus_utils::IncrementalPearson;
+use plus_utils::IncrementalStats;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Version of the embedded dataset.
+pub const HISTORIC_RETURNS_VERSION: &str = "damodaran-2024.01.1";
+
+/// First year of the embedded dataset.
+pub const HISTORIC_RETURNS_FIRST_YEAR: u32 = 1928;
+
+/// Number of years in the embedded dataset.
+pub const HISTORIC_RETURN_YEARS: usize = 96;
+
+/// Annual total returns of the S&P 500, dividends reinvested.
+pub const US_LARGE_CAP_EQUITY_RETURNS: [f64; HISTORIC_RETURN_YEARS] = [
+    0.4381, -0.083, -0.2512, -0.4384, -0.0864, 0.4998, -0.0119, 0.4674, 0.3194,
+    -0.3534, // 1928-1937
+    0.2928, -0.011, -0.1067, -0.1277, 0.1917, 0.2506, 0.1903, 0.3582, -0.0843,
+    0.052, // 1938-1947
+    0.057, 0.183, 0.3081, 0.2368, 0.1815, -0.0121, 0.5256, 0.326, 0.0744,
+    -0.1046, // 1948-1957
+    0.4372, 0.1206, 0.0034, 0.2664, -0.0881, 0.2261, 0.1642, 0.124, -0.0997,
+    0.238, // 1958-1967
+    0.1081, -0.0824, 0.0356, 0.1422, 0.1876, -0.1431, -0.259, 0.37, 0.2383,
+    -0.0698, // 1968-1977
+    0.0651, 0.1852, 0.3174, -0.047, 0.2042, 0.2234, 0.0615, 0.3124, 0.1849,
+    0.0581, // 1978-1987
+    0.1654, 0.3148, -0.0306, 0.3023, 0.0749, 0.0997, 0.0133, 0.372, 0.2268,
+    0.331, // 1988-1997
+    0.2834, 0.2089, -0.0903, -0.1185, -0.2197, 0.2836, 0.1074, 0.0483, 0.1561,
+    0.0548, // 1998-2007
+    -0.3655, 0.2594, 0.1482, 0.021, 0.1589, 0.3215, 0.1352, 0.0138, 0.1177,
+    0.2161, // 2008-2017
+    -0.0423, 0.3121, 0.1802, 0.2847, -0.1804, 0.2606, // 2018-2023
+];
+
+/// Annual returns of 3-month US Treasury bills.
+pub const US_TREASURY_BILL_RETURNS: [f64; HISTORIC_RETURN_YEARS] = [
+    0.0308, 0.0316, 0.0455, 0.0231, 0.0107, 0.0096, 0.0028, 0.0017, 0.0017,
+    0.0028, // 1928-1937
+    0.0007, 0.0005, 0.0004, 0.0013, 0.0034, 0.0038, 0.0038, 0.0038, 0.0038,
+    0.0057, // 1938-1947
+    0.0102, 0.011, 0.0117, 0.0148, 0.0167, 0.0189, 0.0096, 0.0166, 0.0256,
+    0.0323, // 1948-1957
+    0.0178, 0.0326, 0.0305, 0.0227, 0.0278, 0.0311, 0.0351, 0.039, 0.0484,
+    0.0433, // 1958-1967
+    0.0526, 0.0656, 0.0669, 0.0454, 0.0395, 0.0673, 0.0778, 0.0599, 0.0497,
+    0.0513, // 1968-1977
+    0.0693, 0.0994, 0.1122, 0.143, 0.1101, 0.0845, 0.0961, 0.0749, 0.0604,
+    0.0572, // 1978-1987
+    0.0645, 0.0811, 0.0755, 0.0561, 0.0341, 0.0298, 0.0399, 0.0552, 0.0502,
+    0.0505, // 1988-1997
+    0.0473, 0.0451, 0.0576, 0.0367, 0.0166, 0.0103, 0.0123, 0.0301, 0.0468,
+    0.0464, // 1998-2007
+    0.0159, 0.0014, 0.0013, 0.0003, 0.0005, 0.0007, 0.0005, 0.0021, 0.0051,
+    0.0139, // 2008-2017
+    0.0237, 0.0155, 0.0009, 0.0006, 0.0202, 0.0507, // 2018-2023
+];
+
+/// Annual total returns of 10-year US Treasury bonds.
+pub const US_TREASURY_BOND_RETURNS: [f64; HISTORIC_RETURN_YEARS] = [
+    0.0084, 0.042, 0.0454, -0.0256, 0.0879, 0.0186, 0.0796, 0.0447, 0.0502,
+    0.0138, // 1928-1937
+    0.0421, 0.0441, 0.054, -0.0202, 0.0229, 0.0249, 0.0258, 0.038, 0.0313,
+    0.0092, // 1938-1947
+    0.0195, 0.0466, 0.0043, -0.003, 0.0227, 0.0414, 0.0329, -0.0134, -0.0226,
+    0.068, // 1948-1957
+    -0.021, -0.0265, 0.1164, 0.0206, 0.0569, 0.0168, 0.0373, 0.0072, 0.0291,
+    -0.0158, // 1958-1967
+    0.0327, -0.0501, 0.1675, 0.0979, 0.0282, 0.0366, 0.0199, 0.0361, 0.1598,
+    0.0129, // 1968-1977
+    -0.0078, 0.0067, -0.0299, 0.082, 0.3281, 0.032, 0.1373, 0.2571, 0.2428,
+    -0.0496, // 1978-1987
+    0.0822, 0.1769, 0.0624, 0.15, 0.0936, 0.1421, -0.0804, 0.2348, 0.0143,
+    0.0994, // 1988-1997
+    0.1492, -0.0825, 0.1666, 0.0557, 0.1512, 0.0038, 0.0449, 0.0287, 0.0196,
+    0.1021, // 1998-2007
+    0.201, -0.1112, 0.0846, 0.1604, 0.0297, -0.091, 0.1075, 0.0128, 0.0069,
+    0.028, // 2008-2017
+    -0.0002, 0.0964, 0.1133, -0.0442, -0.1783, 0.0388, // 2018-2023
+];
+
+/// Annual total returns of Baa rated US corporate bonds.
+pub const US_CORPORATE_BOND_RETURNS: [f64; HISTORIC_RETURN_YEARS] = [
+    0.0322, 0.0302, 0.0054, -0.1568, 0.2359, 0.1297, 0.1882, 0.1331, 0.1138,
+    -0.0442, // 1928-1937
+    0.0924, 0.0798, 0.0865, 0.0501, 0.0518, 0.0804, 0.0657, 0.068, 0.0251,
+    0.0026, // 1938-1947
+    0.0344, 0.0538, 0.0424, -0.0019, 0.0444, 0.0162, 0.0616, 0.0204, -0.0235,
+    -0.0072, // 1948-1957
+    0.0643, 0.0157, 0.0666, 0.051, 0.065, 0.0546, 0.0516, 0.0319, -0.0345, 0.009, // 1958-1967
+    0.0485, -0.0203, 0.0565, 0.14, 0.1141, 0.0432, -0.0438, 0.1105, 0.1975,
+    0.0995, // 1968-1977
+    0.0314, -0.0201, -0.0332, 0.0846, 0.2905, 0.1619, 0.1562, 0.2386, 0.2149,
+    0.0229, // 1978-1987
+    0.1512, 0.1579, 0.0614, 0.1785, 0.1217, 0.1643, -0.0132, 0.2016, 0.0479,
+    0.1183, // 1988-1997
+    0.0795, 0.0084, 0.0933, 0.0782, 0.1218, 0.1353, 0.0989, 0.0492, 0.0705,
+    0.0315, // 1998-2007
+    -0.0507, 0.2333, 0.0835, 0.1258, 0.1012, -0.0106, 0.1038, -0.007, 0.1037,
+    0.0972, // 2008-2017
+    -0.0276, 0.1533, 0.1041, 0.0093, -0.1514, 0.087, // 2018-2023
+];
+
+/// Annual total returns of US small company stocks: Ibbotson SBBI small company stocks
+/// through 2010, the Russell 2000 from 2011.
+pub const US_SMALL_CAP_EQUITY_RETURNS: [f64; HISTORIC_RETURN_YEARS] = [
+    0.3969, -0.5136, -0.3815, -0.4975, -0.0539, 1.4287, 0.2422, 0.4019, 0.648,
+    -0.5801, // 1928-1937
+    0.328, 0.0035, -0.0516, -0.09, 0.4451, 0.8837, 0.5372, 0.7361, -0.1163,
+    0.0092, // 1938-1947
+    -0.0211, 0.1975, 0.3875, 0.078, 0.0303, -0.0649, 0.6058, 0.2044, 0.0428,
+    -0.1457, // 1948-1957
+    0.6489, 0.164, -0.0329, 0.3209, -0.119, 0.2357, 0.2352, 0.4175, -0.0701,
+    0.8357, // 1958-1967
+    0.3597, -0.2505, -0.1743, 0.165, 0.0443, -0.309, -0.1995, 0.5282, 0.5738,
+    0.2538, // 1968-1977
+    0.2346, 0.4346, 0.3988, 0.1388, 0.2801, 0.3967, -0.0667, 0.2466, 0.0685,
+    -0.093, // 1978-1987
+    0.2287, 0.1018, -0.2156, 0.4463, 0.2335, 0.2098, 0.0311, 0.3446, 0.1762,
+    0.2278, // 1988-1997
+    -0.0731, 0.2979, -0.0359, 0.2277, -0.1328, 0.607, 0.1839, 0.0569, 0.1617,
+    -0.0522, // 1998-2007
+    -0.3672, 0.2809, 0.3126, -0.0418, 0.1635, 0.3882, 0.0489, -0.0441, 0.2131,
+    0.1465, // 2008-2017
+    -0.1101, 0.2552, 0.1996, 0.1482, -0.2044, 0.1693, // 2018-2023
+];
+
+/// First year of the mid cap returns, the first full year of the S&P MidCap 400.
+pub const US_MID_CAP_EQUITY_FIRST_YEAR: u32 = 1991;
+
+/// Annual total returns of the S&P MidCap 400, dividends reinvested.
+pub const US_MID_CAP_EQUITY_RETURNS: [f64; 33] = [
+    0.501, 0.1191, 0.1396, -0.0358, 0.3095, 0.192, 0.3225, 0.1912, 0.1472,
+    0.1751, // 1991-2000
+    -0.006, -0.1451, 0.3562, 0.1648, 0.1256, 0.1032, 0.0798, -0.3623, 0.3738,
+    0.2664, // 2001-2010
+    -0.0173, 0.1788, 0.335, 0.0977, -0.0218, 0.2074, 0.1624, -0.1108, 0.262,
+    0.1366, // 2011-2020
+    0.2476, -0.1306, 0.1644, // 2021-2023
+];
+
+/// Annual change in the year-end US dollar price of gold: the official price of $20.67
+/// an ounce until the Gold Reserve Act set $35 in 1934, the London fixing from 1968.
+pub const GOLD_RETURNS: [f64; HISTORIC_RETURN_YEARS] = [
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6933, 0.0, 0.0, 0.0, // 1928-1937
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, // 1938-1947
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, // 1948-1957
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, // 1958-1967
+    0.1971, -0.1599, 0.0619, 0.1637, 0.4874, 0.7349, 0.6615, -0.248, -0.041,
+    0.2264, // 1968-1977
+    0.3701, 1.2655, 0.1519, -0.326, 0.1494, -0.1631, -0.1938, 0.0607, 0.1954,
+    0.2446, // 1978-1987
+    -0.1569, -0.0223, -0.0369, -0.0856, -0.0571, 0.1764, -0.0217, 0.0098, -0.0459,
+    -0.2141, // 1988-1997
+    -0.0083, 0.0085, -0.0606, 0.0141, 0.2557, 0.1989, 0.0465, 0.1777, 0.232,
+    0.3192, // 1998-2007
+    0.0432, 0.2504, 0.2924, 0.0893, 0.0826, -0.2733, 0.0012, -0.1211, 0.081,
+    0.1266, // 2008-2017
+    -0.0093, 0.1843, 0.2461, -0.0433, 0.0036, 0.138, // 2018-2023
+];
+
+/// Annual change in the average US consumer price index (CPI-U).
+pub const US_INFLATION_RETURNS: [f64; HISTORIC_RETURN_YEARS] = [
+    -0.017, 0.0, -0.023, -0.09, -0.099, -0.051, 0.031, 0.022, 0.015, 0.036, // 1928-1937
+    -0.021, -0.014, 0.007, 0.05, 0.109, 0.061, 0.017, 0.023, 0.083, 0.144, // 1938-1947
+    0.081, -0.012, 0.013, 0.079, 0.019, 0.008, 0.007, -0.004, 0.015, 0.033, // 1948-1957
+    0.028, 0.007, 0.017, 0.01, 0.01, 0.013, 0.013, 0.016, 0.029, 0.031, // 1958-1967
+    0.042, 0.055, 0.057, 0.044, 0.032, 0.062, 0.11, 0.091, 0.058, 0.065, // 1968-1977
+    0.076, 0.113, 0.135, 0.103, 0.062, 0.032, 0.043, 0.036, 0.019, 0.036, // 1978-1987
+    0.041, 0.048, 0.054, 0.042, 0.03, 0.03, 0.026, 0.028, 0.03, 0.023, // 1988-1997
+    0.016, 0.022, 0.034, 0.028, 0.016, 0.023, 0.027, 0.034, 0.032, 0.028, // 1998-2007
+    0.038, -0.004, 0.016, 0.032, 0.021, 0.015, 0.016, 0.001, 0.013, 0.021, // 2008-2017
+    0.024, 0.018, 0.012, 0.047, 0.08, 0.041, // 2018-2023
+];
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A series of the embedded historic returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+pub enum HistoricSeries {
+    /// US large cap equity - the S&P 500.
+    UsLargeCapEquity,
+    /// US mid cap equity - the S&P MidCap 400, from 1991.
+    UsMidCapEquity,
+    /// US small cap equity.
+    UsSmallCapEquity,
+    /// 3-month US Treasury bills.
+    UsTreasuryBill,
+    /// 10-year US Treasury bonds.
+    UsTreasuryBond,
+    /// Baa rated US corporate bonds.
+    UsCorporateBond,
+    /// Gold.
+    Gold,
+    /// US consumer price inflation.
+    UsInflation,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Years covered by the embedded dataset.
+///
+///   * _return_ - The range, `end` exclusive.
+#[inline]
+pub fn historic_years() -> YearRange {
+    // α <fn historic_years>
+    YearRange {
+        start: HISTORIC_RETURNS_FIRST_YEAR,
+        end: HISTORIC_RETURNS_FIRST_YEAR + HISTORIC_RETURN_YEARS as u32,
+    }
+    // ω <fn historic_years>
+}
+
+/// Estimate the return distribution of a series over a window of years.
+///
+///   * **series** - The series.
+///   * **years** - Window of years, `end` exclusive, clipped to the dataset.
+///   * _return_ - Sample mean and standard deviation, `None` if fewer than 2 years of data.
+pub fn historic_normal_spec(series: HistoricSeries, years: &YearRange) -> Option<NormalSpec> {
+    // α <fn historic_normal_spec>
+
+    let mut incremental_stats = IncrementalStats::new(0);
+    for year_value in series.returns_in_range(years) {
+        incremental_stats.push_value(year_value.value);
+    }
+
+    Some(NormalSpec {
+        mean: incremental_stats.mean()?,
+        std_dev: incremental_stats.std_dev()?,
+    })
+
+    // ω <fn historic_normal_spec>
+}
+
+/// Estimate the correlations between series over a window of years.
+/// Years in which any of the series has no return are skipped.
+///
+///   * **series** - The series, in the order of rows/columns of the matrix.
+///   * **years** - Window of years, `end` exclusive, clipped to the dataset.
+///   * _return_ - Square correlation matrix, `None` if fewer than 2 years of data for all series.
+pub fn historic_correlations(series: &[HistoricSeries], years: &YearRange) -> Option<Array2<f64>> {
+    // α <fn historic_correlations>
+
+    let mut incremental_pearson = IncrementalPearson::new(series.len());
+    for year in clipped_years(years) {
+        if let Some(row) = series
+            .iter()
+            .map(|series| series.return_in_year(year))
+            .collect::<Option<Array1<f64>>>()
+        {
+            incremental_pearson.track_row(&row);
+        }
+    }
+
+    incremental_pearson.correlation_matrix()
+
+    // ω <fn historic_correlations>
+}
+
+/// Years of a range within the dataset.
+///
+///   * **years** - Window of years, `end` exclusive.
+///   * _return_ - The years of the window present in the dataset.
+pub(crate) fn clipped_years(years: &YearRange) -> std::ops::Range<u32> {
+    // α <fn clipped_years>
+    let available = historic_years();
+    years.start.max(available.start)..years.end.min(available.end)
+    // ω <fn clipped_years>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl HistoricSeries {
+    /// Annual returns of the series, starting at its `first_year`.
+    ///
+    ///   * _return_ - The returns.
+    pub fn returns(&self) -> &'static [f64] {
+        // α <fn HistoricSeries::returns>
+        match self {
+            HistoricSeries::UsLargeCapEquity => &US_LARGE_CAP_EQUITY_RETURNS,
+            HistoricSeries::UsMidCapEquity => &US_MID_CAP_EQUITY_RETURNS,
+            HistoricSeries::UsSmallCapEquity => &US_SMALL_CAP_EQUITY_RETURNS,
+            HistoricSeries::UsTreasuryBill => &US_TREASURY_BILL_RETURNS,
+            HistoricSeries::UsTreasuryBond => &US_TREASURY_BOND_RETURNS,
+            HistoricSeries::UsCorporateBond => &US_CORPORATE_BOND_RETURNS,
+            HistoricSeries::Gold => &GOLD_RETURNS,
+            HistoricSeries::UsInflation => &US_INFLATION_RETURNS,
+        }
+        // ω <fn HistoricSeries::returns>
+    }
+
+    /// First year with a return in the series.
+    ///
+    ///   * _return_ - The year, `HISTORIC_RETURNS_FIRST_YEAR` unless the series starts later.
+    pub fn first_year(&self) -> u32 {
+        // α <fn HistoricSeries::first_year>
+        match self {
+            HistoricSeries::UsMidCapEquity => US_MID_CAP_EQUITY_FIRST_YEAR,
+            _ => HISTORIC_RETURNS_FIRST_YEAR,
+        }
+        // ω <fn HistoricSeries::first_year>
+    }
+
+    /// The kind of instrument the series represents. Gold is investable but has no kind:
+    /// it is neither a stock nor a bond, nor the stock and bond fund `instrument_series_mix`
+    /// replays for a `MutualFund`.
+    ///
+    ///   * _return_ - The instrument, `None` for inflation and gold.
+    pub fn distribution_instrument(&self) -> Option<DistributionInstrument> {
+        // α <fn HistoricSeries::distribution_instrument>
+        match self {
+            HistoricSeries::UsLargeCapEquity
+            | HistoricSeries::UsMidCapEquity
+            | HistoricSeries::UsSmallCapEquity => Some(DistributionInstrument::Equity),
+            HistoricSeries::UsTreasuryBill
+            | HistoricSeries::UsTreasuryBond
+            | HistoricSeries::UsCorporateBond => Some(DistributionInstrument::InterestBearing),
+            HistoricSeries::Gold | HistoricSeries::UsInflation => None,
+        }
+        // ω <fn HistoricSeries::distribution_instrument>
+    }
+
+    /// Short label for the series.
+    ///
+    ///   * _return_ - The label.
+    pub fn label(&self) -> &'static str {
+        // α <fn HistoricSeries::label>
+        match self {
+            HistoricSeries::UsLargeCapEquity => "US Large Cap",
+            HistoricSeries::UsMidCapEquity => "US Mid Cap",
+            HistoricSeries::UsSmallCapEquity => "US Small Cap",
+            HistoricSeries::UsTreasuryBill => "US T-Bills",
+            HistoricSeries::UsTreasuryBond => "US T-Bonds",
+            HistoricSeries::UsCorporateBond => "US Corp Bonds",
+            HistoricSeries::Gold => "Gold",
+            HistoricSeries::UsInflation => "US Inflation",
+        }
+        // ω <fn HistoricSeries::label>
+    }
+
+    /// Return of the series in a year.
+    ///
+    ///   * **year** - The year.
+    ///   * _return_ - The return, `None` if the year is not in the dataset.
+    pub fn return_in_year(&self, year: u32) -> Option<f64> {
+        // α <fn HistoricSeries::return_in_year>
+        year.checked_sub(self.first_year())
+            .and_then(|index| self.returns().get(index as usize))
+            .copied()
+        // ω <fn HistoricSeries::return_in_year>
+    }
+
+    /// Returns of the series over a window of years.
+    ///
+    ///   * **years** - Window of years, `end` exclusive, clipped to the dataset.
+    ///   * _return_ - The returns by year.
+    pub fn returns_in_range(&self, years: &YearRange) -> Vec<YearValue> {
+        // α <fn HistoricSeries::returns_in_range>
+        clipped_years(years)
+            .filter_map(|year| {
+                self.return_in_year(year)
+                    .map(|value| YearValue { year, value })
+            })
+            .collect()
+        // ω <fn HistoricSeries::returns_in_range>
+    }
+}
+
+/// Unit tests for `historic_returns`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use strum::IntoEnumIterator;
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_historic_years() {
+        // α <fn test_historic_years>
+        assert_eq!(
+            YearRange {
+                start: 1928,
+                end: 2024
+            },
+            historic_years()
+        );
+        // ω <fn test_historic_years>
+    }
+
+    #[test]
+    fn test_historic_normal_spec() {
+        // α <fn test_historic_normal_spec>
+
+        // Full history matches the published arithmetic averages and standard deviations
+        for (series, mean, std_dev) in [
+            (HistoricSeries::UsLargeCapEquity, 0.1166, 0.1955),
+            (HistoricSeries::UsTreasuryBill, 0.0334, 0.0301),
+            (HistoricSeries::UsTreasuryBond, 0.0486, 0.0795),
+            (HistoricSeries::UsCorporateBond, 0.0697, 0.0778),
+        ] {
+            let normal_spec = historic_normal_spec(series, &historic_years()).unwrap();
+            assert!(
+                (normal_spec.mean - mean).abs() < 5e-5,
+                "{series:?} {normal_spec:?}"
+            );
+            assert!(
+                (normal_spec.std_dev - std_dev).abs() < 5e-5,
+                "{series:?} {normal_spec:?}"
+            );
+        }
+
+        // Window clipped to the dataset
+        let window = YearRange {
+            start: 2019,
+            end: 2100,
+        };
+        let normal_spec = historic_normal_spec(HistoricSeries::UsLargeCapEquity, &window).unwrap();
+        let expected_mean = (0.3121 + 0.1802 + 0.2847 - 0.1804 + 0.2606) / 5.0;
+        assert!((normal_spec.mean - expected_mean).abs() < 1e-12);
+
+        let single_year = YearRange {
+            start: 2008,
+            end: 2009,
+        };
+        assert_eq!(
+            None,
+            historic_normal_spec(HistoricSeries::UsLargeCapEquity, &single_year)
+        );
+
+        // ω <fn test_historic_normal_spec>
+    }
+
+    #[test]
+    fn test_historic_correlations() {
+        // α <fn test_historic_correlations>
+
+        let series = [
+            HistoricSeries::UsLargeCapEquity,
+            HistoricSeries::UsTreasuryBill,
+            HistoricSeries::UsTreasuryBond,
+            HistoricSeries::UsCorporateBond,
+            HistoricSeries::UsInflation,
+        ];
+        let correlations = historic_correlations(&series, &historic_years()).unwrap();
+        assert_eq!((5, 5), correlations.dim());
+        for i in 0..series.len() {
+            assert!((correlations[[i, i]] - 1.0).abs() < 1e-12);
+            for j in 0..series.len() {
+                assert!((correlations[[i, j]] - correlations[[j, i]]).abs() < 1e-12);
+            }
+        }
+
+        // Treasury bonds track each other more than stocks do
+        let bond_corporate = correlations[[2, 3]];
+        let stock_bond = correlations[[0, 2]];
+        assert!(bond_corporate > 0.5);
+        assert!(stock_bond.abs() < bond_corporate);
+
+        assert_eq!(
+            None,
+            historic_correlations(
+                &series,
+                &YearRange {
+                    start: 1900,
+                    end: 1920
+                }
+            )
+        );
+
+        // Years before the mid cap returns start are skipped
+        let series = HistoricSeries::iter().collect::<Vec<_>>();
+        let since_mid_cap = YearRange {
+            start: US_MID_CAP_EQUITY_FIRST_YEAR,
+            end: historic_years().end,
+        };
+        assert_eq!(
+            historic_correlations(&series, &since_mid_cap),
+            historic_correlations(&series, &historic_years())
+        );
+
+        // ω <fn test_historic_correlations>
+    }
+
+    /// Test type HistoricSeries
+    mod test_historic_series {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn return_in_year() {
+            // α <fn test HistoricSeries::return_in_year>
+            assert_eq!(
+                Some(0.4381),
+                HistoricSeries::UsLargeCapEquity.return_in_year(1928)
+            );
+            assert_eq!(
+                Some(-0.3655),
+                HistoricSeries::UsLargeCapEquity.return_in_year(2008)
+            );
+            assert_eq!(
+                Some(0.0507),
+                HistoricSeries::UsTreasuryBill.return_in_year(2023)
+            );
+            assert_eq!(None, HistoricSeries::UsTreasuryBill.return_in_year(1927));
+            assert_eq!(None, HistoricSeries::UsTreasuryBill.return_in_year(2024));
+            assert_eq!(
+                Some(0.501),
+                HistoricSeries::UsMidCapEquity.return_in_year(1991)
+            );
+            assert_eq!(None, HistoricSeries::UsMidCapEquity.return_in_year(1990));
+            assert_eq!(Some(1.2655), HistoricSeries::Gold.return_in_year(1979));
+            // ω <fn test HistoricSeries::return_in_year>
+        }
+
+        #[test]
+        fn returns_in_range() {
+            // α <fn test HistoricSeries::returns_in_range>
+            let returns = HistoricSeries::UsInflation.returns_in_range(&YearRange {
+                start: 2020,
+                end: 2030,
+            });
+            assert_eq!(
+                vec![(2020, 0.012), (2021, 0.047), (2022, 0.08), (2023, 0.041)],
+                returns
+                    .iter()
+                    .map(|year_value| (year_value.year, year_value.value))
+                    .collect::<Vec<_>>()
+            );
+            // ω <fn test HistoricSeries::returns_in_range>
+        }
+
+        #[test]
+        fn distribution_instrument() {
+            // α <fn test HistoricSeries::distribution_instrument>
+            assert_eq!(
+                Some(DistributionInstrument::Equity),
+                HistoricSeries::UsLargeCapEquity.distribution_instrument()
+            );
+            assert_eq!(
+                Some(DistributionInstrument::InterestBearing),
+                HistoricSeries::UsCorporateBond.distribution_instrument()
+            );
+            assert_eq!(None, HistoricSeries::Gold.distribution_instrument());
+            assert_eq!(None, HistoricSeries::UsInflation.distribution_instrument());
+            // ω <fn test HistoricSeries::distribution_instrument>
+        }
+
+        // α <mod-def test_historic_series>
+        use super::*;
+        // ω <mod-def test_historic_series>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def historic_returns>
+// ω <mod-def historic_returns>
@@ -15,6 +15,11 @@ extern crate strum_macros;
 ////////////////////////////////////////////////////////////////////////////////////
 pub use self::constants::DEFAULT_DEATH_AGE;
 pub use self::constants::DEFAULT_RETIREMENT_AGE;
+pub use crate::contribution_limits::Contribution;
+pub use crate::contribution_limits::ContributionLimitGroup;
+pub use crate::contribution_limits::ContributionLimitViolation;
+pub use crate::contribution_limits::Contributor;
+pub use crate::contribution_limits::HsaCoverage;
 pub use crate::core::dossier_item_index::ItemIndex;
 pub use crate::core::CurrencyValue;
 pub use crate::core::Date;
@@ -53,16 +58,77 @@ pub use crate::core_enums::TaxUsCategory;
 pub use crate::core_enums::TaxUsFilingStatus;
 pub use crate::core_enums::WorthType;
 pub use crate::core_enums::YearEndpoint;
+pub use crate::correlated_shocks::CorrelatedShockSampler;
+pub use crate::currency_impl::CurrencyMetadata;
+pub use crate::currency_impl::SymbolPlacement;
+pub use crate::currency_risk::CurrencyRiskSimulator;
+pub use crate::currency_risk::CurrencyRiskSpec;
+pub use crate::efficient_frontier::FrontierAllocation;
+pub use crate::efficient_frontier::MeanVarianceOptimizer;
+pub use crate::forecast_config::ForecastConfig;
+pub use crate::historic_bootstrap::HistoricBootstrap;
+pub use crate::historic_bootstrap::SimulationMode;
+pub use crate::historic_returns::HistoricSeries;
+pub use crate::required_minimum_distribution::RmdAccount;
+pub use crate::required_minimum_distribution::RmdWithdrawal;
+pub use crate::return_distribution::ReturnDistribution;
+pub use crate::roth_conversion::RothConversionComparison;
+pub use crate::roth_conversion::RothConversionOutcome;
+pub use crate::roth_conversion::RothConversionPlanner;
+pub use crate::roth_conversion::RothConversionTarget;
+pub use crate::roth_conversion::RothConversionYear;
+pub use crate::social_security::SocialSecurityClaim;
+pub use crate::social_security::SocialSecurityEarner;
+pub use crate::social_security::SocialSecurityEstimate;
+pub use crate::social_security::SocialSecurityParameters;
+pub use crate::stochastic_inflation::CorrelatedInflationSampler;
+pub use crate::stochastic_inflation::StochasticInflation;
+pub use crate::stress_scenario::StressOutcome;
+pub use crate::stress_scenario::StressScenario;
+pub use crate::tax_brackets::TaxBracket;
+pub use crate::tax_jurisdiction::FrTaxJurisdiction;
+pub use crate::tax_jurisdiction::TaxAssessment;
+pub use crate::tax_jurisdiction::TaxJurisdiction;
+pub use crate::tax_jurisdiction::TaxWrapper;
+pub use crate::tax_jurisdiction::TaxableIncome;
+pub use crate::tax_jurisdiction::UkTaxJurisdiction;
+pub use crate::tax_jurisdiction::UsTaxJurisdiction;
+pub use crate::tax_jurisdiction::WrapperWithdrawal;
+pub use crate::tax_lot::CostBasisMethod;
+pub use crate::tax_lot::RealizedGain;
+pub use crate::tax_lot::TaxLot;
+pub use crate::tax_lot::TaxLots;
 pub use plus_utils::SystemUnicodes;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- mod decls ---
 ////////////////////////////////////////////////////////////////////////////////////
 pub mod constants;
+pub mod contribution_limits;
 pub mod core;
 pub mod core_display;
 pub mod core_enums;
+pub mod correlated_shocks;
+pub mod correlation_matrix_impl;
 pub mod currency_impl;
+pub mod currency_risk;
+pub mod efficient_frontier;
+pub mod forecast_config;
+pub mod historic_bootstrap;
+pub mod historic_returns;
+pub mod normal_spec_impl;
+pub mod rate_curve_impl;
+pub mod required_minimum_distribution;
+pub mod return_distribution;
+pub mod roth_conversion;
+pub mod social_security;
+pub mod stochastic_inflation;
+pub mod stress_scenario;
+pub mod tax_brackets;
+pub mod tax_jurisdiction;
+pub mod tax_lot;
+pub mod tax_us_brackets;
+pub mod time_value;
 
 // α <mod-def lib>
 
@@ -0,0 +1,260 @@
+//! An impl for struct normal spec
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::DossierCorrelationMatrix;
+use crate::DossierItemIndex;
+use crate::NormalSpec;
+use ndarray::Array2;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl NormalSpec {
+    /// Combined return of a portfolio of holdings with normally distributed returns.
+    /// The portfolio mean is the weighted mean and its variance `Σᵢ Σⱼ wᵢ wⱼ σᵢ σⱼ ρᵢⱼ`.
+    /// Weights are normalized to sum to one, so market values may be passed directly.
+    ///
+    ///   * **weights** - Weight (or market value) of each holding.
+    ///   * **normal_specs** - Return of each holding.
+    ///   * **correlations** - Square matrix of correlations between holdings' returns.
+    ///   * _return_ - The portfolio return, `None` if sizes differ or the weights sum to 0.
+    pub fn portfolio(
+        weights: &[f64],
+        normal_specs: &[NormalSpec],
+        correlations: &Array2<f64>,
+    ) -> Option<NormalSpec> {
+        // α <fn NormalSpec::portfolio>
+
+        let holdings = weights.len();
+        if normal_specs.len() != holdings || correlations.dim() != (holdings, holdings) {
+            return None;
+        }
+
+        let total_weight = weights.iter().sum::<f64>();
+        if total_weight == 0.0 || !total_weight.is_finite() {
+            return None;
+        }
+        let weights = weights
+            .iter()
+            .map(|weight| weight / total_weight)
+            .collect::<Vec<_>>();
+
+        let mean = weights
+            .iter()
+            .zip(normal_specs)
+            .map(|(weight, normal_spec)| weight * normal_spec.mean)
+            .sum();
+
+        let mut variance = 0.0;
+        for (i, (weight_i, spec_i)) in weights.iter().zip(normal_specs).enumerate() {
+            for (j, (weight_j, spec_j)) in weights.iter().zip(normal_specs).enumerate() {
+                variance +=
+                    weight_i * weight_j * spec_i.std_dev * spec_j.std_dev * correlations[[i, j]];
+            }
+        }
+
+        Some(NormalSpec {
+            mean,
+            // Guard against round off in a near singular matrix
+            std_dev: variance.max(0.0).sqrt(),
+        })
+
+        // ω <fn NormalSpec::portfolio>
+    }
+
+    /// Combined return of a portfolio of dossier holdings, with the correlations of
+    /// their returns looked up in a dossier correlation matrix.
+    ///
+    ///   * **holdings** - Item, weight (or market value) and return of each holding.
+    ///   * **correlation_matrix** - Correlations between items.
+    ///   * _return_ - The portfolio return, `None` if a correlation is missing or the weights sum to 0.
+    pub fn portfolio_of_dossier_items(
+        holdings: &[(DossierItemIndex, f64, NormalSpec)],
+        correlation_matrix: &DossierCorrelationMatrix,
+    ) -> Option<NormalSpec> {
+        // α <fn NormalSpec::portfolio_of_dossier_items>
+
+        let holding_count = holdings.len();
+        let mut correlations = Array2::<f64>::zeros((holding_count, holding_count));
+        for (i, (row_item, _, _)) in holdings.iter().enumerate() {
+            for (j, (column_item, _, _)) in holdings.iter().enumerate() {
+                correlations[[i, j]] = correlation_matrix.correlation(row_item, column_item)?;
+            }
+        }
+
+        let weights = holdings
+            .iter()
+            .map(|(_, weight, _)| *weight)
+            .collect::<Vec<_>>();
+        let normal_specs = holdings
+            .iter()
+            .map(|(_, _, normal_spec)| *normal_spec)
+            .collect::<Vec<_>>();
+
+        NormalSpec::portfolio(&weights, &normal_specs, &correlations)
+
+        // ω <fn NormalSpec::portfolio_of_dossier_items>
+    }
+
+    /// Approximate geometric mean of the return (i.e. the compound annual return),
+    /// `mean - std_dev²/2`. Volatility drags the compound return below the mean, which
+    /// is why diversifying lifts the geometric return of a portfolio.
+    ///
+    ///   * _return_ - The geometric mean.
+    #[inline]
+    pub fn geometric_mean(&self) -> f64 {
+        // α <fn NormalSpec::geometric_mean>
+        self.mean - 0.5 * self.std_dev * self.std_dev
+        // ω <fn NormalSpec::geometric_mean>
+    }
+}
+
+/// Unit tests for `normal_spec_impl`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type NormalSpec
+    mod test_normal_spec {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::DossierCorrelationMatrix;
+        use crate::DossierItemIndex;
+        use crate::NormalSpec;
+        use ndarray::array;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn portfolio() {
+            // α <fn test NormalSpec::portfolio>
+
+            let stocks = NormalSpec {
+                mean: 0.10,
+                std_dev: 0.20,
+            };
+            let bonds = NormalSpec {
+                mean: 0.04,
+                std_dev: 0.05,
+            };
+
+            // 60/40 with correlation 0.2 - weights given as market values
+            let portfolio = NormalSpec::portfolio(
+                &[60.0, 40.0],
+                &[stocks, bonds],
+                &array![[1.0, 0.2], [0.2, 1.0]],
+            )
+            .unwrap();
+            assert!((portfolio.mean - 0.076).abs() < 1e-12);
+            let variance = 0.6f64.powi(2) * 0.04
+                + 0.4f64.powi(2) * 0.0025
+                + 2.0 * 0.6 * 0.4 * 0.2 * 0.05 * 0.2;
+            assert!((portfolio.std_dev - variance.sqrt()).abs() < 1e-12);
+
+            // Perfectly correlated holdings do not diversify
+            let portfolio = NormalSpec::portfolio(
+                &[0.5, 0.5],
+                &[stocks, bonds],
+                &array![[1.0, 1.0], [1.0, 1.0]],
+            )
+            .unwrap();
+            assert!((portfolio.std_dev - 0.125).abs() < 1e-12);
+
+            // A single holding is itself
+            assert_eq!(
+                Some(stocks),
+                NormalSpec::portfolio(&[3.0], &[stocks], &array![[1.0]])
+            );
+
+            assert_eq!(
+                None,
+                NormalSpec::portfolio(&[0.5, 0.5], &[stocks], &array![[1.0]])
+            );
+            assert_eq!(
+                None,
+                NormalSpec::portfolio(
+                    &[0.0, 0.0],
+                    &[stocks, bonds],
+                    &array![[1.0, 0.2], [0.2, 1.0]]
+                )
+            );
+
+            // ω <fn test NormalSpec::portfolio>
+        }
+
+        #[test]
+        fn portfolio_of_dossier_items() {
+            // α <fn test NormalSpec::portfolio_of_dossier_items>
+
+            use crate::ItemIndex;
+
+            let stocks = NormalSpec {
+                mean: 0.10,
+                std_dev: 0.20,
+            };
+            let bonds = NormalSpec {
+                mean: 0.04,
+                std_dev: 0.05,
+            };
+            let items = [1, 2, 3]
+                .into_iter()
+                .map(|index| DossierItemIndex {
+                    item_index: Some(ItemIndex::WorthIndex(index)),
+                })
+                .collect::<Vec<_>>();
+            let correlation_matrix = DossierCorrelationMatrix::from_correlations(
+                &items[..2],
+                &array![[1.0, 0.2], [0.2, 1.0]],
+            );
+
+            let portfolio = NormalSpec::portfolio_of_dossier_items(
+                &[(items[1], 40.0, bonds), (items[0], 60.0, stocks)],
+                &correlation_matrix,
+            )
+            .unwrap();
+            let expected = NormalSpec::portfolio(
+                &[60.0, 40.0],
+                &[stocks, bonds],
+                &array![[1.0, 0.2], [0.2, 1.0]],
+            )
+            .unwrap();
+            assert!((portfolio.mean - expected.mean).abs() < 1e-12);
+            assert!((portfolio.std_dev - expected.std_dev).abs() < 1e-12);
+
+            // No correlation for the third item
+            assert_eq!(
+                None,
+                NormalSpec::portfolio_of_dossier_items(
+                    &[(items[0], 60.0, stocks), (items[2], 40.0, bonds)],
+                    &correlation_matrix,
+                )
+            );
+
+            // ω <fn test NormalSpec::portfolio_of_dossier_items>
+        }
+
+        #[test]
+        fn geometric_mean() {
+            // α <fn test NormalSpec::geometric_mean>
+            let normal_spec = NormalSpec {
+                mean: 0.10,
+                std_dev: 0.20,
+            };
+            assert!((normal_spec.geometric_mean() - 0.08).abs() < 1e-12);
+            // ω <fn test NormalSpec::geometric_mean>
+        }
+
+        // α <mod-def test_normal_spec>
+        // ω <mod-def test_normal_spec>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def normal_spec_impl>
+// ω <mod-def normal_spec_impl>
@@ -0,0 +1,248 @@
+//! An impl for struct rate curve
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::time_value::flows_currency;
+use crate::CurrencyValue;
+use crate::RateCurve;
+use crate::YearCurrencyValue;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl RateCurve {
+    /// The rate in effect for a year. The curve is a step function sorted by year:
+    /// each rate holds from its year until the next entry, the first rate applies to
+    /// earlier years and the last to all later years.
+    ///
+    ///   * **year** - Year to get the rate for.
+    ///   * _return_ - The rate, 0 for an empty curve.
+    pub fn rate_in_year(&self, year: u32) -> f64 {
+        // α <fn RateCurve::rate_in_year>
+
+        let after = self
+            .curve
+            .partition_point(|year_value| year_value.year <= year);
+        self.curve
+            .get(after.saturating_sub(1))
+            .map(|year_value| year_value.value)
+            .unwrap_or_default()
+
+        // ω <fn RateCurve::rate_in_year>
+    }
+
+    /// Factor moving a value from one year to another by compounding the rates between.
+    /// Discounting to an earlier year divides by the growth in each year between, growing
+    /// to a later year multiplies by it.
+    ///
+    ///   * **from_year** - Year the value is in.
+    ///   * **to_year** - Year the value is moved to.
+    ///   * _return_ - Multiplier taking a value in `from_year` to `to_year`.
+    pub fn discount_factor(&self, from_year: u32, to_year: u32) -> f64 {
+        // α <fn RateCurve::discount_factor>
+
+        let growth = (from_year.min(to_year)..from_year.max(to_year))
+            .map(|year| 1.0 + self.rate_in_year(year))
+            .product::<f64>();
+
+        if from_year > to_year {
+            1.0 / growth
+        } else {
+            growth
+        }
+
+        // ω <fn RateCurve::discount_factor>
+    }
+
+    /// Present value of flows, e.g. the payments of a pension, discounted by the curve.
+    ///
+    ///   * **flows** - The cash flows, in any order.
+    ///   * **valuation_year** - Year the value is stated in.
+    ///   * _return_ - The present value, `None` if no flows or flows in more than one currency.
+    pub fn present_value_of_flows(
+        &self,
+        flows: &[YearCurrencyValue],
+        valuation_year: u32,
+    ) -> Option<CurrencyValue> {
+        // α <fn RateCurve::present_value_of_flows>
+
+        let currency = flows_currency(flows)?;
+        Some(CurrencyValue {
+            currency,
+            value: flows
+                .iter()
+                .map(|flow| flow.value * self.discount_factor(flow.year, valuation_year))
+                .sum(),
+        })
+
+        // ω <fn RateCurve::present_value_of_flows>
+    }
+
+    /// Index flows stated in a base year's money by the curve, e.g. living expenses or
+    /// a COLA pension grown by one sampled path of inflation.
+    ///
+    ///   * **flows** - The cash flows, valued in `base_year` money.
+    ///   * **base_year** - Year the flow values are stated in.
+    ///   * _return_ - The flows in the money of their own years.
+    pub fn index_flows(
+        &self,
+        flows: &[YearCurrencyValue],
+        base_year: u32,
+    ) -> Vec<YearCurrencyValue> {
+        // α <fn RateCurve::index_flows>
+        flows
+            .iter()
+            .map(|flow| YearCurrencyValue {
+                value: flow.value * self.discount_factor(base_year, flow.year),
+                ..*flow
+            })
+            .collect()
+        // ω <fn RateCurve::index_flows>
+    }
+}
+
+/// Unit tests for `rate_curve_impl`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type RateCurve
+    mod test_rate_curve {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::Currency;
+        use crate::RateCurve;
+        use crate::YearCurrencyValue;
+        use crate::YearValue;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn rate_in_year() {
+            // α <fn test RateCurve::rate_in_year>
+
+            let rate_curve = sample_curve();
+            assert_eq!(0.02, rate_curve.rate_in_year(2000));
+            assert_eq!(0.02, rate_curve.rate_in_year(2024));
+            assert_eq!(0.02, rate_curve.rate_in_year(2025));
+            assert_eq!(0.05, rate_curve.rate_in_year(2026));
+            assert_eq!(0.05, rate_curve.rate_in_year(2100));
+            assert_eq!(0.0, RateCurve::default().rate_in_year(2024));
+
+            // ω <fn test RateCurve::rate_in_year>
+        }
+
+        #[test]
+        fn discount_factor() {
+            // α <fn test RateCurve::discount_factor>
+
+            let rate_curve = sample_curve();
+            assert_eq!(1.0, rate_curve.discount_factor(2025, 2025));
+            assert!((rate_curve.discount_factor(2024, 2027) - 1.02 * 1.02 * 1.05).abs() < 1e-12);
+            assert!(
+                (rate_curve.discount_factor(2027, 2024) - 1.0 / (1.02 * 1.02 * 1.05)).abs() < 1e-12
+            );
+
+            // ω <fn test RateCurve::discount_factor>
+        }
+
+        #[test]
+        fn present_value_of_flows() {
+            // α <fn test RateCurve::present_value_of_flows>
+
+            let rate_curve = sample_curve();
+            let flows = [2024, 2025, 2026]
+                .into_iter()
+                .map(|year| YearCurrencyValue {
+                    year,
+                    currency: Currency::Usd as i32,
+                    value: 1_000.0,
+                })
+                .collect::<Vec<_>>();
+
+            let present_value = rate_curve.present_value_of_flows(&flows, 2024).unwrap();
+            assert_eq!(Currency::Usd as i32, present_value.currency);
+            let expected = 1_000.0 + 1_000.0 / 1.02 + 1_000.0 / (1.02 * 1.02);
+            assert!((present_value.value - expected).abs() < 1e-9);
+
+            // A flat curve agrees with a constant rate NPV
+            let flat = RateCurve {
+                curve: vec![YearValue {
+                    year: 2024,
+                    value: 0.04,
+                }],
+            };
+            assert!(
+                (flat.present_value_of_flows(&flows, 2024).unwrap().value
+                    - crate::time_value::net_present_value(&flows, 0.04, 2024)
+                        .unwrap()
+                        .value)
+                    .abs()
+                    < 1e-9
+            );
+
+            assert_eq!(None, rate_curve.present_value_of_flows(&[], 2024));
+
+            // ω <fn test RateCurve::present_value_of_flows>
+        }
+
+        #[test]
+        fn index_flows() {
+            // α <fn test RateCurve::index_flows>
+
+            let rate_curve = sample_curve();
+            let flows = [2024, 2026, 2027]
+                .into_iter()
+                .map(|year| YearCurrencyValue {
+                    year,
+                    currency: Currency::Eur as i32,
+                    value: -1_000.0,
+                })
+                .collect::<Vec<_>>();
+
+            let indexed = rate_curve.index_flows(&flows, 2024);
+            assert_eq!(
+                vec![2024, 2026, 2027],
+                indexed.iter().map(|flow| flow.year).collect::<Vec<_>>()
+            );
+            assert!(indexed
+                .iter()
+                .all(|flow| flow.currency == Currency::Eur as i32));
+            assert_eq!(-1_000.0, indexed[0].value);
+            assert!((indexed[1].value + 1_000.0 * 1.02 * 1.02).abs() < 1e-9);
+            assert!((indexed[2].value + 1_000.0 * 1.02 * 1.02 * 1.05).abs() < 1e-9);
+
+            // ω <fn test RateCurve::index_flows>
+        }
+
+        /// Curve of 2% through 2025 then 5%.
+        ///
+        ///   * _return_ - The curve.
+        fn sample_curve() -> RateCurve {
+            RateCurve {
+                curve: vec![
+                    YearValue {
+                        year: 2024,
+                        value: 0.02,
+                    },
+                    YearValue {
+                        year: 2026,
+                        value: 0.05,
+                    },
+                ],
+            }
+        }
+
+        // α <mod-def test_rate_curve>
+        // ω <mod-def test_rate_curve>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def rate_curve_impl>
+// ω <mod-def rate_curve_impl>
@@ -0,0 +1,327 @@
+//! Required minimum distributions (RMDs) from tax deferred accounts.
+//!
+//! Owners of `TraditionalIrs401K` and `TraditionalIra` accounts must withdraw a minimum
+//! each year starting at the RMD age set by _SECURE 2.0_ (73 for those born 1951-1959,
+//! 75 for those born 1960 or later). The minimum is the prior year end balance divided
+//! by the distribution period of the IRS _Uniform Lifetime Table_ for the owner's age.
+//! Withdrawals are taxed as ordinary income.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::AccountType;
+use crate::PersonType;
+use crate::TaxUsCategory;
+use crate::YearRange;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// First age covered by `UNIFORM_LIFETIME_TABLE`.
+pub const UNIFORM_LIFETIME_TABLE_START_AGE: u32 = 70;
+
+/// Distribution periods of the IRS _Uniform Lifetime Table_ (effective 2022) by age,
+/// starting at `UNIFORM_LIFETIME_TABLE_START_AGE`. The last entry applies to ages 120 and over.
+pub const UNIFORM_LIFETIME_TABLE: [f64; 51] = [
+    29.1, 28.2, 27.4, 26.5, 25.5, 24.6, 23.7, 22.9, 22.0, 21.1, // 70-79
+    20.2, 19.4, 18.5, 17.7, 16.8, 16.0, 15.2, 14.4, 13.7, 12.9, // 80-89
+    12.2, 11.5, 10.8, 10.1, 9.5, 8.9, 8.4, 7.8, 7.3, 6.8, // 90-99
+    6.4, 6.0, 5.6, 5.2, 4.9, 4.6, 4.3, 4.1, 3.9, 3.7, // 100-109
+    3.5, 3.4, 3.3, 3.1, 3.0, 2.9, 2.8, 2.7, 2.5, 2.3, // 110-119
+    2.0, // 120+
+];
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A tax deferred account subject to RMDs and the owner it is distributed to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RmdAccount {
+    /// Owner of the account.
+    pub owner: PersonType,
+    /// Year of birth of the owner.
+    pub owner_birth_year: u32,
+    /// Type of the account.
+    pub account_type: AccountType,
+    /// Balance at the end of the year prior to the first projected year.
+    pub balance: f64,
+    /// Assumed annual growth of the balance.
+    pub growth: f64,
+}
+
+/// A forced withdrawal reported as its own line of a forecast.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RmdWithdrawal {
+    /// Year of the withdrawal.
+    pub year: u32,
+    /// Owner the withdrawal is distributed to.
+    pub owner: PersonType,
+    /// Type of the account withdrawn from.
+    pub account_type: AccountType,
+    /// Prior year end balance the distribution is based on.
+    pub prior_year_balance: f64,
+    /// Amount withdrawn.
+    pub amount: f64,
+    /// How the withdrawal is taxed - always ordinary income.
+    pub tax_category: TaxUsCategory,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The age RMDs must start for an owner born in `birth_year`.
+///
+///   * **birth_year** - Year of birth of the owner.
+///   * _return_ - Age of the first required distribution.
+pub fn rmd_start_age(birth_year: u32) -> u32 {
+    // α <fn rmd_start_age>
+
+    match birth_year {
+        year if year <= 1948 => 70,
+        1949..=1950 => 72,
+        1951..=1959 => 73,
+        _ => 75,
+    }
+
+    // ω <fn rmd_start_age>
+}
+
+/// The _Uniform Lifetime Table_ distribution period for `age`.
+///
+///   * **age** - Age of the owner at the end of the distribution year.
+///   * _return_ - The distribution period, `None` if younger than covered by the table.
+pub fn uniform_lifetime_distribution_period(age: u32) -> Option<f64> {
+    // α <fn uniform_lifetime_distribution_period>
+
+    if age < UNIFORM_LIFETIME_TABLE_START_AGE {
+        None
+    } else {
+        let index = ((age - UNIFORM_LIFETIME_TABLE_START_AGE) as usize)
+            .min(UNIFORM_LIFETIME_TABLE.len() - 1);
+        Some(UNIFORM_LIFETIME_TABLE[index])
+    }
+
+    // ω <fn uniform_lifetime_distribution_period>
+}
+
+/// The minimum that must be distributed from an account in `year`.
+///
+///   * **account_type** - Type of the account.
+///   * **birth_year** - Year of birth of the owner.
+///   * **year** - Year of the distribution.
+///   * **prior_year_balance** - Account balance at the end of the prior year.
+///   * _return_ - The required distribution, 0 if none required.
+pub fn required_minimum_distribution(
+    account_type: AccountType,
+    birth_year: u32,
+    year: u32,
+    prior_year_balance: f64,
+) -> f64 {
+    // α <fn required_minimum_distribution>
+
+    let age = year.saturating_sub(birth_year);
+    if !account_type.requires_minimum_distribution()
+        || age < rmd_start_age(birth_year)
+        || prior_year_balance <= 0.0
+    {
+        0.0
+    } else {
+        uniform_lifetime_distribution_period(age)
+            .map(|period| prior_year_balance / period)
+            .unwrap_or_default()
+    }
+
+    // ω <fn required_minimum_distribution>
+}
+
+/// Project the forced RMD withdrawals of each account over `years`.
+/// Each year the account grows, then the distribution based on the prior year end
+/// balance is withdrawn.
+///
+///   * **accounts** - Accounts to distribute from.
+///   * **years** - Range of years to project.
+///   * _return_ - One withdrawal line per account per year with a required distribution.
+pub fn project_required_distributions(
+    accounts: &[RmdAccount],
+    years: &YearRange,
+) -> Vec<RmdWithdrawal> {
+    // α <fn project_required_distributions>
+
+    let mut balances = accounts
+        .iter()
+        .map(|account| account.balance)
+        .collect::<Vec<_>>();
+    let mut withdrawals = Vec::new();
+
+    for year in years.start..years.end {
+        for (account, balance) in accounts.iter().zip(balances.iter_mut()) {
+            let prior_year_balance = *balance;
+            let amount = required_minimum_distribution(
+                account.account_type,
+                account.owner_birth_year,
+                year,
+                prior_year_balance,
+            );
+            *balance = (prior_year_balance * (1.0 + account.growth) - amount).max(0.0);
+            if amount > 0.0 {
+                withdrawals.push(RmdWithdrawal {
+                    year,
+                    owner: account.owner,
+                    account_type: account.account_type,
+                    prior_year_balance,
+                    amount,
+                    tax_category: TaxUsCategory::UsOrdinaryIncome,
+                });
+            }
+        }
+    }
+
+    withdrawals
+
+    // ω <fn project_required_distributions>
+}
+
+/// Total RMD withdrawals of `owner` in `year`, all of which are ordinary income.
+///
+///   * **withdrawals** - Projected withdrawals.
+///   * **owner** - Owner of interest.
+///   * **year** - Year of interest.
+///   * _return_ - Sum of the owner's withdrawals for the year.
+pub fn owner_required_distributions(
+    withdrawals: &[RmdWithdrawal],
+    owner: PersonType,
+    year: u32,
+) -> f64 {
+    // α <fn owner_required_distributions>
+
+    withdrawals
+        .iter()
+        .filter(|withdrawal| withdrawal.owner == owner && withdrawal.year == year)
+        .map(|withdrawal| withdrawal.amount)
+        .sum()
+
+    // ω <fn owner_required_distributions>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl AccountType {
+    /// Indicates the account is tax deferred and subject to required minimum distributions.
+    ///
+    ///   * _return_ - True for traditional IRA and 401K accounts.
+    #[inline]
+    pub fn requires_minimum_distribution(&self) -> bool {
+        // α <fn AccountType::requires_minimum_distribution>
+        matches!(
+            self,
+            AccountType::TraditionalIrs401K | AccountType::TraditionalIra
+        )
+        // ω <fn AccountType::requires_minimum_distribution>
+    }
+}
+
+/// Unit tests for `required_minimum_distribution`
+#[cfg(test)]
+pub mod unit_tests {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_rmd_start_age() {
+        // α <fn test_rmd_start_age>
+        assert_eq!(72, rmd_start_age(1950));
+        assert_eq!(73, rmd_start_age(1951));
+        assert_eq!(73, rmd_start_age(1959));
+        assert_eq!(75, rmd_start_age(1960));
+        // ω <fn test_rmd_start_age>
+    }
+
+    #[test]
+    fn test_uniform_lifetime_distribution_period() {
+        // α <fn test_uniform_lifetime_distribution_period>
+        assert_eq!(None, uniform_lifetime_distribution_period(69));
+        assert_eq!(Some(26.5), uniform_lifetime_distribution_period(73));
+        assert_eq!(Some(24.6), uniform_lifetime_distribution_period(75));
+        assert_eq!(Some(2.0), uniform_lifetime_distribution_period(125));
+        // ω <fn test_uniform_lifetime_distribution_period>
+    }
+
+    #[test]
+    fn test_required_minimum_distribution() {
+        // α <fn test_required_minimum_distribution>
+
+        assert_eq!(
+            100_000.0 / 26.5,
+            required_minimum_distribution(AccountType::TraditionalIra, 1955, 2028, 100_000.0)
+        );
+        assert_eq!(
+            0.0,
+            required_minimum_distribution(AccountType::TraditionalIra, 1955, 2027, 100_000.0)
+        );
+        assert_eq!(
+            0.0,
+            required_minimum_distribution(AccountType::RothIrs401K, 1955, 2028, 100_000.0)
+        );
+
+        // ω <fn test_required_minimum_distribution>
+    }
+
+    #[test]
+    fn test_project_required_distributions() {
+        // α <fn test_project_required_distributions>
+
+        let accounts = [
+            RmdAccount {
+                owner: PersonType::PrimaryOwner,
+                owner_birth_year: 1955,
+                account_type: AccountType::TraditionalIrs401K,
+                balance: 100_000.0,
+                growth: 0.0,
+            },
+            RmdAccount {
+                owner: PersonType::SecondaryOwner,
+                owner_birth_year: 1960,
+                account_type: AccountType::TraditionalIra,
+                balance: 50_000.0,
+                growth: 0.0,
+            },
+        ];
+
+        let withdrawals = project_required_distributions(
+            &accounts,
+            &YearRange {
+                start: 2027,
+                end: 2036,
+            },
+        );
+
+        // Primary starts at 73 in 2028, secondary at 75 in 2035
+        assert_eq!(2028, withdrawals[0].year);
+        assert_eq!(PersonType::PrimaryOwner, withdrawals[0].owner);
+        assert_eq!(100_000.0 / 26.5, withdrawals[0].amount);
+        assert_eq!(TaxUsCategory::UsOrdinaryIncome, withdrawals[0].tax_category);
+
+        let second_year_balance = 100_000.0 - 100_000.0 / 26.5;
+        assert_eq!(second_year_balance, withdrawals[1].prior_year_balance);
+        assert_eq!(second_year_balance / 25.5, withdrawals[1].amount);
+
+        assert_eq!(
+            50_000.0 / 24.6,
+            owner_required_distributions(&withdrawals, PersonType::SecondaryOwner, 2035)
+        );
+        assert_eq!(
+            0.0,
+            owner_required_distributions(&withdrawals, PersonType::SecondaryOwner, 2034)
+        );
+
+        // ω <fn test_project_required_distributions>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def required_minimum_distribution>
+// ω <mod-def required_minimum_distribution>
@@ -0,0 +1,532 @@
+//! Distributions of annual returns.
+//!
+//! A `NormalSpec` allows returns below -100% and has thin tails. A `ReturnDistribution`
+//! may instead be _lognormal_ - `1 + r` is lognormal, so returns are bounded by -100% -
+//! or _Student's t_ with fat tails for a given number of degrees of freedom.
+//! All variants are parameterized by the arithmetic mean and standard deviation of the
+//! return, so they can be swapped without changing the expected return or volatility.
+//! Samples are drawn by inverting the _cdf_ of a uniform draw. Simulations grow items from
+//! correlated standard normal shocks, each mapped to a return through its _cdf_, so any mix
+//! of distributions keeps the correlations of the shocks (a _Gaussian copula_).
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::NormalSpec;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Distribution of an annual return.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ReturnDistribution {
+    /// Normally distributed return.
+    Normal(NormalSpec),
+    /// Return `r` where `1 + r` is lognormal.
+    LogNormal {
+        /// Arithmetic mean of the return.
+        mean: f64,
+        /// Standard deviation of the return.
+        std_dev: f64,
+    },
+    /// Return following a scaled and shifted _Student's t_.
+    StudentT {
+        /// Arithmetic mean of the return.
+        mean: f64,
+        /// Standard deviation of the return.
+        std_dev: f64,
+        /// Degrees of freedom, greater than 2 for a finite standard deviation.
+        /// Lower is fatter tailed - 3 to 5 is typical of equity returns.
+        degrees_of_freedom: f64,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl ReturnDistribution {
+    /// Create a lognormal distribution from its geometric mean (i.e. the compound annual return).
+    ///
+    ///   * **geometric_mean** - Geometric mean of the return.
+    ///   * **std_dev** - Standard deviation of the return.
+    ///   * _return_ - The lognormal distribution.
+    pub fn lognormal_from_geometric_mean(geometric_mean: f64, std_dev: f64) -> Self {
+        // α <fn ReturnDistribution::lognormal_from_geometric_mean>
+
+        // With m = 1 + mean and g = 1 + geometric_mean: m⁴ - g²·m² - g²·std_dev² = 0
+        let g_sq = (1.0 + geometric_mean).powi(2);
+        let m_sq = 0.5 * (g_sq + (g_sq * g_sq + 4.0 * g_sq * std_dev * std_dev).sqrt());
+
+        ReturnDistribution::LogNormal {
+            mean: m_sq.sqrt() - 1.0,
+            std_dev,
+        }
+
+        // ω <fn ReturnDistribution::lognormal_from_geometric_mean>
+    }
+
+    /// Arithmetic mean of the return.
+    ///
+    ///   * _return_ - The mean.
+    pub fn mean(&self) -> f64 {
+        // α <fn ReturnDistribution::mean>
+        match self {
+            ReturnDistribution::Normal(normal_spec) => normal_spec.mean,
+            ReturnDistribution::LogNormal { mean, .. } => *mean,
+            ReturnDistribution::StudentT { mean, .. } => *mean,
+        }
+        // ω <fn ReturnDistribution::mean>
+    }
+
+    /// Standard deviation of the return.
+    ///
+    ///   * _return_ - The standard deviation.
+    pub fn std_dev(&self) -> f64 {
+        // α <fn ReturnDistribution::std_dev>
+        match self {
+            ReturnDistribution::Normal(normal_spec) => normal_spec.std_dev,
+            ReturnDistribution::LogNormal { std_dev, .. } => *std_dev,
+            ReturnDistribution::StudentT { std_dev, .. } => *std_dev,
+        }
+        // ω <fn ReturnDistribution::std_dev>
+    }
+
+    /// Geometric mean of the return (i.e. the compound annual return).
+    /// Exact for the lognormal, the usual `mean - std_dev²/2` approximation otherwise.
+    ///
+    ///   * _return_ - The geometric mean.
+    pub fn geometric_mean(&self) -> f64 {
+        // α <fn ReturnDistribution::geometric_mean>
+        match self.log_normal_parameters() {
+            Some((mu, _)) => mu.exp() - 1.0,
+            None => self.normal_spec().geometric_mean(),
+        }
+        // ω <fn ReturnDistribution::geometric_mean>
+    }
+
+    /// The normal with the same mean and standard deviation.
+    ///
+    ///   * _return_ - The normal.
+    #[inline]
+    pub fn normal_spec(&self) -> NormalSpec {
+        // α <fn ReturnDistribution::normal_spec>
+        NormalSpec {
+            mean: self.mean(),
+            std_dev: self.std_dev(),
+        }
+        // ω <fn ReturnDistribution::normal_spec>
+    }
+
+    /// Probability density at `x`.
+    ///
+    ///   * **x** - Return to evaluate at.
+    ///   * _return_ - The density, `None` if the parameters are invalid.
+    pub fn pdf(&self, x: f64) -> Option<f64> {
+        // α <fn ReturnDistribution::pdf>
+
+        use plus_utils::student_t_pdf;
+        use std::f64::consts::PI;
+
+        let normal_pdf = |z: f64| (-0.5 * z * z).exp() / (2.0 * PI).sqrt();
+
+        if !self.is_valid() {
+            return None;
+        }
+
+        Some(match self {
+            ReturnDistribution::Normal(normal_spec) => {
+                normal_pdf((x - normal_spec.mean) / normal_spec.std_dev) / normal_spec.std_dev
+            }
+            ReturnDistribution::LogNormal { .. } => {
+                let (mu, sigma) = self.log_normal_parameters().expect("lognormal");
+                if x <= -1.0 {
+                    0.0
+                } else {
+                    normal_pdf(((1.0 + x).ln() - mu) / sigma) / (sigma * (1.0 + x))
+                }
+            }
+            ReturnDistribution::StudentT {
+                mean,
+                degrees_of_freedom,
+                ..
+            } => {
+                let scale = self.student_t_scale().expect("student t");
+                student_t_pdf((x - mean) / scale, *degrees_of_freedom) / scale
+            }
+        })
+
+        // ω <fn ReturnDistribution::pdf>
+    }
+
+    /// Cumulative probability at `x`.
+    ///
+    ///   * **x** - Return to evaluate at.
+    ///   * _return_ - Probability the return is at most `x`, `None` if the parameters are invalid.
+    pub fn cdf(&self, x: f64) -> Option<f64> {
+        // α <fn ReturnDistribution::cdf>
+
+        use plus_utils::normal_cdf;
+        use plus_utils::student_t_cdf;
+
+        if !self.is_valid() {
+            return None;
+        }
+
+        match self {
+            ReturnDistribution::Normal(normal_spec) => {
+                normal_cdf(x, normal_spec.mean, normal_spec.std_dev)
+            }
+            ReturnDistribution::LogNormal { .. } => {
+                let (mu, sigma) = self.log_normal_parameters().expect("lognormal");
+                if x <= -1.0 {
+                    Some(0.0)
+                } else {
+                    normal_cdf((1.0 + x).ln(), mu, sigma)
+                }
+            }
+            ReturnDistribution::StudentT {
+                mean,
+                degrees_of_freedom,
+                ..
+            } => {
+                let scale = self.student_t_scale().expect("student t");
+                Some(student_t_cdf((x - mean) / scale, *degrees_of_freedom))
+            }
+        }
+
+        // ω <fn ReturnDistribution::cdf>
+    }
+
+    /// Return at cumulative probability `p` (inverse _cdf_).
+    ///
+    ///   * **p** - Probability in (0, 1).
+    ///   * _return_ - The return, `None` if `p` or the parameters are invalid.
+    pub fn quantile(&self, p: f64) -> Option<f64> {
+        // α <fn ReturnDistribution::quantile>
+
+        use plus_utils::normal_quantile;
+        use plus_utils::standard_normal_inverse_cdf;
+        use plus_utils::student_t_inverse_cdf;
+
+        if !self.is_valid() {
+            return None;
+        }
+
+        match self {
+            ReturnDistribution::Normal(normal_spec) => {
+                normal_quantile(p, normal_spec.mean, normal_spec.std_dev)
+            }
+            ReturnDistribution::LogNormal { .. } => {
+                let (mu, sigma) = self.log_normal_parameters().expect("lognormal");
+                standard_normal_inverse_cdf(p).map(|z| (mu + sigma * z).exp() - 1.0)
+            }
+            ReturnDistribution::StudentT {
+                mean,
+                degrees_of_freedom,
+                ..
+            } => {
+                let scale = self.student_t_scale().expect("student t");
+                student_t_inverse_cdf(p, *degrees_of_freedom).map(|t| mean + scale * t)
+            }
+        }
+
+        // ω <fn ReturnDistribution::quantile>
+    }
+
+    /// Sample a return by inverting the _cdf_ at a uniform draw.
+    ///
+    ///   * **uniform** - Draw from the uniform distribution on [0, 1).
+    ///   * _return_ - The sampled return, `None` if the parameters are invalid.
+    pub fn sample(&self, uniform: f64) -> Option<f64> {
+        // α <fn ReturnDistribution::sample>
+        // Keep the draw strictly inside (0, 1) where the quantile is finite
+        self.quantile(uniform.clamp(f64::EPSILON, 1.0 - f64::EPSILON))
+        // ω <fn ReturnDistribution::sample>
+    }
+
+    /// Sample a return driven by a standard normal shock, e.g. one of
+    /// `CorrelatedShockSampler::sample`. Higher shocks give higher returns and a
+    /// `Normal` return is `mean + std_dev·shock`.
+    ///
+    ///   * **shock** - Draw from the standard normal.
+    ///   * _return_ - The sampled return, `None` if the parameters are invalid.
+    pub fn sample_shock(&self, shock: f64) -> Option<f64> {
+        // α <fn ReturnDistribution::sample_shock>
+        use plus_utils::standard_normal_cdf;
+        self.sample(standard_normal_cdf(shock))
+        // ω <fn ReturnDistribution::sample_shock>
+    }
+
+    /// True if the parameters define a distribution.
+    ///
+    ///   * _return_ - True if standard deviation is positive and degrees of freedom exceed 2.
+    pub fn is_valid(&self) -> bool {
+        // α <fn ReturnDistribution::is_valid>
+        self.std_dev() > 0.0
+            && match self {
+                ReturnDistribution::Normal(_) => true,
+                ReturnDistribution::LogNormal { mean, .. } => *mean > -1.0,
+                ReturnDistribution::StudentT {
+                    degrees_of_freedom, ..
+                } => *degrees_of_freedom > 2.0,
+            }
+        // ω <fn ReturnDistribution::is_valid>
+    }
+
+    /// Mean and standard deviation of `ln(1 + r)` for the lognormal.
+    ///
+    ///   * _return_ - The `(μ, σ)` of the underlying normal, `None` if not lognormal.
+    fn log_normal_parameters(&self) -> Option<(f64, f64)> {
+        // α <fn ReturnDistribution::log_normal_parameters>
+        match self {
+            ReturnDistribution::LogNormal { mean, std_dev } => {
+                let sigma_sq = (1.0 + (std_dev / (1.0 + mean)).powi(2)).ln();
+                Some(((1.0 + mean).ln() - 0.5 * sigma_sq, sigma_sq.sqrt()))
+            }
+            _ => None,
+        }
+        // ω <fn ReturnDistribution::log_normal_parameters>
+    }
+
+    /// Scale of the _Student's t_ giving the specified standard deviation.
+    ///
+    ///   * _return_ - The scale, `None` if not _Student's t_.
+    fn student_t_scale(&self) -> Option<f64> {
+        // α <fn ReturnDistribution::student_t_scale>
+        match self {
+            ReturnDistribution::StudentT {
+                std_dev,
+                degrees_of_freedom,
+                ..
+            } => Some(std_dev * ((degrees_of_freedom - 2.0) / degrees_of_freedom).sqrt()),
+            _ => None,
+        }
+        // ω <fn ReturnDistribution::student_t_scale>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl From<NormalSpec> for ReturnDistribution {
+    /// Create a normal return distribution.
+    ///
+    ///   * **normal_spec** - The normal.
+    ///   * _return_ - The distribution.
+    fn from(normal_spec: NormalSpec) -> Self {
+        // α <fn From::from for ReturnDistribution>
+        ReturnDistribution::Normal(normal_spec)
+        // ω <fn From::from for ReturnDistribution>
+    }
+}
+
+impl Display for ReturnDistribution {
+    /// Format the instance.
+    ///
+    ///   * **f** - Formatter to push formatted item to.
+    ///   * _return_ - Formatted instance
+    fn fmt(&self, #[allow(unused)] f: &mut Formatter<'_>) -> ::core::fmt::Result {
+        // α <fn Display::fmt for ReturnDistribution>
+        use crate::SystemUnicodes;
+        use plus_utils::scale_by;
+        use plus_utils::with_max_precision;
+
+        let precision = 2usize;
+        let parameters = format!(
+            "{}={}%,{}={}%",
+            SystemUnicodes::MathMu.as_unicode(),
+            with_max_precision(scale_by(self.mean(), 2), precision),
+            SystemUnicodes::MathSigma.as_unicode(),
+            with_max_precision(scale_by(self.std_dev(), 2), precision)
+        );
+
+        match self {
+            ReturnDistribution::Normal(normal_spec) => write!(f, "{normal_spec}"),
+            ReturnDistribution::LogNormal { .. } => write!(f, "ln𝑁({parameters})"),
+            ReturnDistribution::StudentT {
+                degrees_of_freedom, ..
+            } => write!(f, "𝑡(ν={degrees_of_freedom},{parameters})"),
+        }
+        // ω <fn Display::fmt for ReturnDistribution>
+    }
+}
+
+/// Unit tests for `return_distribution`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type ReturnDistribution
+    mod test_return_distribution {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn lognormal_from_geometric_mean() {
+            // α <fn test ReturnDistribution::lognormal_from_geometric_mean>
+
+            let lognormal = ReturnDistribution::lognormal_from_geometric_mean(0.06, 0.18);
+            assert!((lognormal.geometric_mean() - 0.06).abs() < 1e-12);
+            assert!(lognormal.mean() > 0.06);
+            assert_eq!(0.18, lognormal.std_dev());
+
+            // Mean return of 10% compounds to nothing when std dev is sqrt(1.1⁴ - 1.1²)
+            let lognormal = ReturnDistribution::LogNormal {
+                mean: 0.1,
+                std_dev: (1.1f64.powi(4) - 1.1f64.powi(2)).sqrt(),
+            };
+            assert!(lognormal.geometric_mean().abs() < 1e-12);
+
+            // ω <fn test ReturnDistribution::lognormal_from_geometric_mean>
+        }
+
+        #[test]
+        fn cdf() {
+            // α <fn test ReturnDistribution::cdf>
+
+            let normal = ReturnDistribution::from(NormalSpec {
+                mean: 0.07,
+                std_dev: 0.15,
+            });
+            assert_eq!(Some(0.5), normal.cdf(0.07));
+            // A normal puts mass on losing more than everything
+            assert!(normal.cdf(-1.0).unwrap() > 0.0);
+
+            let lognormal = ReturnDistribution::LogNormal {
+                mean: 0.07,
+                std_dev: 0.15,
+            };
+            assert_eq!(Some(0.0), lognormal.cdf(-1.0));
+            // Median below the mean
+            assert!(lognormal.cdf(0.07).unwrap() > 0.5);
+
+            let student_t = ReturnDistribution::StudentT {
+                mean: 0.07,
+                std_dev: 0.15,
+                degrees_of_freedom: 4.0,
+            };
+            assert_eq!(Some(0.5), student_t.cdf(0.07));
+            // Fatter tail than the normal 4 std devs down
+            assert!(student_t.cdf(-0.53).unwrap() > normal.cdf(-0.53).unwrap());
+
+            assert_eq!(
+                None,
+                ReturnDistribution::StudentT {
+                    mean: 0.07,
+                    std_dev: 0.15,
+                    degrees_of_freedom: 2.0,
+                }
+                .cdf(0.0)
+            );
+
+            // ω <fn test ReturnDistribution::cdf>
+        }
+
+        #[test]
+        fn quantile() {
+            // α <fn test ReturnDistribution::quantile>
+
+            for distribution in [
+                ReturnDistribution::from(NormalSpec {
+                    mean: 0.07,
+                    std_dev: 0.15,
+                }),
+                ReturnDistribution::LogNormal {
+                    mean: 0.07,
+                    std_dev: 0.15,
+                },
+                ReturnDistribution::StudentT {
+                    mean: 0.07,
+                    std_dev: 0.15,
+                    degrees_of_freedom: 5.0,
+                },
+            ] {
+                for p in [0.01, 0.05, 0.5, 0.95] {
+                    let x = distribution.quantile(p).unwrap();
+                    assert!((distribution.cdf(x).unwrap() - p).abs() < 1e-10);
+                }
+                assert_eq!(None, distribution.quantile(1.0));
+                assert!(distribution.sample(0.0).unwrap().is_finite());
+            }
+
+            // ω <fn test ReturnDistribution::quantile>
+        }
+
+        #[test]
+        fn sample_shock() {
+            // α <fn test ReturnDistribution::sample_shock>
+
+            let normal = ReturnDistribution::from(NormalSpec {
+                mean: 0.07,
+                std_dev: 0.15,
+            });
+            for shock in [-2.0, 0.0, 1.5] {
+                assert!((normal.sample_shock(shock).unwrap() - (0.07 + 0.15 * shock)).abs() < 1e-9);
+            }
+
+            // A lognormal return stays above -100% however bad the shock
+            let lognormal = ReturnDistribution::LogNormal {
+                mean: 0.07,
+                std_dev: 0.15,
+            };
+            assert!(lognormal.sample_shock(-10.0).unwrap() > -1.0);
+            assert!(lognormal.sample_shock(1.0).unwrap() > lognormal.sample_shock(0.0).unwrap());
+            assert_eq!(
+                None,
+                ReturnDistribution::LogNormal {
+                    mean: 0.07,
+                    std_dev: 0.0,
+                }
+                .sample_shock(0.0)
+            );
+
+            // ω <fn test ReturnDistribution::sample_shock>
+        }
+
+        #[test]
+        fn pdf() {
+            // α <fn test ReturnDistribution::pdf>
+
+            // Density integrates to the cdf
+            for distribution in [
+                ReturnDistribution::LogNormal {
+                    mean: 0.07,
+                    std_dev: 0.15,
+                },
+                ReturnDistribution::StudentT {
+                    mean: 0.07,
+                    std_dev: 0.15,
+                    degrees_of_freedom: 5.0,
+                },
+            ] {
+                let steps = 2_000;
+                let (low, high) = (-0.2, 0.3);
+                let width = (high - low) / steps as f64;
+                let integral = (0..steps)
+                    .map(|i| distribution.pdf(low + (i as f64 + 0.5) * width).unwrap() * width)
+                    .sum::<f64>();
+                let expected = distribution.cdf(high).unwrap() - distribution.cdf(low).unwrap();
+                assert!((integral - expected).abs() < 1e-6);
+            }
+
+            // ω <fn test ReturnDistribution::pdf>
+        }
+
+        // α <mod-def test_return_distribution>
+        use super::*;
+        // ω <mod-def test_return_distribution>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def return_distribution>
+// ω <mod-def return_distribution>
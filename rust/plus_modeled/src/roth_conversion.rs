@@ -0,0 +1,425 @@
+//! Plans annual conversions of tax deferred balances into a `RothIrs401K` account.
+//!
+//! Each year of the conversion range an amount is moved from the traditional account
+//! into the Roth account, either enough to fill a chosen federal bracket or a fixed
+//! target. The converted amount is taxed as ordinary income and the tax is paid from
+//! the taxable account. The household is then forecast with and without the
+//! conversions so lifetime taxes and final after-tax wealth can be compared.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::required_minimum_distribution::required_minimum_distribution;
+use crate::tax_brackets::{marginal_rate, room_in_bracket, tax_on_income};
+use crate::AccountType;
+use crate::TaxUsFilingStatus;
+use crate::YearRange;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// How much to convert in each conversion year.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RothConversionTarget {
+    /// Convert enough to fill the federal bracket with the given marginal rate.
+    FillBracket(f64),
+    /// Convert a fixed amount each year.
+    Amount(f64),
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Inputs to plan and evaluate Roth conversions for a household.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RothConversionPlanner {
+    /// Tax deferred account converted from, `TraditionalIrs401K` or `TraditionalIra`.
+    pub source_account_type: AccountType,
+    /// Year of birth of the account owner, which determines RMDs.
+    pub owner_birth_year: u32,
+    /// Filing status whose brackets apply to the conversion years.
+    pub filing_status: TaxUsFilingStatus,
+    /// Balance of the tax deferred account before the first forecast year.
+    pub traditional_balance: f64,
+    /// Balance of the Roth account before the first forecast year.
+    pub roth_balance: f64,
+    /// Balance of the taxable account paying conversion taxes before the first forecast year.
+    pub taxable_balance: f64,
+    /// Other ordinary income in the first forecast year, grown with `inflation`.
+    pub other_ordinary_income: f64,
+    /// Annual growth of all account balances.
+    pub growth: f64,
+    /// Annual inflation used to index brackets and other income.
+    pub inflation: f64,
+    /// Rate at which the tax deferred balance left at the end is taxed on liquidation.
+    pub liquidation_rate: f64,
+    /// Years in which conversions are made.
+    pub conversion_years: YearRange,
+    /// How much to convert each conversion year, see `validated` for the allowed targets.
+    pub target: RothConversionTarget,
+    /// Years of the forecast.
+    pub forecast_years: YearRange,
+}
+
+/// Results of a single forecast year.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RothConversionYear {
+    /// Year of the forecast.
+    pub year: u32,
+    /// Amount converted into the Roth account.
+    pub conversion: f64,
+    /// Required minimum distribution taken from the tax deferred account.
+    pub required_distribution: f64,
+    /// Federal taxable ordinary income.
+    pub taxable_income: f64,
+    /// Federal tax on ordinary income.
+    pub tax: f64,
+    /// Marginal rate on the last dollar of taxable income.
+    pub marginal_rate: f64,
+    /// Tax deferred balance at year end.
+    pub traditional_balance: f64,
+    /// Roth balance at year end.
+    pub roth_balance: f64,
+    /// Taxable balance at year end.
+    pub taxable_balance: f64,
+}
+
+/// Results of a forecast.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RothConversionOutcome {
+    /// Results by forecast year.
+    pub years: Vec<RothConversionYear>,
+    /// Sum of federal taxes over the forecast.
+    pub lifetime_taxes: f64,
+    /// Final balances with the tax deferred balance reduced by the `liquidation_rate`.
+    pub final_after_tax_wealth: f64,
+}
+
+/// Forecasts of the same household with and without conversions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RothConversionComparison {
+    /// Forecast making the planned conversions.
+    pub with_conversions: RothConversionOutcome,
+    /// Forecast making no conversions.
+    pub without_conversions: RothConversionOutcome,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl RothConversionPlanner {
+    /// Account type conversions are made into.
+    pub const TARGET_ACCOUNT_TYPE: AccountType = AccountType::RothIrs401K;
+
+    /// Check the planner before forecasting. A `FillBracket` target must name the rate of
+    /// one of the federal ordinary income brackets of `filing_status`, otherwise there is
+    /// no bracket to fill and no conversions would be proposed.
+    ///
+    ///   * _return_ - The planner, `None` if the target names no bracket.
+    pub fn validated(self) -> Option<RothConversionPlanner> {
+        // α <fn RothConversionPlanner::validated>
+
+        match self.target {
+            RothConversionTarget::FillBracket(rate) => self
+                .filing_status
+                .ordinary_income_brackets(self.forecast_years.start, self.inflation)
+                .iter()
+                .any(|bracket| bracket.rate == rate)
+                .then_some(self),
+            RothConversionTarget::Amount(_) => Some(self),
+        }
+
+        // ω <fn RothConversionPlanner::validated>
+    }
+
+    /// Forecast with and without conversions.
+    ///
+    ///   * _return_ - The comparison of the two forecasts.
+    pub fn compare(&self) -> RothConversionComparison {
+        // α <fn RothConversionPlanner::compare>
+
+        RothConversionComparison {
+            with_conversions: self.forecast(true),
+            without_conversions: self.forecast(false),
+        }
+
+        // ω <fn RothConversionPlanner::compare>
+    }
+
+    /// Proposed conversion for each conversion year.
+    ///
+    ///   * _return_ - Pairs of year and amount converted, omitting years with no conversion.
+    pub fn proposed_conversions(&self) -> Vec<(u32, f64)> {
+        // α <fn RothConversionPlanner::proposed_conversions>
+
+        self.forecast(true)
+            .years
+            .iter()
+            .filter(|year| year.conversion > 0.0)
+            .map(|year| (year.year, year.conversion))
+            .collect()
+
+        // ω <fn RothConversionPlanner::proposed_conversions>
+    }
+
+    /// Forecast balances and taxes over `forecast_years`.
+    ///
+    /// Each year the RMD is taken from the prior year end balance, the conversion is
+    /// sized, taxes attributable to the RMD and the conversion are paid from the taxable
+    /// account (which also receives the RMD), and then all balances grow.
+    /// Tax on `other_ordinary_income` is assumed paid from that income.
+    /// A conversion is reduced when the taxable account cannot cover its tax.
+    ///
+    ///   * **with_conversions** - If set conversions are made in the conversion years.
+    ///   * _return_ - The forecast outcome.
+    pub fn forecast(&self, with_conversions: bool) -> RothConversionOutcome {
+        // α <fn RothConversionPlanner::forecast>
+
+        let mut traditional = self.traditional_balance;
+        let mut roth = self.roth_balance;
+        let mut taxable = self.taxable_balance;
+        let mut years = Vec::new();
+
+        for year in self.forecast_years.start..self.forecast_years.end {
+            let brackets = self
+                .filing_status
+                .ordinary_income_brackets(year, self.inflation);
+            let deduction = self.filing_status.standard_deduction(year, self.inflation);
+            let other_income = self.other_ordinary_income
+                * (1.0 + self.inflation).powi((year - self.forecast_years.start) as i32);
+            let taxable_income_of = |gross: f64| (gross - deduction).max(0.0);
+            let other_tax = tax_on_income(taxable_income_of(other_income), &brackets);
+
+            let required_distribution = required_minimum_distribution(
+                self.source_account_type,
+                self.owner_birth_year,
+                year,
+                traditional,
+            );
+            traditional -= required_distribution;
+            taxable += required_distribution;
+            let base_gross = other_income + required_distribution;
+            let base_tax = tax_on_income(taxable_income_of(base_gross), &brackets);
+
+            let mut conversion = if with_conversions
+                && year >= self.conversion_years.start
+                && year < self.conversion_years.end
+            {
+                match self.target {
+                    RothConversionTarget::FillBracket(rate) => {
+                        room_in_bracket(taxable_income_of(base_gross), rate, &brackets)
+                            + (deduction - base_gross).max(0.0)
+                    }
+                    RothConversionTarget::Amount(amount) => amount,
+                }
+                .min(traditional)
+                .max(0.0)
+            } else {
+                0.0
+            };
+
+            let mut tax = tax_on_income(taxable_income_of(base_gross + conversion), &brackets);
+            let available = (taxable - (base_tax - other_tax)).max(0.0);
+            let conversion_tax = tax - base_tax;
+            if conversion_tax > available {
+                // Tax is convex in income so scaling keeps the conversion tax within reach
+                conversion *= available / conversion_tax;
+                tax = tax_on_income(taxable_income_of(base_gross + conversion), &brackets);
+            }
+
+            traditional -= conversion;
+            roth += conversion;
+            taxable -= tax - other_tax;
+
+            let growth = 1.0 + self.growth;
+            traditional *= growth;
+            roth *= growth;
+            taxable *= growth;
+
+            let taxable_income = taxable_income_of(base_gross + conversion);
+            years.push(RothConversionYear {
+                year,
+                conversion,
+                required_distribution,
+                taxable_income,
+                tax,
+                marginal_rate: marginal_rate(taxable_income, &brackets),
+                traditional_balance: traditional,
+                roth_balance: roth,
+                taxable_balance: taxable,
+            });
+        }
+
+        RothConversionOutcome {
+            lifetime_taxes: years.iter().map(|year| year.tax).sum(),
+            final_after_tax_wealth: roth + taxable + traditional * (1.0 - self.liquidation_rate),
+            years,
+        }
+
+        // ω <fn RothConversionPlanner::forecast>
+    }
+}
+
+impl RothConversionComparison {
+    /// Change in lifetime taxes due to the conversions.
+    ///
+    ///   * _return_ - Lifetime taxes with conversions less those without.
+    pub fn lifetime_tax_change(&self) -> f64 {
+        // α <fn RothConversionComparison::lifetime_tax_change>
+        self.with_conversions.lifetime_taxes - self.without_conversions.lifetime_taxes
+        // ω <fn RothConversionComparison::lifetime_tax_change>
+    }
+
+    /// Change in final after-tax wealth due to the conversions.
+    ///
+    ///   * _return_ - Final after-tax wealth with conversions less that without.
+    pub fn after_tax_wealth_change(&self) -> f64 {
+        // α <fn RothConversionComparison::after_tax_wealth_change>
+        self.with_conversions.final_after_tax_wealth
+            - self.without_conversions.final_after_tax_wealth
+        // ω <fn RothConversionComparison::after_tax_wealth_change>
+    }
+}
+
+/// Unit tests for `roth_conversion`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type RothConversionPlanner
+    mod test_roth_conversion_planner {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn forecast() {
+            // α <fn test RothConversionPlanner::forecast>
+
+            let planner = sample_planner(RothConversionTarget::FillBracket(0.12))
+                .validated()
+                .unwrap();
+            let outcome = planner.forecast(true);
+
+            // 2024: deduction covers 14_600 of the conversion, then fill the 12% bracket
+            let first = outcome.years[0];
+            assert_eq!(14_600.0 + 47_150.0, first.conversion);
+            assert_eq!(47_150.0, first.taxable_income);
+            assert_eq!(0.12, first.marginal_rate);
+            assert!((first.tax - (1_160.0 + 0.12 * 35_550.0)).abs() < 1e-9);
+            assert_eq!(0.0, outcome.years[5].conversion);
+
+            let amount = sample_planner(RothConversionTarget::Amount(10_000.0)).forecast(true);
+            assert_eq!(10_000.0, amount.years[0].conversion);
+            assert_eq!(0.0, amount.years[0].tax);
+
+            let none = planner.forecast(false);
+            assert!(none.years.iter().all(|year| year.conversion == 0.0));
+            assert!(none
+                .years
+                .iter()
+                .any(|year| year.required_distribution > 0.0));
+
+            // ω <fn test RothConversionPlanner::forecast>
+        }
+
+        #[test]
+        fn validated() {
+            // α <fn test RothConversionPlanner::validated>
+
+            let planner = sample_planner(RothConversionTarget::FillBracket(0.12));
+            assert_eq!(Some(planner.clone()), planner.validated());
+            assert!(sample_planner(RothConversionTarget::Amount(10_000.0))
+                .validated()
+                .is_some());
+
+            // A rate of no bracket has no room to fill
+            assert_eq!(
+                None,
+                sample_planner(RothConversionTarget::FillBracket(0.15)).validated()
+            );
+
+            // ω <fn test RothConversionPlanner::validated>
+        }
+
+        #[test]
+        fn compare() {
+            // α <fn test RothConversionPlanner::compare>
+
+            let comparison = sample_planner(RothConversionTarget::FillBracket(0.12)).compare();
+
+            // Conversions prepay taxes at low rates, shrinking later RMDs and liquidation tax
+            assert!(comparison.after_tax_wealth_change() > 0.0);
+            assert!(
+                comparison
+                    .with_conversions
+                    .years
+                    .last()
+                    .unwrap()
+                    .traditional_balance
+                    < comparison
+                        .without_conversions
+                        .years
+                        .last()
+                        .unwrap()
+                        .traditional_balance
+            );
+
+            // ω <fn test RothConversionPlanner::compare>
+        }
+
+        #[test]
+        fn proposed_conversions() {
+            // α <fn test RothConversionPlanner::proposed_conversions>
+
+            let mut planner = sample_planner(RothConversionTarget::Amount(50_000.0));
+            planner.taxable_balance = 0.0;
+            assert!(planner.proposed_conversions().is_empty());
+
+            planner.taxable_balance = 1_000_000.0;
+            let conversions = planner.proposed_conversions();
+            assert_eq!(5, conversions.len());
+            assert_eq!((2024, 50_000.0), conversions[0]);
+
+            // ω <fn test RothConversionPlanner::proposed_conversions>
+        }
+
+        fn sample_planner(target: RothConversionTarget) -> RothConversionPlanner {
+            RothConversionPlanner {
+                source_account_type: AccountType::TraditionalIra,
+                owner_birth_year: 1959,
+                filing_status: TaxUsFilingStatus::Single,
+                traditional_balance: 1_000_000.0,
+                roth_balance: 0.0,
+                taxable_balance: 200_000.0,
+                other_ordinary_income: 0.0,
+                growth: 0.05,
+                inflation: 0.0,
+                liquidation_rate: 0.32,
+                conversion_years: YearRange {
+                    start: 2024,
+                    end: 2029,
+                },
+                target,
+                forecast_years: YearRange {
+                    start: 2024,
+                    end: 2054,
+                },
+            }
+        }
+
+        // α <mod-def test_roth_conversion_planner>
+        use super::*;
+        // ω <mod-def test_roth_conversion_planner>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def roth_conversion>
+// ω <mod-def roth_conversion>
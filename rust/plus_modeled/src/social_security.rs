@@ -0,0 +1,807 @@
+//! Estimates US Social Security retirement benefits from an earnings history.
+//!
+//! The estimate follows the _Social Security Administration_ formulas:
+//!
+//!  - Earnings before the year the earner turns 60 are wage indexed to that year.
+//!  - The top 35 years of indexed earnings are averaged monthly to get the _AIME_.
+//!  - The _PIA_ applies the 90%/32%/15% factors over the bend points of the
+//!    eligibility year (the year the earner turns 62).
+//!  - The benefit is the _PIA_ reduced for claiming before full retirement age or
+//!    increased by delayed retirement credits for claiming after.
+//!
+//! Spousal and survivor benefits are supported for a `PrimaryOwner` and
+//! `SecondaryOwner` pair. See [SSA benefit formulas](https://www.ssa.gov/oact/cola/piaformula.html).
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::Currency;
+use crate::FlowType;
+use crate::PersonType;
+use crate::YearCurrencyValue;
+use crate::YearValueSeries;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Number of top earning years averaged into the AIME.
+pub const SS_COMPUTATION_YEARS: usize = 35;
+/// Earliest claiming age for retirement benefits, in months.
+pub const SS_EARLIEST_CLAIM_AGE_MONTHS: u32 = 62 * 12;
+/// Earliest claiming age for survivor benefits, in months.
+pub const SS_EARLIEST_SURVIVOR_CLAIM_AGE_MONTHS: u32 = 60 * 12;
+/// Age after which no further delayed retirement credits accrue, in months.
+pub const SS_MAX_CREDIT_AGE_MONTHS: u32 = 70 * 12;
+/// The AWI for 1977, which anchors the bend point formula.
+pub const SS_AWI_1977: f64 = 9_779.44;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Assumptions used to project benefits.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocialSecurityParameters {
+    /// Annual growth of the national average wage index, used for years not in `average_wage_index`.
+    pub wage_growth: f64,
+    /// Annual cost of living adjustment applied from the eligibility year on.
+    pub cola: f64,
+    /// Known values of the national average wage index (AWI) by year. May be empty.
+    pub average_wage_index: YearValueSeries,
+    /// Year for which `bend_points` are known.
+    pub bend_points_year: u32,
+    /// First and second PIA bend points for `bend_points_year`.
+    pub bend_points: (f64, f64),
+}
+
+/// A person with an earnings history covered by Social Security.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocialSecurityEarner {
+    /// Role of the earner in the dossier.
+    pub person_type: PersonType,
+    /// Year of birth.
+    pub birth_year: u32,
+    /// Covered (i.e. capped at the taxable maximum) earnings by year.
+    pub earnings: YearValueSeries,
+}
+
+/// An earner with the claiming decision and assumed age of death.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocialSecurityClaim {
+    /// The earner claiming benefits.
+    pub earner: SocialSecurityEarner,
+    /// Age, in months, benefits are claimed.
+    pub claim_age_months: u32,
+    /// Age, in years, of assumed death.
+    pub death_age: u32,
+}
+
+/// The estimated benefit of one person as a generated `SocialSecurityIncome` flow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocialSecurityEstimate {
+    /// Person receiving the benefit.
+    pub person_type: PersonType,
+    /// Primary insurance amount in eligibility year dollars.
+    pub pia: f64,
+    /// Monthly retirement benefit on own record in eligibility year dollars.
+    pub monthly_benefit: f64,
+    /// First year benefits are received.
+    pub claim_year: u32,
+    /// Annual benefits received, including spousal and survivor amounts.
+    pub flow: Vec<YearCurrencyValue>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Full retirement age, in months, for the given year of birth.
+///
+///   * **birth_year** - Year of birth.
+///   * _return_ - Full retirement age in months.
+pub fn full_retirement_age_months(birth_year: u32) -> u32 {
+    // α <fn full_retirement_age_months>
+
+    match birth_year {
+        year if year <= 1937 => 65 * 12,
+        1938..=1942 => 65 * 12 + (birth_year - 1937) * 2,
+        1943..=1954 => 66 * 12,
+        1955..=1959 => 66 * 12 + (birth_year - 1954) * 2,
+        _ => 67 * 12,
+    }
+
+    // ω <fn full_retirement_age_months>
+}
+
+/// Factor applied to the _PIA_ for claiming retirement benefits at `claim_age_months`.
+/// Claiming early reduces the benefit 5/9 of 1% per month for the first 36 months and 5/12
+/// of 1% for each additional month. Claiming late adds 2/3 of 1% per month up to age 70.
+///
+///   * **birth_year** - Year of birth of the claimant.
+///   * **claim_age_months** - Age benefits are claimed in months.
+///   * _return_ - Adjustment factor for the PIA.
+pub fn retirement_claim_factor(birth_year: u32, claim_age_months: u32) -> f64 {
+    // α <fn retirement_claim_factor>
+
+    let fra = full_retirement_age_months(birth_year);
+    let claim_age_months =
+        claim_age_months.clamp(SS_EARLIEST_CLAIM_AGE_MONTHS, SS_MAX_CREDIT_AGE_MONTHS);
+
+    if claim_age_months < fra {
+        let months_early = (fra - claim_age_months) as f64;
+        1.0 - months_early.min(36.0) * 5.0 / 900.0 - (months_early - 36.0).max(0.0) * 5.0 / 1200.0
+    } else {
+        1.0 + (claim_age_months - fra) as f64 * 2.0 / 300.0
+    }
+
+    // ω <fn retirement_claim_factor>
+}
+
+/// Factor applied to the spousal benefit for claiming at `claim_age_months`.
+/// Claiming early reduces the benefit 25/36 of 1% per month for the first 36 months and 5/12
+/// of 1% for each additional month. There are no credits for delaying.
+///
+///   * **birth_year** - Year of birth of the spouse claiming.
+///   * **claim_age_months** - Age the spousal benefit is claimed in months.
+///   * _return_ - Adjustment factor for the spousal benefit.
+pub fn spousal_claim_factor(birth_year: u32, claim_age_months: u32) -> f64 {
+    // α <fn spousal_claim_factor>
+
+    let fra = full_retirement_age_months(birth_year);
+    let claim_age_months = claim_age_months.max(SS_EARLIEST_CLAIM_AGE_MONTHS);
+
+    if claim_age_months < fra {
+        let months_early = (fra - claim_age_months) as f64;
+        1.0 - months_early.min(36.0) * 25.0 / 3600.0 - (months_early - 36.0).max(0.0) * 5.0 / 1200.0
+    } else {
+        1.0
+    }
+
+    // ω <fn spousal_claim_factor>
+}
+
+/// Factor applied to the survivor benefit for claiming at `claim_age_months`.
+/// The reduction is prorated from 28.5% at age 60 to none at full retirement age.
+///
+///   * **birth_year** - Year of birth of the survivor.
+///   * **claim_age_months** - Age the survivor benefit is claimed in months.
+///   * _return_ - Adjustment factor for the survivor benefit.
+pub fn survivor_claim_factor(birth_year: u32, claim_age_months: u32) -> f64 {
+    // α <fn survivor_claim_factor>
+
+    let fra = full_retirement_age_months(birth_year);
+    let claim_age_months = claim_age_months.max(SS_EARLIEST_SURVIVOR_CLAIM_AGE_MONTHS);
+
+    if claim_age_months < fra {
+        let months_early = (fra - claim_age_months) as f64;
+        let reduction_months = (fra - SS_EARLIEST_SURVIVOR_CLAIM_AGE_MONTHS) as f64;
+        1.0 - 0.285 * months_early / reduction_months
+    } else {
+        1.0
+    }
+
+    // ω <fn survivor_claim_factor>
+}
+
+/// Estimate benefits for a household of one or two earners.
+/// Each person receives their own retirement benefit, topped up by a spousal benefit once
+/// both have claimed, and switches to the survivor benefit when it is larger after the
+/// death of the other.
+///
+///   * **parameters** - Assumptions used to project benefits.
+///   * **primary** - Claim of the `PrimaryOwner`.
+///   * **secondary** - Claim of the `SecondaryOwner`, if any.
+///   * _return_ - One estimate per person, primary first.
+pub fn estimate_household(
+    parameters: &SocialSecurityParameters,
+    primary: &SocialSecurityClaim,
+    secondary: Option<&SocialSecurityClaim>,
+) -> Vec<SocialSecurityEstimate> {
+    // α <fn estimate_household>
+
+    let mut estimates = vec![primary.estimate(parameters, secondary)];
+    if let Some(secondary) = secondary {
+        estimates.push(secondary.estimate(parameters, Some(primary)));
+    }
+    estimates
+
+    // ω <fn estimate_household>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl SocialSecurityParameters {
+    /// Get the average wage index for `year`, projecting from the nearest known value
+    /// with `wage_growth` when `year` is not in the series.
+    ///
+    ///   * **year** - Year of the index.
+    ///   * _return_ - The (possibly projected) AWI, or `None` if there are no known values.
+    pub fn get_average_wage_index(&self, year: u32) -> Option<f64> {
+        // α <fn SocialSecurityParameters::get_average_wage_index>
+
+        self.average_wage_index
+            .curve
+            .iter()
+            .min_by_key(|year_value| year_value.year.abs_diff(year))
+            .map(|year_value| {
+                year_value.value
+                    * (1.0 + self.wage_growth).powi(year as i32 - year_value.year as i32)
+            })
+
+        // ω <fn SocialSecurityParameters::get_average_wage_index>
+    }
+
+    /// Factor to index earnings of `year` to the values of `indexing_year`.
+    ///
+    ///   * **year** - Year of the earnings.
+    ///   * **indexing_year** - Year the earner turns 60.
+    ///   * _return_ - The wage indexing factor, 1.0 for years at or after `indexing_year`.
+    pub fn indexing_factor(&self, year: u32, indexing_year: u32) -> f64 {
+        // α <fn SocialSecurityParameters::indexing_factor>
+
+        if year >= indexing_year {
+            1.0
+        } else {
+            match (
+                self.get_average_wage_index(year),
+                self.get_average_wage_index(indexing_year),
+            ) {
+                (Some(from), Some(to)) if from > 0.0 => to / from,
+                _ => (1.0 + self.wage_growth).powi((indexing_year - year) as i32),
+            }
+        }
+
+        // ω <fn SocialSecurityParameters::indexing_factor>
+    }
+
+    /// The PIA bend points for those first eligible in `eligibility_year`.
+    /// Uses the statutory formula on the AWI of two years prior when available,
+    /// otherwise grows the known `bend_points` by `wage_growth`.
+    ///
+    ///   * **eligibility_year** - Year the earner turns 62.
+    ///   * _return_ - First and second bend points.
+    pub fn get_bend_points(&self, eligibility_year: u32) -> (f64, f64) {
+        // α <fn SocialSecurityParameters::get_bend_points>
+
+        match self.get_average_wage_index(eligibility_year - 2) {
+            Some(awi) => (
+                (180.0 * awi / SS_AWI_1977).round(),
+                (1085.0 * awi / SS_AWI_1977).round(),
+            ),
+            None => {
+                let growth = (1.0 + self.wage_growth)
+                    .powi(eligibility_year as i32 - self.bend_points_year as i32);
+                (
+                    (self.bend_points.0 * growth).round(),
+                    (self.bend_points.1 * growth).round(),
+                )
+            }
+        }
+
+        // ω <fn SocialSecurityParameters::get_bend_points>
+    }
+}
+
+impl SocialSecurityEarner {
+    /// Year the earner turns 62 and first becomes eligible.
+    ///
+    ///   * _return_ - The eligibility year.
+    #[inline]
+    pub fn eligibility_year(&self) -> u32 {
+        // α <fn SocialSecurityEarner::eligibility_year>
+        self.birth_year + 62
+        // ω <fn SocialSecurityEarner::eligibility_year>
+    }
+
+    /// Earnings wage indexed to the year the earner turns 60, largest first.
+    ///
+    ///   * **parameters** - Assumptions used to project benefits.
+    ///   * _return_ - Indexed earnings sorted descending.
+    pub fn indexed_earnings(&self, parameters: &SocialSecurityParameters) -> Vec<f64> {
+        // α <fn SocialSecurityEarner::indexed_earnings>
+
+        let indexing_year = self.birth_year + 60;
+        let mut indexed = self
+            .earnings
+            .curve
+            .iter()
+            .map(|year_value| {
+                year_value.value.max(0.0)
+                    * parameters.indexing_factor(year_value.year, indexing_year)
+            })
+            .collect::<Vec<_>>();
+        indexed.sort_by(|a, b| b.total_cmp(a));
+        indexed
+
+        // ω <fn SocialSecurityEarner::indexed_earnings>
+    }
+
+    /// Average indexed monthly earnings over the top 35 years, rounded down to the dollar.
+    ///
+    ///   * **parameters** - Assumptions used to project benefits.
+    ///   * _return_ - The AIME.
+    pub fn aime(&self, parameters: &SocialSecurityParameters) -> f64 {
+        // α <fn SocialSecurityEarner::aime>
+
+        let total: f64 = self
+            .indexed_earnings(parameters)
+            .iter()
+            .take(SS_COMPUTATION_YEARS)
+            .sum();
+        (total / (SS_COMPUTATION_YEARS as f64 * 12.0)).floor()
+
+        // ω <fn SocialSecurityEarner::aime>
+    }
+
+    /// Primary insurance amount, rounded down to the dime.
+    ///
+    ///   * **parameters** - Assumptions used to project benefits.
+    ///   * _return_ - The PIA in eligibility year dollars.
+    pub fn pia(&self, parameters: &SocialSecurityParameters) -> f64 {
+        // α <fn SocialSecurityEarner::pia>
+
+        let aime = self.aime(parameters);
+        let (first, second) = parameters.get_bend_points(self.eligibility_year());
+        let pia = 0.9 * aime.min(first)
+            + 0.32 * (aime.min(second) - first).max(0.0)
+            + 0.15 * (aime - second).max(0.0);
+        (pia * 10.0).floor() / 10.0
+
+        // ω <fn SocialSecurityEarner::pia>
+    }
+
+    /// Monthly retirement benefit on own record, rounded down to the dollar.
+    ///
+    ///   * **parameters** - Assumptions used to project benefits.
+    ///   * **claim_age_months** - Age benefits are claimed in months.
+    ///   * _return_ - Monthly benefit in eligibility year dollars.
+    pub fn retirement_benefit(
+        &self,
+        parameters: &SocialSecurityParameters,
+        claim_age_months: u32,
+    ) -> f64 {
+        // α <fn SocialSecurityEarner::retirement_benefit>
+
+        (self.pia(parameters) * retirement_claim_factor(self.birth_year, claim_age_months)).floor()
+
+        // ω <fn SocialSecurityEarner::retirement_benefit>
+    }
+
+    /// The monthly benefit a survivor bases their benefit on when `self` dies.
+    /// This is the benefit `self` was receiving, but no less than 82.5% of the PIA,
+    /// or the PIA with any credits earned to death if `self` had not yet claimed.
+    ///
+    ///   * **parameters** - Assumptions used to project benefits.
+    ///   * **claim_age_months** - Age `self` claimed or planned to claim in months.
+    ///   * **death_age_months** - Age of death of `self` in months.
+    ///   * _return_ - Monthly benefit in eligibility year dollars of `self`.
+    pub fn survivor_base_benefit(
+        &self,
+        parameters: &SocialSecurityParameters,
+        claim_age_months: u32,
+        death_age_months: u32,
+    ) -> f64 {
+        // α <fn SocialSecurityEarner::survivor_base_benefit>
+
+        let pia = self.pia(parameters);
+        let fra = full_retirement_age_months(self.birth_year);
+        if claim_age_months > death_age_months {
+            (pia * retirement_claim_factor(self.birth_year, death_age_months.max(fra))).floor()
+        } else {
+            self.retirement_benefit(parameters, claim_age_months)
+                .max((0.825 * pia).floor())
+        }
+
+        // ω <fn SocialSecurityEarner::survivor_base_benefit>
+    }
+
+    /// Find the claiming age maximizing the present value of own retirement benefits.
+    ///
+    ///   * **parameters** - Assumptions used to project benefits.
+    ///   * **death_age** - Age of assumed death in years.
+    ///   * **discount_rate** - Real rate used to discount benefits to the eligibility year.
+    ///   * _return_ - The best claiming age in months and its present value.
+    pub fn optimal_claim_age(
+        &self,
+        parameters: &SocialSecurityParameters,
+        death_age: u32,
+        discount_rate: f64,
+    ) -> (u32, f64) {
+        // α <fn SocialSecurityEarner::optimal_claim_age>
+
+        let pia = self.pia(parameters);
+        let death_age_months = death_age * 12;
+        (SS_EARLIEST_CLAIM_AGE_MONTHS..=SS_MAX_CREDIT_AGE_MONTHS)
+            .map(|claim_age_months| {
+                let monthly =
+                    (pia * retirement_claim_factor(self.birth_year, claim_age_months)).floor();
+                let present_value = (claim_age_months..death_age_months)
+                    .map(|age_months| {
+                        let years = (age_months - SS_EARLIEST_CLAIM_AGE_MONTHS) as f64 / 12.0;
+                        monthly / (1.0 + discount_rate).powf(years)
+                    })
+                    .sum::<f64>();
+                (claim_age_months, present_value)
+            })
+            .fold(
+                (SS_EARLIEST_CLAIM_AGE_MONTHS, f64::MIN),
+                |best, candidate| {
+                    if candidate.1 > best.1 {
+                        candidate
+                    } else {
+                        best
+                    }
+                },
+            )
+
+        // ω <fn SocialSecurityEarner::optimal_claim_age>
+    }
+}
+
+impl SocialSecurityClaim {
+    /// Year benefits start.
+    ///
+    ///   * _return_ - The claim year.
+    #[inline]
+    pub fn claim_year(&self) -> u32 {
+        // α <fn SocialSecurityClaim::claim_year>
+        self.earner.birth_year + self.claim_age_months / 12
+        // ω <fn SocialSecurityClaim::claim_year>
+    }
+
+    /// Year of assumed death, the first year without benefits.
+    ///
+    ///   * _return_ - The death year.
+    #[inline]
+    pub fn death_year(&self) -> u32 {
+        // α <fn SocialSecurityClaim::death_year>
+        self.earner.birth_year + self.death_age
+        // ω <fn SocialSecurityClaim::death_year>
+    }
+
+    /// Months of benefits received in `year`, assuming birthdays in January.
+    ///
+    ///   * **year** - Year of interest.
+    ///   * **claim_age_months** - Age the benefit is claimed in months.
+    ///   * _return_ - Months of benefits received in the year.
+    fn months_received(&self, year: u32, claim_age_months: u32) -> u32 {
+        // α <fn SocialSecurityClaim::months_received>
+
+        let start_year = self.earner.birth_year + claim_age_months / 12;
+        if year < start_year || year >= self.death_year() {
+            0
+        } else if year == start_year {
+            12 - claim_age_months % 12
+        } else {
+            12
+        }
+
+        // ω <fn SocialSecurityClaim::months_received>
+    }
+
+    /// Estimate the benefit flow of `self`, including spousal and survivor benefits
+    /// on the record of `spouse`.
+    ///
+    ///   * **parameters** - Assumptions used to project benefits.
+    ///   * **spouse** - The claim of the spouse, if any.
+    ///   * _return_ - The estimate with its generated flow.
+    pub fn estimate(
+        &self,
+        parameters: &SocialSecurityParameters,
+        spouse: Option<&SocialSecurityClaim>,
+    ) -> SocialSecurityEstimate {
+        // α <fn SocialSecurityClaim::estimate>
+
+        let earner = &self.earner;
+        let pia = earner.pia(parameters);
+        let monthly_benefit = earner.retirement_benefit(parameters, self.claim_age_months);
+        let claim_year = self.claim_year();
+        let cola_from = |from_year: u32, year: u32| -> f64 {
+            (1.0 + parameters.cola).powi(year as i32 - from_year as i32)
+        };
+
+        let flow = (claim_year.min(spouse.map_or(claim_year, |spouse| spouse.death_year()))
+            ..self.death_year())
+            .filter_map(|year| {
+                let own_months = self.months_received(year, self.claim_age_months) as f64;
+                let own = monthly_benefit * cola_from(earner.eligibility_year(), year);
+
+                let mut annual = own * own_months;
+
+                if let Some(spouse) = spouse {
+                    let spouse_pia = spouse.earner.pia(parameters)
+                        * cola_from(spouse.earner.eligibility_year(), year);
+                    let own_pia = pia * cola_from(earner.eligibility_year(), year);
+
+                    if year >= spouse.death_year() {
+                        // Survivor benefit replaces own benefit when larger
+                        let survivor_claim_age_months = ((spouse.death_year() - earner.birth_year)
+                            * 12)
+                            .max(SS_EARLIEST_SURVIVOR_CLAIM_AGE_MONTHS);
+                        let survivor = spouse.earner.survivor_base_benefit(
+                            parameters,
+                            spouse.claim_age_months,
+                            spouse.death_age * 12,
+                        ) * cola_from(spouse.earner.eligibility_year(), year)
+                            * survivor_claim_factor(earner.birth_year, survivor_claim_age_months);
+                        let survivor_months =
+                            self.months_received(year, survivor_claim_age_months) as f64;
+                        annual = annual.max(survivor * survivor_months);
+                    } else if year >= spouse.claim_year() {
+                        // Spousal top up once both have claimed
+                        let excess = (0.5 * spouse_pia - own_pia).max(0.0)
+                            * spousal_claim_factor(
+                                earner.birth_year,
+                                self.claim_age_months
+                                    .max((spouse.claim_year() - earner.birth_year) * 12),
+                            );
+                        let spouse_months =
+                            spouse.months_received(year, spouse.claim_age_months) as f64;
+                        annual += excess * own_months.min(spouse_months);
+                    }
+                }
+
+                if annual > 0.0 {
+                    Some(YearCurrencyValue {
+                        year,
+                        currency: Currency::Usd as i32,
+                        value: annual,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        SocialSecurityEstimate {
+            person_type: earner.person_type,
+            pia,
+            monthly_benefit,
+            claim_year,
+            flow,
+        }
+
+        // ω <fn SocialSecurityClaim::estimate>
+    }
+}
+
+impl SocialSecurityEstimate {
+    /// The type of flow generated.
+    ///
+    ///   * _return_ - Always `SocialSecurityIncome`.
+    #[inline]
+    pub fn flow_type(&self) -> FlowType {
+        // α <fn SocialSecurityEstimate::flow_type>
+        FlowType::SocialSecurityIncome
+        // ω <fn SocialSecurityEstimate::flow_type>
+    }
+
+    /// Sum of all benefits in the flow, undiscounted.
+    ///
+    ///   * _return_ - Lifetime benefits.
+    #[inline]
+    pub fn lifetime_benefits(&self) -> f64 {
+        // α <fn SocialSecurityEstimate::lifetime_benefits>
+        self.flow.iter().map(|year_value| year_value.value).sum()
+        // ω <fn SocialSecurityEstimate::lifetime_benefits>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl Default for SocialSecurityParameters {
+    /// A trait for giving a type a useful default value.
+    ///
+    ///   * _return_ - The new default instance
+    fn default() -> Self {
+        // α <fn Default::default for SocialSecurityParameters>
+
+        SocialSecurityParameters {
+            wage_growth: 0.035,
+            cola: 0.025,
+            average_wage_index: YearValueSeries { curve: Vec::new() },
+            bend_points_year: 2024,
+            bend_points: (1_174.0, 7_078.0),
+        }
+
+        // ω <fn Default::default for SocialSecurityParameters>
+    }
+}
+
+/// Unit tests for `social_security`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type SocialSecurityEarner
+    mod test_social_security_earner {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn pia() {
+            // α <fn test SocialSecurityEarner::pia>
+
+            // 35 years at flat indexed earnings of 60,000 -> AIME of 5,000
+            let earner = flat_earner(PersonType::PrimaryOwner, 1962, 60_000.0);
+            let parameters = no_growth_parameters();
+            assert_eq!(5_000.0, earner.aime(&parameters));
+            // 0.9 * 1174 + 0.32 * (5000 - 1174)
+            assert_eq!(2_280.9, earner.pia(&parameters));
+
+            // Only top 35 years count
+            let mut earner = earner.clone();
+            earner.earnings.curve.push(YearValue {
+                year: 1980,
+                value: 1.0,
+            });
+            assert_eq!(5_000.0, earner.aime(&parameters));
+
+            // ω <fn test SocialSecurityEarner::pia>
+        }
+
+        #[test]
+        fn retirement_benefit() {
+            // α <fn test SocialSecurityEarner::retirement_benefit>
+
+            let earner = flat_earner(PersonType::PrimaryOwner, 1962, 60_000.0);
+            let parameters = no_growth_parameters();
+            assert_eq!(2_280.0, earner.retirement_benefit(&parameters, 67 * 12));
+            // 30% reduction at 62 for full retirement age of 67
+            assert_eq!(1_596.0, earner.retirement_benefit(&parameters, 62 * 12));
+            // 24% increase at 70
+            assert_eq!(2_828.0, earner.retirement_benefit(&parameters, 70 * 12));
+
+            // ω <fn test SocialSecurityEarner::retirement_benefit>
+        }
+
+        #[test]
+        fn optimal_claim_age() {
+            // α <fn test SocialSecurityEarner::optimal_claim_age>
+
+            let earner = flat_earner(PersonType::PrimaryOwner, 1962, 60_000.0);
+            let parameters = no_growth_parameters();
+            assert_eq!(62 * 12, earner.optimal_claim_age(&parameters, 70, 0.0).0);
+            assert_eq!(70 * 12, earner.optimal_claim_age(&parameters, 100, 0.0).0);
+
+            // ω <fn test SocialSecurityEarner::optimal_claim_age>
+        }
+
+        // α <mod-def test_social_security_earner>
+        use super::*;
+        // ω <mod-def test_social_security_earner>
+    }
+
+    /// Test type SocialSecurityClaim
+    mod test_social_security_claim {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn estimate() {
+            // α <fn test SocialSecurityClaim::estimate>
+
+            let parameters = no_growth_parameters();
+            let primary = SocialSecurityClaim {
+                earner: flat_earner(PersonType::PrimaryOwner, 1962, 60_000.0),
+                claim_age_months: 67 * 12,
+                death_age: 80,
+            };
+            let secondary = SocialSecurityClaim {
+                earner: flat_earner(PersonType::SecondaryOwner, 1962, 0.0),
+                claim_age_months: 67 * 12,
+                death_age: 90,
+            };
+
+            let estimates = estimate_household(&parameters, &primary, Some(&secondary));
+            assert_eq!(2, estimates.len());
+
+            let primary_estimate = &estimates[0];
+            assert_eq!(FlowType::SocialSecurityIncome, primary_estimate.flow_type());
+            assert_eq!(2029, primary_estimate.claim_year);
+            assert_eq!(2029, primary_estimate.flow[0].year);
+            assert_eq!(2_280.0 * 12.0, primary_estimate.flow[0].value);
+            assert_eq!(2041, primary_estimate.flow.last().unwrap().year);
+
+            // Secondary has no earnings and gets half of primary PIA while both alive
+            let secondary_estimate = &estimates[1];
+            assert_eq!(0.0, secondary_estimate.pia);
+            assert_eq!(2029, secondary_estimate.flow[0].year);
+            assert_eq!(0.5 * 2_280.9 * 12.0, secondary_estimate.flow[0].value);
+
+            // After primary dies, secondary gets the survivor benefit
+            let survivor_year = secondary_estimate
+                .flow
+                .iter()
+                .find(|year_value| year_value.year == 2042)
+                .unwrap();
+            assert_eq!(2_280.0 * 12.0, survivor_year.value);
+            assert_eq!(2051, secondary_estimate.flow.last().unwrap().year);
+
+            // ω <fn test SocialSecurityClaim::estimate>
+        }
+
+        // α <mod-def test_social_security_claim>
+        use super::*;
+        // ω <mod-def test_social_security_claim>
+    }
+
+    #[test]
+    fn full_retirement_age_months() {
+        // α <fn test full_retirement_age_months>
+
+        assert_eq!(65 * 12, super::full_retirement_age_months(1930));
+        assert_eq!(65 * 12 + 6, super::full_retirement_age_months(1940));
+        assert_eq!(66 * 12, super::full_retirement_age_months(1950));
+        assert_eq!(66 * 12 + 10, super::full_retirement_age_months(1959));
+        assert_eq!(67 * 12, super::full_retirement_age_months(1960));
+
+        // ω <fn test full_retirement_age_months>
+    }
+
+    #[test]
+    fn spousal_claim_factor() {
+        // α <fn test spousal_claim_factor>
+
+        assert_eq!(1.0, super::spousal_claim_factor(1960, 67 * 12));
+        assert!((0.65 - super::spousal_claim_factor(1960, 62 * 12)).abs() < 1e-12);
+
+        // ω <fn test spousal_claim_factor>
+    }
+
+    #[test]
+    fn survivor_claim_factor() {
+        // α <fn test survivor_claim_factor>
+
+        assert_eq!(1.0, super::survivor_claim_factor(1960, 67 * 12));
+        assert!((0.715 - super::survivor_claim_factor(1960, 60 * 12)).abs() < 1e-12);
+
+        // ω <fn test survivor_claim_factor>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    use crate::YearValue;
+
+    /// Parameters with no wage growth or cola so amounts are easy to verify
+    fn no_growth_parameters() -> SocialSecurityParameters {
+        SocialSecurityParameters {
+            wage_growth: 0.0,
+            cola: 0.0,
+            ..Default::default()
+        }
+    }
+
+    /// Earner with flat earnings from age 22 through 56
+    fn flat_earner(
+        person_type: PersonType,
+        birth_year: u32,
+        earnings: f64,
+    ) -> SocialSecurityEarner {
+        SocialSecurityEarner {
+            person_type,
+            birth_year,
+            earnings: YearValueSeries {
+                curve: (birth_year + 22..birth_year + 57)
+                    .map(|year| YearValue {
+                        year,
+                        value: earnings,
+                    })
+                    .collect(),
+            },
+        }
+    }
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def social_security>
+// ω <mod-def social_security>
@@ -0,0 +1,453 @@
+//! Inflation drawn as a random variable rather than a fixed `RateCurve`.
+//!
+//! Annual inflation follows a first order autoregression around its long run mean:
+//! `iₜ = μ + φ·(iₜ₋₁ - μ) + εₜ` with `εₜ` normal. The `NormalSpec` is the stationary
+//! distribution of `iₜ`, so the innovations have standard deviation `σ·√(1 - φ²)`.
+//! With `φ` near the historic 0.6 a spike persists for several years, as in the 70s.
+//!
+//! Inflation is correlated with holdings through a `DossierCorrelationMatrix` in which it is
+//! the item `ItemIndex::RateCurveIndex(NamedRateCurve::ReportInflation)`. Each sampled path
+//! is a `RateCurve`, so inflation indexed flows (living expenses, COLA pensions) vary per run
+//! via `RateCurve::index_flows`.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::historic_returns::historic_normal_spec;
+use crate::CorrelatedShockSampler;
+use crate::DossierCorrelationMatrix;
+use crate::DossierItemIndex;
+use crate::HistoricSeries;
+use crate::ItemIndex;
+use crate::NamedRateCurve;
+use crate::NormalSpec;
+use crate::RateCurve;
+use crate::YearRange;
+use crate::YearValue;
+use ndarray::s;
+use ndarray::Array1;
+use plus_utils::IncrementalPearson;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Inflation as an autoregressive random variable.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StochasticInflation {
+    /// Long run mean and standard deviation of annual inflation.
+    pub normal_spec: NormalSpec,
+    /// Correlation of inflation with the prior year's, in (-1, 1).
+    pub autocorrelation: f64,
+}
+
+/// Samples inflation jointly with correlated shocks to holdings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrelatedInflationSampler {
+    /// The inflation process.
+    pub inflation: StochasticInflation,
+    /// Holdings correlated with inflation, in the order of the sampled shocks.
+    pub items: Vec<DossierItemIndex>,
+    /// Shocks to the items followed by inflation.
+    shock_sampler: CorrelatedShockSampler,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl StochasticInflation {
+    /// Estimate from the embedded history of US inflation.
+    ///
+    ///   * **years** - Window of years, `end` exclusive, clipped to the dataset.
+    ///   * _return_ - The estimate, `None` if fewer than 3 years of data.
+    pub fn from_history(years: &YearRange) -> Option<StochasticInflation> {
+        // α <fn StochasticInflation::from_history>
+
+        let normal_spec = historic_normal_spec(HistoricSeries::UsInflation, years)?;
+        let rates = HistoricSeries::UsInflation
+            .returns_in_range(years)
+            .into_iter()
+            .map(|year_value| year_value.value)
+            .collect::<Vec<_>>();
+
+        let mut incremental_pearson = IncrementalPearson::new(2);
+        for pair in rates.windows(2) {
+            incremental_pearson.track_row(&Array1::from_vec(pair.to_vec()));
+        }
+        let autocorrelation = incremental_pearson.correlation_matrix()?[[0, 1]];
+
+        Some(StochasticInflation {
+            normal_spec,
+            autocorrelation: if autocorrelation.is_finite() {
+                autocorrelation
+            } else {
+                0.0
+            },
+        })
+
+        // ω <fn StochasticInflation::from_history>
+    }
+
+    /// The item identifying inflation in a `DossierCorrelationMatrix`.
+    ///
+    ///   * _return_ - The dossier item index.
+    pub fn dossier_item_index() -> DossierItemIndex {
+        // α <fn StochasticInflation::dossier_item_index>
+        DossierItemIndex {
+            item_index: Some(ItemIndex::RateCurveIndex(
+                NamedRateCurve::ReportInflation as i32,
+            )),
+        }
+        // ω <fn StochasticInflation::dossier_item_index>
+    }
+
+    /// Standard deviation of the annual innovation `εₜ`.
+    ///
+    ///   * _return_ - The standard deviation.
+    #[inline]
+    pub fn innovation_std_dev(&self) -> f64 {
+        // α <fn StochasticInflation::innovation_std_dev>
+        self.normal_spec.std_dev
+            * (1.0 - self.autocorrelation * self.autocorrelation)
+                .max(0.0)
+                .sqrt()
+        // ω <fn StochasticInflation::innovation_std_dev>
+    }
+
+    /// Inflation following a year's inflation.
+    ///
+    ///   * **previous_rate** - Inflation of the prior year.
+    ///   * **standard_normal** - Draw from the standard normal driving the innovation.
+    ///   * _return_ - The inflation rate.
+    #[inline]
+    pub fn next_rate(&self, previous_rate: f64, standard_normal: f64) -> f64 {
+        // α <fn StochasticInflation::next_rate>
+        let mean = self.normal_spec.mean;
+        mean + self.autocorrelation * (previous_rate - mean)
+            + self.innovation_std_dev() * standard_normal
+        // ω <fn StochasticInflation::next_rate>
+    }
+
+    /// Sample a path of inflation.
+    ///
+    ///   * **years** - Years of the path, `end` exclusive.
+    ///   * **previous_rate** - Inflation of the year before the path (e.g. the latest reported).
+    ///   * **standard_normal** - Source of draws from the standard normal, one per year.
+    ///   * _return_ - Inflation by year.
+    pub fn sample_curve(
+        &self,
+        years: &YearRange,
+        previous_rate: f64,
+        mut standard_normal: impl FnMut() -> f64,
+    ) -> RateCurve {
+        // α <fn StochasticInflation::sample_curve>
+
+        let mut rate = previous_rate;
+        RateCurve {
+            curve: (years.start..years.end)
+                .map(|year| {
+                    rate = self.next_rate(rate, standard_normal());
+                    YearValue { year, value: rate }
+                })
+                .collect(),
+        }
+
+        // ω <fn StochasticInflation::sample_curve>
+    }
+}
+
+impl CorrelatedInflationSampler {
+    /// Create a sampler of inflation correlated with holdings.
+    ///
+    ///   * **inflation** - The inflation process.
+    ///   * **items** - Holdings to sample shocks for.
+    ///   * **correlation_matrix** - Correlations between holdings and with `StochasticInflation::dossier_item_index`.
+    ///   * _return_ - The sampler, `None` if the correlations are not a valid correlation matrix.
+    pub fn new(
+        inflation: StochasticInflation,
+        items: &[DossierItemIndex],
+        correlation_matrix: &DossierCorrelationMatrix,
+    ) -> Option<CorrelatedInflationSampler> {
+        // α <fn CorrelatedInflationSampler::new>
+
+        let all_items = items
+            .iter()
+            .copied()
+            .chain(std::iter::once(StochasticInflation::dossier_item_index()))
+            .collect::<Vec<_>>();
+
+        Some(CorrelatedInflationSampler {
+            inflation,
+            items: items.to_vec(),
+            shock_sampler: CorrelatedShockSampler::new(&all_items, correlation_matrix)?,
+        })
+
+        // ω <fn CorrelatedInflationSampler::new>
+    }
+
+    /// Sample a year of holding shocks and inflation.
+    ///
+    ///   * **previous_rate** - Inflation of the prior year.
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - Standard normal shock of each item, correlated with inflation, and the inflation rate.
+    pub fn sample_year(
+        &self,
+        previous_rate: f64,
+        mut standard_normal: impl FnMut() -> f64,
+    ) -> (Array1<f64>, f64) {
+        // α <fn CorrelatedInflationSampler::sample_year>
+
+        let holding_count = self.items.len();
+        let shocks = self.shock_sampler.sample(&mut standard_normal);
+
+        (
+            shocks.slice(s![..holding_count]).to_owned(),
+            self.inflation
+                .next_rate(previous_rate, shocks[holding_count]),
+        )
+
+        // ω <fn CorrelatedInflationSampler::sample_year>
+    }
+
+    /// Sample a path of holding shocks and inflation.
+    ///
+    ///   * **years** - Years of the path, `end` exclusive.
+    ///   * **previous_rate** - Inflation of the year before the path.
+    ///   * **standard_normal** - Source of independent draws from the standard normal.
+    ///   * _return_ - Shocks of the items for each year and the inflation curve.
+    pub fn sample_path(
+        &self,
+        years: &YearRange,
+        previous_rate: f64,
+        mut standard_normal: impl FnMut() -> f64,
+    ) -> (Vec<Array1<f64>>, RateCurve) {
+        // α <fn CorrelatedInflationSampler::sample_path>
+
+        let mut rate = previous_rate;
+        let mut shocks_by_year =
+            Vec::with_capacity((years.end.saturating_sub(years.start)) as usize);
+        let mut curve = Vec::with_capacity(shocks_by_year.capacity());
+        for year in years.start..years.end {
+            let (shocks, next_rate) = self.sample_year(rate, &mut standard_normal);
+            rate = next_rate;
+            shocks_by_year.push(shocks);
+            curve.push(YearValue { year, value: rate });
+        }
+
+        (shocks_by_year, RateCurve { curve })
+
+        // ω <fn CorrelatedInflationSampler::sample_path>
+    }
+}
+
+/// Unit tests for `stochastic_inflation`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type StochasticInflation
+    mod test_stochastic_inflation {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::historic_returns::historic_years;
+        use crate::NormalSpec;
+        use crate::StochasticInflation;
+        use crate::YearRange;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn from_history() {
+            // α <fn test StochasticInflation::from_history>
+
+            let inflation = StochasticInflation::from_history(&historic_years()).unwrap();
+            assert!(inflation.normal_spec.mean > 0.02 && inflation.normal_spec.mean < 0.04);
+            // Inflation is persistent
+            assert!(inflation.autocorrelation > 0.4 && inflation.autocorrelation < 0.9);
+
+            assert_eq!(
+                None,
+                StochasticInflation::from_history(&YearRange {
+                    start: 2023,
+                    end: 2024
+                })
+            );
+
+            // ω <fn test StochasticInflation::from_history>
+        }
+
+        #[test]
+        fn next_rate() {
+            // α <fn test StochasticInflation::next_rate>
+
+            let inflation = StochasticInflation {
+                normal_spec: NormalSpec {
+                    mean: 0.03,
+                    std_dev: 0.05,
+                },
+                autocorrelation: 0.6,
+            };
+            assert!((inflation.innovation_std_dev() - 0.04).abs() < 1e-12);
+
+            // A spike decays toward the mean
+            assert!((inflation.next_rate(0.13, 0.0) - 0.09).abs() < 1e-12);
+            assert!((inflation.next_rate(0.03, 1.0) - 0.07).abs() < 1e-12);
+
+            // ω <fn test StochasticInflation::next_rate>
+        }
+
+        #[test]
+        fn sample_curve() {
+            // α <fn test StochasticInflation::sample_curve>
+
+            let inflation = StochasticInflation {
+                normal_spec: NormalSpec {
+                    mean: 0.03,
+                    std_dev: 0.05,
+                },
+                autocorrelation: 0.5,
+            };
+            let curve = inflation.sample_curve(
+                &YearRange {
+                    start: 2030,
+                    end: 2033,
+                },
+                0.13,
+                || 0.0,
+            );
+            let years = curve.curve.iter().map(|yv| yv.year).collect::<Vec<_>>();
+            assert_eq!(vec![2030, 2031, 2032], years);
+            for (year_value, expected) in curve.curve.iter().zip([0.08, 0.055, 0.0425]) {
+                assert!((year_value.value - expected).abs() < 1e-12);
+            }
+
+            // ω <fn test StochasticInflation::sample_curve>
+        }
+
+        // α <mod-def test_stochastic_inflation>
+        // ω <mod-def test_stochastic_inflation>
+    }
+
+    /// Test type CorrelatedInflationSampler
+    mod test_correlated_inflation_sampler {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use crate::CorrelatedInflationSampler;
+        use crate::DossierCorrelationMatrix;
+        use crate::DossierItemIndex;
+        use crate::ItemIndex;
+        use crate::NormalSpec;
+        use crate::StochasticInflation;
+        use crate::YearRange;
+        use ndarray::array;
+        use plus_utils::IncrementalPearson;
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn sample_year() {
+            // α <fn test CorrelatedInflationSampler::sample_year>
+
+            let stocks = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(0)),
+            };
+            let correlation_matrix = DossierCorrelationMatrix::from_correlations(
+                &[stocks, StochasticInflation::dossier_item_index()],
+                &array![[1.0, -1.0], [-1.0, 1.0]],
+            );
+            let sampler = CorrelatedInflationSampler::new(
+                StochasticInflation {
+                    normal_spec: NormalSpec {
+                        mean: 0.03,
+                        std_dev: 0.05,
+                    },
+                    autocorrelation: 0.0,
+                },
+                &[stocks],
+                &correlation_matrix,
+            )
+            .unwrap();
+
+            // Perfectly negatively correlated - stocks fall as inflation spikes
+            let (shocks, rate) = sampler.sample_year(0.03, || -2.0);
+            assert_eq!(1, shocks.len());
+            assert!((shocks[0] + 2.0).abs() < 1e-12);
+            assert!((rate - 0.13).abs() < 1e-12);
+
+            // ω <fn test CorrelatedInflationSampler::sample_year>
+        }
+
+        #[test]
+        fn sample_path() {
+            // α <fn test CorrelatedInflationSampler::sample_path>
+
+            let stocks = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(0)),
+            };
+            let bonds = DossierItemIndex {
+                item_index: Some(ItemIndex::WorthIndex(1)),
+            };
+            let correlation_matrix = DossierCorrelationMatrix::from_correlations(
+                &[stocks, bonds, StochasticInflation::dossier_item_index()],
+                &array![[1.0, 0.2, -0.3], [0.2, 1.0, -0.5], [-0.3, -0.5, 1.0]],
+            );
+            let inflation = StochasticInflation {
+                normal_spec: NormalSpec {
+                    mean: 0.03,
+                    std_dev: 0.04,
+                },
+                autocorrelation: 0.6,
+            };
+            let sampler =
+                CorrelatedInflationSampler::new(inflation, &[stocks, bonds], &correlation_matrix)
+                    .unwrap();
+
+            // Deterministic stand in for standard normal draws
+            let mut state = 12345_u64;
+            let mut standard_normal = move || {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let uniform = ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+                plus_utils::standard_normal_inverse_cdf(uniform).unwrap_or_default()
+            };
+            let years = YearRange {
+                start: 2000,
+                end: 22000,
+            };
+            let (shocks, curve) = sampler.sample_path(&years, 0.03, &mut standard_normal);
+            assert_eq!(20_000, shocks.len());
+            assert_eq!(20_000, curve.curve.len());
+
+            // Stock and bond shocks are correlated with the inflation innovations
+            let mut incremental_pearson = IncrementalPearson::new(3);
+            let mut previous_rate = 0.03;
+            for (shock, year_value) in shocks.iter().zip(&curve.curve) {
+                let innovation = year_value.value
+                    - inflation.normal_spec.mean
+                    - inflation.autocorrelation * (previous_rate - inflation.normal_spec.mean);
+                previous_rate = year_value.value;
+                incremental_pearson.track_row(&array![shock[0], shock[1], innovation]);
+            }
+            let correlations = incremental_pearson.correlation_matrix().unwrap();
+            assert!((correlations[[0, 1]] - 0.2).abs() < 0.03);
+            assert!((correlations[[0, 2]] + 0.3).abs() < 0.03);
+            assert!((correlations[[1, 2]] + 0.5).abs() < 0.03);
+
+            // ω <fn test CorrelatedInflationSampler::sample_path>
+        }
+
+        // α <mod-def test_correlated_inflation_sampler>
+        // ω <mod-def test_correlated_inflation_sampler>
+    }
+
+    // α <mod-def unit_tests>
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def stochastic_inflation>
+// ω <mod-def stochastic_inflation>
@@ -0,0 +1,303 @@
+//! Deterministic replay of historic stress periods.
+//!
+//! A `StressScenario` replays the annual returns and inflation of a named historic period,
+//! starting in a chosen forecast year. Each holding takes the returns of the historic series
+//! matching its `DistributionInstrument`. Years of the forecast outside the scenario grow at
+//! the long run average of the full history. The outcome reports the balance by year and the
+//! first year spending could not be met. This answers "would the plan survive a repeat of the
+//! 70s" directly, where Monte Carlo percentiles mix that history with every other.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::historic_returns::historic_normal_spec;
+use crate::historic_returns::historic_years;
+use crate::DistributionInstrument;
+use crate::HistoricSeries;
+use crate::YearRange;
+use crate::YearValue;
+use strum::IntoEnumIterator;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A named historic period to replay.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumVariantNames, EnumIter,
+)]
+pub enum StressScenario {
+    /// 1929-1945: the crash, the depression and the war years.
+    GreatDepression,
+    /// 1966-1982: stagflation - flat real equity returns, high inflation and rising rates.
+    Stagflation,
+    /// 2000-2012: the dot-com bust and the financial crisis back to back.
+    LostDecade,
+    /// 2008: the financial crisis.
+    FinancialCrisis,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Result of replaying a scenario over a plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StressOutcome {
+    /// The scenario replayed.
+    pub scenario: StressScenario,
+    /// Total balance at the end of each forecast year.
+    pub balances: Vec<YearValue>,
+    /// First year spending could not be met, `None` if the plan survives.
+    pub first_insolvency: Option<u32>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl StressScenario {
+    /// Historic years replayed.
+    ///
+    ///   * _return_ - The years, `end` exclusive.
+    pub fn years(&self) -> YearRange {
+        // α <fn StressScenario::years>
+        let (start, end) = match self {
+            StressScenario::GreatDepression => (1929, 1946),
+            StressScenario::Stagflation => (1966, 1983),
+            StressScenario::LostDecade => (2000, 2013),
+            StressScenario::FinancialCrisis => (2008, 2009),
+        };
+        YearRange { start, end }
+        // ω <fn StressScenario::years>
+    }
+
+    /// Replay the scenario over a plan drawing a fixed real amount each year.
+    /// Spending is withdrawn at the start of each year pro rata across holdings, then
+    /// each holding grows by its return and spending grows by inflation.
+    ///
+    ///   * **holdings** - Instrument and starting value of each holding.
+    ///   * **annual_spending** - Spending in the first forecast year.
+    ///   * **forecast_years** - Years of the forecast, `end` exclusive.
+    ///   * **scenario_start_year** - Forecast year the first scenario year is replayed in.
+    ///   * _return_ - Balances by year and the first year spending is not met.
+    pub fn replay(
+        &self,
+        holdings: &[(DistributionInstrument, f64)],
+        annual_spending: f64,
+        forecast_years: &YearRange,
+        scenario_start_year: u32,
+    ) -> StressOutcome {
+        // α <fn StressScenario::replay>
+
+        let scenario_years = self.years();
+        let long_run_mean = |series: HistoricSeries| {
+            historic_normal_spec(series, &historic_years())
+                .map(|normal_spec| normal_spec.mean)
+                .unwrap_or_default()
+        };
+        let long_run = HistoricSeries::iter()
+            .map(|series| (series, long_run_mean(series)))
+            .collect::<Vec<_>>();
+        let series_return = |series: HistoricSeries, year: u32| {
+            year.checked_sub(scenario_start_year)
+                .map(|offset| scenario_years.start + offset)
+                .filter(|historic_year| *historic_year < scenario_years.end)
+                .and_then(|historic_year| series.return_in_year(historic_year))
+                .or_else(|| {
+                    long_run
+                        .iter()
+                        .find(|(long_run_series, _)| *long_run_series == series)
+                        .map(|(_, mean)| *mean)
+                })
+                .unwrap_or_default()
+        };
+
+        let mut values = holdings.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+        let mut spending = annual_spending;
+        let mut balances = Vec::new();
+        let mut first_insolvency = None;
+
+        for year in forecast_years.start..forecast_years.end {
+            let total = values.iter().sum::<f64>();
+            if first_insolvency.is_none() && total < spending {
+                first_insolvency = Some(year);
+            }
+            let remaining = if total > 0.0 {
+                (total - spending).max(0.0) / total
+            } else {
+                0.0
+            };
+
+            for ((instrument, _), value) in holdings.iter().zip(values.iter_mut()) {
+                let instrument_return = instrument_series_mix(*instrument)
+                    .iter()
+                    .map(|(series, weight)| weight * series_return(*series, year))
+                    .sum::<f64>();
+                *value *= remaining * (1.0 + instrument_return);
+            }
+            spending *= 1.0 + series_return(HistoricSeries::UsInflation, year);
+
+            balances.push(YearValue {
+                year,
+                value: values.iter().sum(),
+            });
+        }
+
+        StressOutcome {
+            scenario: *self,
+            balances,
+            first_insolvency,
+        }
+
+        // ω <fn StressScenario::replay>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Historic series whose returns a holding of an instrument takes in a replay.
+/// Mutual funds are taken as balanced, 60% equity and 40% treasury bonds.
+///
+///   * **instrument** - The instrument.
+///   * _return_ - Each series with its weight, weights summing to one.
+pub fn instrument_series_mix(
+    instrument: DistributionInstrument,
+) -> &'static [(HistoricSeries, f64)] {
+    // α <fn instrument_series_mix>
+    match instrument {
+        DistributionInstrument::Equity => &[(HistoricSeries::UsLargeCapEquity, 1.0)],
+        DistributionInstrument::InterestBearing => &[(HistoricSeries::UsTreasuryBond, 1.0)],
+        DistributionInstrument::MutualFund => &[
+            (HistoricSeries::UsLargeCapEquity, 0.6),
+            (HistoricSeries::UsTreasuryBond, 0.4),
+        ],
+    }
+    // ω <fn instrument_series_mix>
+}
+
+/// Unit tests for `stress_scenario`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_instrument_series_mix() {
+        // α <fn test_instrument_series_mix>
+        for instrument in [
+            DistributionInstrument::Equity,
+            DistributionInstrument::InterestBearing,
+            DistributionInstrument::MutualFund,
+        ] {
+            let total_weight = instrument_series_mix(instrument)
+                .iter()
+                .map(|(_, weight)| weight)
+                .sum::<f64>();
+            assert!((total_weight - 1.0).abs() < 1e-12);
+        }
+
+        // A series replays as series of its own instrument
+        for series in HistoricSeries::iter() {
+            if let Some(instrument) = series.distribution_instrument() {
+                for (mix_series, _) in instrument_series_mix(instrument) {
+                    assert_eq!(Some(instrument), mix_series.distribution_instrument());
+                }
+            }
+        }
+        // ω <fn test_instrument_series_mix>
+    }
+
+    /// Test type StressScenario
+    mod test_stress_scenario {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn years() {
+            // α <fn test StressScenario::years>
+            for scenario in StressScenario::iter() {
+                let years = scenario.years();
+                assert!(years.start < years.end);
+                assert!(HistoricSeries::UsLargeCapEquity
+                    .return_in_year(years.end - 1)
+                    .is_some());
+            }
+            // ω <fn test StressScenario::years>
+        }
+
+        #[test]
+        fn replay() {
+            // α <fn test StressScenario::replay>
+
+            let forecast_years = YearRange {
+                start: 2030,
+                end: 2060,
+            };
+
+            // Equity replays the crisis year then grows at the long run average
+            let outcome = StressScenario::FinancialCrisis.replay(
+                &[(DistributionInstrument::Equity, 1_000.0)],
+                0.0,
+                &forecast_years,
+                2031,
+            );
+            assert_eq!(30, outcome.balances.len());
+            let long_run =
+                historic_normal_spec(HistoricSeries::UsLargeCapEquity, &historic_years())
+                    .unwrap()
+                    .mean;
+            assert!((outcome.balances[0].value - 1_000.0 * (1.0 + long_run)).abs() < 1e-9);
+            assert!(
+                (outcome.balances[1].value - outcome.balances[0].value * (1.0 - 0.3655)).abs()
+                    < 1e-9
+            );
+            assert_eq!(None, outcome.first_insolvency);
+
+            // Spending 6% through the 70s fails, spending 2% survives
+            let holdings = [
+                (DistributionInstrument::Equity, 600_000.0),
+                (DistributionInstrument::InterestBearing, 400_000.0),
+            ];
+            let stagflation =
+                StressScenario::Stagflation.replay(&holdings, 60_000.0, &forecast_years, 2030);
+            let insolvency = stagflation.first_insolvency.unwrap();
+            assert!(insolvency > 2030 && insolvency < 2060);
+            assert!(stagflation
+                .balances
+                .iter()
+                .skip_while(|balance| balance.year < insolvency)
+                .all(|balance| balance.value == 0.0));
+
+            let modest =
+                StressScenario::Stagflation.replay(&holdings, 20_000.0, &forecast_years, 2030);
+            assert_eq!(None, modest.first_insolvency);
+
+            // ω <fn test StressScenario::replay>
+        }
+
+        // α <mod-def test_stress_scenario>
+        use super::*;
+        // ω <mod-def test_stress_scenario>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    use crate::historic_returns::historic_normal_spec;
+    use crate::historic_returns::historic_years;
+    use crate::HistoricSeries;
+    use crate::YearRange;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def stress_scenario>
+// ω <mod-def stress_scenario>
@@ -0,0 +1,130 @@
+//! Progressive tax schedules shared by all jurisdictions.
+//!
+//! A schedule is a list of `TaxBracket`s, each taxing the income between its start and
+//! the start of the next bracket at its marginal rate.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A single bracket of a progressive tax schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TaxBracket {
+    /// Taxable income at which the bracket starts.
+    pub start: f64,
+    /// Marginal rate applied to income in the bracket.
+    pub rate: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Tax due on `taxable_income` under a progressive schedule.
+///
+///   * **taxable_income** - Income after deductions.
+///   * **brackets** - Brackets sorted by `start`, the first starting at 0.
+///   * _return_ - The tax due.
+pub fn tax_on_income(taxable_income: f64, brackets: &[TaxBracket]) -> f64 {
+    // α <fn tax_on_income>
+
+    brackets
+        .iter()
+        .enumerate()
+        .map(|(i, bracket)| {
+            let end = brackets
+                .get(i + 1)
+                .map(|next| next.start)
+                .unwrap_or(f64::MAX);
+            (taxable_income.min(end) - bracket.start).max(0.0) * bracket.rate
+        })
+        .sum()
+
+    // ω <fn tax_on_income>
+}
+
+/// Marginal rate applying to the last dollar of `taxable_income`.
+///
+///   * **taxable_income** - Income after deductions.
+///   * **brackets** - Brackets sorted by `start`, the first starting at 0.
+///   * _return_ - The marginal rate.
+pub fn marginal_rate(taxable_income: f64, brackets: &[TaxBracket]) -> f64 {
+    // α <fn marginal_rate>
+
+    brackets
+        .iter()
+        .rev()
+        .find(|bracket| taxable_income > bracket.start)
+        .or(brackets.first())
+        .map(|bracket| bracket.rate)
+        .unwrap_or_default()
+
+    // ω <fn marginal_rate>
+}
+
+/// Additional taxable income that can be added before leaving the bracket taxed at `rate`.
+///
+///   * **taxable_income** - Income after deductions.
+///   * **rate** - Marginal rate of the bracket to fill.
+///   * **brackets** - Brackets sorted by `start`, the first starting at 0.
+///   * _return_ - Room left up to the top of the bracket, 0 if already beyond it.
+pub fn room_in_bracket(taxable_income: f64, rate: f64, brackets: &[TaxBracket]) -> f64 {
+    // α <fn room_in_bracket>
+
+    brackets
+        .iter()
+        .position(|bracket| bracket.rate == rate)
+        .map(|i| {
+            brackets
+                .get(i + 1)
+                .map(|next| (next.start - taxable_income.max(0.0)).max(0.0))
+                .unwrap_or(f64::MAX)
+        })
+        .unwrap_or_default()
+
+    // ω <fn room_in_bracket>
+}
+
+/// Unit tests for `tax_brackets`
+#[cfg(test)]
+pub mod unit_tests {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_tax_on_income() {
+        // α <fn test_tax_on_income>
+
+        let brackets = TaxUsFilingStatus::Single.ordinary_income_brackets(2024, 0.0);
+        assert_eq!(0.0, tax_on_income(0.0, &brackets));
+        assert_eq!(1_160.0, tax_on_income(11_600.0, &brackets));
+        assert!((tax_on_income(50_000.0, &brackets) - 6_053.0).abs() < 1e-9);
+        assert_eq!(0.22, marginal_rate(50_000.0, &brackets));
+        assert_eq!(0.12, marginal_rate(47_150.0, &brackets));
+        assert_eq!(0.10, marginal_rate(0.0, &brackets));
+
+        // ω <fn test_tax_on_income>
+    }
+
+    #[test]
+    fn test_room_in_bracket() {
+        // α <fn test_room_in_bracket>
+
+        let brackets = TaxUsFilingStatus::MarriedJoint.ordinary_income_brackets(2024, 0.0);
+        assert_eq!(
+            94_300.0 - 30_000.0,
+            room_in_bracket(30_000.0, 0.12, &brackets)
+        );
+        assert_eq!(0.0, room_in_bracket(100_000.0, 0.12, &brackets));
+        assert_eq!(f64::MAX, room_in_bracket(1_000_000.0, 0.37, &brackets));
+        assert_eq!(0.0, room_in_bracket(30_000.0, 0.15, &brackets));
+
+        // ω <fn test_room_in_bracket>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    use crate::TaxUsFilingStatus;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def tax_brackets>
+// ω <mod-def tax_brackets>
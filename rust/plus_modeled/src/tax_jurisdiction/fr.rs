@@ -0,0 +1,280 @@
+//! Simplified French taxes for the 2024 tax year.
+//!
+//! Income tax applies the progressive scale per household part (_quotient familial_)
+//! after the 10% professional expense allowance. Capital gains bear the 30% flat tax
+//! (_prélèvement forfaitaire unique_, 12.8% income tax and 17.2% social charges).
+//! The wealth tax is the _impôt sur la fortune immobilière_ on net real estate wealth.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use super::TaxJurisdiction;
+use super::TaxWrapper;
+use super::WrapperWithdrawal;
+use crate::tax_brackets::tax_on_income;
+use crate::tax_brackets::TaxBracket;
+use crate::Country;
+use crate::Currency;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Tax year of the scale and thresholds.
+pub const FR_TAX_YEAR: u32 = 2024;
+
+/// Social charges on investment income and gains.
+pub const FR_SOCIAL_CHARGES_RATE: f64 = 0.172;
+
+/// Income tax portion of the flat tax on investment income and gains.
+pub const FR_FLAT_TAX_INCOME_RATE: f64 = 0.128;
+
+/// Income tax rate on _assurance-vie_ gains after 8 years.
+pub const FR_ASSURANCE_VIE_REDUCED_RATE: f64 = 0.075;
+
+/// Yearly _assurance-vie_ gains exempt from income tax after 8 years, per person.
+pub const FR_ASSURANCE_VIE_ALLOWANCE: f64 = 4_600.0;
+
+/// Net real estate wealth from which the wealth tax is due.
+pub const FR_WEALTH_TAX_THRESHOLD: f64 = 1_300_000.0;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// French taxes of a household (_foyer fiscal_).
+/// Foreign wrappers are not recognized and are taxed like a `FrCompteTitres`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrTaxJurisdiction {
+    /// Parts of the household - 1 per adult, 0.5 for each of the first two children.
+    pub household_parts: f64,
+    /// Assumed annual indexing of the income tax scale.
+    pub inflation: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl TaxJurisdiction for FrTaxJurisdiction {
+    /// Country of the jurisdiction.
+    ///
+    ///   * _return_ - The country.
+    fn country(&self) -> Country {
+        // α <fn TaxJurisdiction::country for FrTaxJurisdiction>
+        Country::France
+        // ω <fn TaxJurisdiction::country for FrTaxJurisdiction>
+    }
+
+    /// Currency taxes are computed in.
+    ///
+    ///   * _return_ - The currency.
+    fn currency(&self) -> Currency {
+        // α <fn TaxJurisdiction::currency for FrTaxJurisdiction>
+        Currency::Eur
+        // ω <fn TaxJurisdiction::currency for FrTaxJurisdiction>
+    }
+
+    /// Wrappers the jurisdiction provides.
+    ///
+    ///   * _return_ - The wrappers.
+    fn wrappers(&self) -> Vec<TaxWrapper> {
+        // α <fn TaxJurisdiction::wrappers for FrTaxJurisdiction>
+        vec![
+            TaxWrapper::FrPea,
+            TaxWrapper::FrAssuranceVie,
+            TaxWrapper::FrCompteTitres,
+        ]
+        // ω <fn TaxJurisdiction::wrappers for FrTaxJurisdiction>
+    }
+
+    /// Tax on ordinary income from the progressive scale applied per household part.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **ordinary_income** - Gross ordinary income of the household.
+    ///   * _return_ - The income tax.
+    fn income_tax(&self, year: u32, ordinary_income: f64) -> f64 {
+        // α <fn TaxJurisdiction::income_tax for FrTaxJurisdiction>
+
+        let index = (1.0 + self.inflation).powi(year as i32 - FR_TAX_YEAR as i32);
+        let brackets = [
+            (0.0, 0.0),
+            (11_294.0, 0.11),
+            (28_797.0, 0.30),
+            (82_341.0, 0.41),
+            (177_106.0, 0.45),
+        ]
+        .into_iter()
+        .map(|(start, rate)| TaxBracket {
+            start: (start * index).round(),
+            rate,
+        })
+        .collect::<Vec<_>>();
+        let parts = self.household_parts.max(1.0);
+        let taxable_income = ordinary_income - (ordinary_income * 0.1).min(14_171.0 * index);
+
+        tax_on_income(taxable_income / parts, &brackets) * parts
+
+        // ω <fn TaxJurisdiction::income_tax for FrTaxJurisdiction>
+    }
+
+    /// Flat tax of 30% on gains.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **capital_gains** - Realized gains.
+    ///   * **ordinary_income** - Ordinary income, which does not affect the flat tax.
+    ///   * _return_ - The capital gains tax.
+    fn capital_gains_tax(&self, _year: u32, capital_gains: f64, _ordinary_income: f64) -> f64 {
+        // α <fn TaxJurisdiction::capital_gains_tax for FrTaxJurisdiction>
+        capital_gains.max(0.0) * (FR_FLAT_TAX_INCOME_RATE + FR_SOCIAL_CHARGES_RATE)
+        // ω <fn TaxJurisdiction::capital_gains_tax for FrTaxJurisdiction>
+    }
+
+    /// Wealth tax on net real estate wealth of at least `FR_WEALTH_TAX_THRESHOLD`,
+    /// including the reduction (_décote_) for wealth under 1.4M.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **net_wealth** - Net real estate wealth.
+    ///   * _return_ - The wealth tax.
+    fn wealth_tax(&self, _year: u32, net_wealth: f64) -> f64 {
+        // α <fn TaxJurisdiction::wealth_tax for FrTaxJurisdiction>
+
+        if net_wealth < FR_WEALTH_TAX_THRESHOLD {
+            0.0
+        } else {
+            let brackets = [
+                (0.0, 0.0),
+                (800_000.0, 0.005),
+                (1_300_000.0, 0.007),
+                (2_570_000.0, 0.01),
+                (5_000_000.0, 0.0125),
+                (10_000_000.0, 0.015),
+            ]
+            .map(|(start, rate)| TaxBracket { start, rate });
+            let decote = if net_wealth < 1_400_000.0 {
+                17_500.0 - 0.0125 * net_wealth
+            } else {
+                0.0
+            };
+            (tax_on_income(net_wealth, &brackets) - decote).max(0.0)
+        }
+
+        // ω <fn TaxJurisdiction::wealth_tax for FrTaxJurisdiction>
+    }
+
+    /// Tax on the gain of a withdrawal. PEA gains only bear social charges after 5 years,
+    /// _assurance-vie_ gains are taxed at 7.5% beyond the allowance after 8 years, and
+    /// all other gains bear the flat tax.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **withdrawal** - The withdrawal.
+    ///   * **ordinary_income** - Other ordinary income of the year.
+    ///   * _return_ - The tax due on the withdrawal.
+    fn withdrawal_tax(
+        &self,
+        year: u32,
+        withdrawal: &WrapperWithdrawal,
+        ordinary_income: f64,
+    ) -> f64 {
+        // α <fn TaxJurisdiction::withdrawal_tax for FrTaxJurisdiction>
+
+        let gain = withdrawal.gain.max(0.0);
+        match withdrawal.wrapper {
+            TaxWrapper::FrPea if withdrawal.years_held >= 5 => gain * FR_SOCIAL_CHARGES_RATE,
+            TaxWrapper::FrAssuranceVie if withdrawal.years_held >= 8 => {
+                let allowance = FR_ASSURANCE_VIE_ALLOWANCE * self.household_parts.clamp(1.0, 2.0);
+                (gain - allowance).max(0.0) * FR_ASSURANCE_VIE_REDUCED_RATE
+                    + gain * FR_SOCIAL_CHARGES_RATE
+            }
+            _ => self.capital_gains_tax(year, gain, ordinary_income),
+        }
+
+        // ω <fn TaxJurisdiction::withdrawal_tax for FrTaxJurisdiction>
+    }
+}
+
+/// Unit tests for `fr`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test trait tax_jurisdiction on FrTaxJurisdiction
+    pub mod test_tax_jurisdiction_on_fr_tax_jurisdiction {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn income_tax() {
+            // α <fn test TaxJurisdiction::income_tax on FrTaxJurisdiction>
+
+            let single = FrTaxJurisdiction {
+                household_parts: 1.0,
+                inflation: 0.0,
+            };
+            let couple = FrTaxJurisdiction {
+                household_parts: 2.0,
+                ..single
+            };
+
+            // 30_000 less the 10% allowance is 27_000 taxable
+            let single_tax = 0.11 * (27_000.0 - 11_294.0);
+            assert!((single_tax - single.income_tax(2024, 30_000.0)).abs() < 1e-9);
+            assert!((2.0 * single_tax - couple.income_tax(2024, 60_000.0)).abs() < 1e-9);
+
+            // ω <fn test TaxJurisdiction::income_tax on FrTaxJurisdiction>
+        }
+
+        #[test]
+        fn wealth_tax() {
+            // α <fn test TaxJurisdiction::wealth_tax on FrTaxJurisdiction>
+
+            let fr = FrTaxJurisdiction {
+                household_parts: 2.0,
+                inflation: 0.0,
+            };
+            assert_eq!(0.0, fr.wealth_tax(2024, 1_299_999.0));
+            assert!((2_500.0 + 0.007 * 200_000.0 - fr.wealth_tax(2024, 1_500_000.0)).abs() < 1e-9);
+
+            // ω <fn test TaxJurisdiction::wealth_tax on FrTaxJurisdiction>
+        }
+
+        #[test]
+        fn withdrawal_tax() {
+            // α <fn test TaxJurisdiction::withdrawal_tax on FrTaxJurisdiction>
+
+            let fr = FrTaxJurisdiction {
+                household_parts: 1.0,
+                inflation: 0.0,
+            };
+            let withdrawal = |wrapper, years_held| WrapperWithdrawal {
+                wrapper,
+                amount: 20_000.0,
+                gain: 10_000.0,
+                years_held,
+            };
+            let tax = |wrapper, years_held| {
+                fr.withdrawal_tax(2024, &withdrawal(wrapper, years_held), 30_000.0)
+            };
+
+            assert!((3_000.0 - tax(TaxWrapper::FrPea, 4)).abs() < 1e-9);
+            assert!((1_720.0 - tax(TaxWrapper::FrPea, 5)).abs() < 1e-9);
+            assert!((3_000.0 - tax(TaxWrapper::FrAssuranceVie, 7)).abs() < 1e-9);
+            assert!((0.075 * 5_400.0 + 1_720.0 - tax(TaxWrapper::FrAssuranceVie, 8)).abs() < 1e-9);
+            assert!((3_000.0 - tax(TaxWrapper::FrCompteTitres, 20)).abs() < 1e-9);
+
+            // ω <fn test TaxJurisdiction::withdrawal_tax on FrTaxJurisdiction>
+        }
+
+        // α <mod-def test_tax_jurisdiction_on_fr_tax_jurisdiction>
+        use super::*;
+        // ω <mod-def test_tax_jurisdiction_on_fr_tax_jurisdiction>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def fr>
+// ω <mod-def fr>
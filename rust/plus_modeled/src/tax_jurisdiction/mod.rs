@@ -0,0 +1,272 @@
+//! Taxation of income, gains and wealth by country of residence.
+//!
+//! `tax_jurisdiction` selects the `TaxJurisdiction` of a `Country` of residence, which
+//! assesses the taxes of a year with `TaxJurisdiction::assess`. Nothing in the forecast
+//! calls it yet. Each jurisdiction also determines how withdrawals from its account
+//! wrappers are taxed, e.g. a `UkIsa` is tax free while a `FrAssuranceVie` is taxed on its
+//! gain at a rate depending on how long it was held. The non-US rules are simplified:
+//! thresholds of the 2024 tax year, no reliefs beyond the main allowances.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::AccountType;
+use crate::Country;
+use crate::Currency;
+use crate::TaxUsFilingStatus;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- pub module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+pub use self::fr::FrTaxJurisdiction;
+pub use self::uk::UkTaxJurisdiction;
+pub use self::us::UsTaxJurisdiction;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- mod decls ---
+////////////////////////////////////////////////////////////////////////////////////
+pub mod fr;
+pub mod uk;
+pub mod us;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// An account wrapper whose withdrawals a jurisdiction taxes by its own rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TaxWrapper {
+    /// A US account of the given type.
+    Us(AccountType),
+    /// UK _Individual Savings Account_ - growth and withdrawals are tax free.
+    UkIsa,
+    /// UK _Self Invested Personal Pension_ - 25% of withdrawals are tax free, the rest is income.
+    UkSipp,
+    /// UK _General Investment Account_ - gains are subject to capital gains tax.
+    UkGeneralInvestment,
+    /// French _Plan d'Épargne en Actions_ - gains only bear social charges after 5 years.
+    FrPea,
+    /// French _assurance-vie_ - gains are taxed at a reduced rate after 8 years.
+    FrAssuranceVie,
+    /// French _compte-titres ordinaire_ - gains bear the flat tax.
+    FrCompteTitres,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A withdrawal from a wrapper.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WrapperWithdrawal {
+    /// Wrapper withdrawn from.
+    pub wrapper: TaxWrapper,
+    /// Amount withdrawn.
+    pub amount: f64,
+    /// Portion of `amount` that is gain over contributions.
+    pub gain: f64,
+    /// Years since the wrapper was opened (or the contributions made).
+    pub years_held: u32,
+}
+
+/// Income and wealth of a household for a year, in the jurisdiction's currency.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxableIncome {
+    /// Income taxed at ordinary rates - wages, pensions, interest.
+    pub ordinary_income: f64,
+    /// Realized long term capital gains outside wrappers.
+    pub capital_gains: f64,
+    /// Net wealth subject to any wealth tax.
+    pub net_wealth: f64,
+}
+
+/// Taxes of a household for a year.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxAssessment {
+    /// Tax on ordinary income.
+    pub income_tax: f64,
+    /// Tax on capital gains.
+    pub capital_gains_tax: f64,
+    /// Tax on wealth.
+    pub wealth_tax: f64,
+    /// Tax on wrapper withdrawals.
+    pub withdrawal_tax: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- traits ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Taxes of a country of residence.
+pub trait TaxJurisdiction {
+    /// Country of the jurisdiction.
+    ///
+    ///   * _return_ - The country.
+    fn country(&self) -> Country;
+
+    /// Currency taxes are computed in.
+    ///
+    ///   * _return_ - The currency.
+    fn currency(&self) -> Currency;
+
+    /// Wrappers the jurisdiction provides.
+    ///
+    ///   * _return_ - The wrappers.
+    fn wrappers(&self) -> Vec<TaxWrapper>;
+
+    /// Tax on ordinary income.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **ordinary_income** - Gross ordinary income.
+    ///   * _return_ - The income tax.
+    fn income_tax(&self, year: u32, ordinary_income: f64) -> f64;
+
+    /// Tax on capital gains realized outside wrappers.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **capital_gains** - Realized gains.
+    ///   * **ordinary_income** - Ordinary income, which may determine the rate.
+    ///   * _return_ - The capital gains tax.
+    fn capital_gains_tax(&self, year: u32, capital_gains: f64, ordinary_income: f64) -> f64;
+
+    /// Tax on wealth.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **net_wealth** - Net wealth subject to the tax.
+    ///   * _return_ - The wealth tax.
+    fn wealth_tax(&self, year: u32, net_wealth: f64) -> f64;
+
+    /// Tax on a withdrawal from a wrapper.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **withdrawal** - The withdrawal.
+    ///   * **ordinary_income** - Other ordinary income of the year.
+    ///   * _return_ - The tax due on the withdrawal.
+    fn withdrawal_tax(
+        &self,
+        year: u32,
+        withdrawal: &WrapperWithdrawal,
+        ordinary_income: f64,
+    ) -> f64;
+
+    /// All taxes of a year.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **income** - Income and wealth of the year.
+    ///   * **withdrawals** - Withdrawals from wrappers, each taxed on top of ordinary income.
+    ///   * _return_ - The taxes.
+    fn assess(
+        &self,
+        year: u32,
+        income: &TaxableIncome,
+        withdrawals: &[WrapperWithdrawal],
+    ) -> TaxAssessment {
+        // α <fn TaxJurisdiction::assess>
+
+        TaxAssessment {
+            income_tax: self.income_tax(year, income.ordinary_income),
+            capital_gains_tax: self.capital_gains_tax(
+                year,
+                income.capital_gains,
+                income.ordinary_income,
+            ),
+            wealth_tax: self.wealth_tax(year, income.net_wealth),
+            withdrawal_tax: withdrawals
+                .iter()
+                .map(|withdrawal| self.withdrawal_tax(year, withdrawal, income.ordinary_income))
+                .sum(),
+        }
+
+        // ω <fn TaxJurisdiction::assess>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The jurisdiction for a country of residence with default settings.
+///
+///   * **country** - Country of residence.
+///   * **filing_status** - Filing status, used for the US and to count French household parts.
+///   * _return_ - The jurisdiction.
+pub fn tax_jurisdiction(
+    country: Country,
+    filing_status: TaxUsFilingStatus,
+) -> Box<dyn TaxJurisdiction> {
+    // α <fn tax_jurisdiction>
+
+    match country {
+        Country::UnitedStates => Box::new(UsTaxJurisdiction {
+            filing_status,
+            inflation: 0.0,
+        }),
+        Country::UnitedKingdom => Box::new(UkTaxJurisdiction),
+        Country::France => Box::new(FrTaxJurisdiction {
+            household_parts: if filing_status == TaxUsFilingStatus::MarriedJoint {
+                2.0
+            } else {
+                1.0
+            },
+            inflation: 0.0,
+        }),
+    }
+
+    // ω <fn tax_jurisdiction>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl TaxAssessment {
+    /// Sum of all taxes.
+    ///
+    ///   * _return_ - The total tax.
+    #[inline]
+    pub fn total(&self) -> f64 {
+        // α <fn TaxAssessment::total>
+        self.income_tax + self.capital_gains_tax + self.wealth_tax + self.withdrawal_tax
+        // ω <fn TaxAssessment::total>
+    }
+}
+
+/// Unit tests for `tax_jurisdiction`
+#[cfg(test)]
+pub mod unit_tests {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_tax_jurisdiction() {
+        // α <fn test_tax_jurisdiction>
+
+        let income = TaxableIncome {
+            ordinary_income: 60_000.0,
+            capital_gains: 10_000.0,
+            net_wealth: 500_000.0,
+        };
+
+        for country in [
+            Country::UnitedStates,
+            Country::UnitedKingdom,
+            Country::France,
+        ] {
+            let jurisdiction = tax_jurisdiction(country, TaxUsFilingStatus::Single);
+            assert_eq!(country, jurisdiction.country());
+
+            let assessment = jurisdiction.assess(2024, &income, &[]);
+            assert!(assessment.income_tax > 0.0);
+            assert!(assessment.capital_gains_tax > 0.0);
+            assert_eq!(0.0, assessment.wealth_tax);
+            assert_eq!(
+                assessment.income_tax + assessment.capital_gains_tax,
+                assessment.total()
+            );
+        }
+
+        // ω <fn test_tax_jurisdiction>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def tax_jurisdiction>
+// ω <mod-def tax_jurisdiction>
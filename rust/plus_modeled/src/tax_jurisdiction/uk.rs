@@ -0,0 +1,262 @@
+//! Simplified UK taxes for the 2024/25 tax year.
+//!
+//! Income tax bands and the personal allowance are frozen until 2028, so they are not
+//! indexed. Capital gains use the rates from 30 October 2024. There is no wealth tax.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use super::TaxJurisdiction;
+use super::TaxWrapper;
+use super::WrapperWithdrawal;
+use crate::tax_brackets::tax_on_income;
+use crate::tax_brackets::TaxBracket;
+use crate::Country;
+use crate::Currency;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Income free of tax, tapered away above `UK_PERSONAL_ALLOWANCE_TAPER_START`.
+pub const UK_PERSONAL_ALLOWANCE: f64 = 12_570.0;
+
+/// Income above which the personal allowance is reduced by £1 for every £2.
+pub const UK_PERSONAL_ALLOWANCE_TAPER_START: f64 = 100_000.0;
+
+/// Taxable income taxed at the basic rate.
+pub const UK_BASIC_RATE_BAND: f64 = 37_700.0;
+
+/// Gains free of capital gains tax each year.
+pub const UK_CAPITAL_GAINS_ANNUAL_EXEMPT_AMOUNT: f64 = 3_000.0;
+
+/// Portion of pension withdrawals that is tax free.
+pub const UK_PENSION_TAX_FREE_PORTION: f64 = 0.25;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// UK taxes of an individual.
+/// Foreign wrappers are not recognized and are taxed like a `UkGeneralInvestment` account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct UkTaxJurisdiction;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl UkTaxJurisdiction {
+    /// Income after the tapered personal allowance.
+    ///
+    ///   * **ordinary_income** - Gross ordinary income.
+    ///   * _return_ - The taxable income.
+    pub fn taxable_income(&self, ordinary_income: f64) -> f64 {
+        // α <fn UkTaxJurisdiction::taxable_income>
+
+        let allowance = (UK_PERSONAL_ALLOWANCE
+            - (ordinary_income - UK_PERSONAL_ALLOWANCE_TAPER_START).max(0.0) / 2.0)
+            .max(0.0);
+        (ordinary_income - allowance).max(0.0)
+
+        // ω <fn UkTaxJurisdiction::taxable_income>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl TaxJurisdiction for UkTaxJurisdiction {
+    /// Country of the jurisdiction.
+    ///
+    ///   * _return_ - The country.
+    fn country(&self) -> Country {
+        // α <fn TaxJurisdiction::country for UkTaxJurisdiction>
+        Country::UnitedKingdom
+        // ω <fn TaxJurisdiction::country for UkTaxJurisdiction>
+    }
+
+    /// Currency taxes are computed in.
+    ///
+    ///   * _return_ - The currency.
+    fn currency(&self) -> Currency {
+        // α <fn TaxJurisdiction::currency for UkTaxJurisdiction>
+        Currency::Gbp
+        // ω <fn TaxJurisdiction::currency for UkTaxJurisdiction>
+    }
+
+    /// Wrappers the jurisdiction provides.
+    ///
+    ///   * _return_ - The wrappers.
+    fn wrappers(&self) -> Vec<TaxWrapper> {
+        // α <fn TaxJurisdiction::wrappers for UkTaxJurisdiction>
+        vec![
+            TaxWrapper::UkIsa,
+            TaxWrapper::UkSipp,
+            TaxWrapper::UkGeneralInvestment,
+        ]
+        // ω <fn TaxJurisdiction::wrappers for UkTaxJurisdiction>
+    }
+
+    /// Tax on ordinary income at the basic (20%), higher (40%) and additional (45%) rates.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **ordinary_income** - Gross ordinary income.
+    ///   * _return_ - The income tax.
+    fn income_tax(&self, _year: u32, ordinary_income: f64) -> f64 {
+        // α <fn TaxJurisdiction::income_tax for UkTaxJurisdiction>
+
+        tax_on_income(
+            self.taxable_income(ordinary_income),
+            &[
+                TaxBracket {
+                    start: 0.0,
+                    rate: 0.20,
+                },
+                TaxBracket {
+                    start: UK_BASIC_RATE_BAND,
+                    rate: 0.40,
+                },
+                TaxBracket {
+                    start: 125_140.0,
+                    rate: 0.45,
+                },
+            ],
+        )
+
+        // ω <fn TaxJurisdiction::income_tax for UkTaxJurisdiction>
+    }
+
+    /// Tax on gains above the annual exempt amount, at 18% within any unused basic
+    /// rate band and 24% above.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **capital_gains** - Realized gains.
+    ///   * **ordinary_income** - Ordinary income, which determines the unused basic rate band.
+    ///   * _return_ - The capital gains tax.
+    fn capital_gains_tax(&self, _year: u32, capital_gains: f64, ordinary_income: f64) -> f64 {
+        // α <fn TaxJurisdiction::capital_gains_tax for UkTaxJurisdiction>
+
+        let taxable_gains = (capital_gains - UK_CAPITAL_GAINS_ANNUAL_EXEMPT_AMOUNT).max(0.0);
+        let unused_basic_band =
+            (UK_BASIC_RATE_BAND - self.taxable_income(ordinary_income)).max(0.0);
+        let basic_rate_gains = taxable_gains.min(unused_basic_band);
+        basic_rate_gains * 0.18 + (taxable_gains - basic_rate_gains) * 0.24
+
+        // ω <fn TaxJurisdiction::capital_gains_tax for UkTaxJurisdiction>
+    }
+
+    /// There is no UK wealth tax.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **net_wealth** - Net wealth.
+    ///   * _return_ - Always 0.
+    fn wealth_tax(&self, _year: u32, _net_wealth: f64) -> f64 {
+        // α <fn TaxJurisdiction::wealth_tax for UkTaxJurisdiction>
+        0.0
+        // ω <fn TaxJurisdiction::wealth_tax for UkTaxJurisdiction>
+    }
+
+    /// Tax on a withdrawal. ISA withdrawals are tax free and 75% of SIPP withdrawals
+    /// are taxed as income.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **withdrawal** - The withdrawal.
+    ///   * **ordinary_income** - Other ordinary income of the year.
+    ///   * _return_ - The tax due on the withdrawal.
+    fn withdrawal_tax(
+        &self,
+        year: u32,
+        withdrawal: &WrapperWithdrawal,
+        ordinary_income: f64,
+    ) -> f64 {
+        // α <fn TaxJurisdiction::withdrawal_tax for UkTaxJurisdiction>
+
+        match withdrawal.wrapper {
+            TaxWrapper::UkIsa => 0.0,
+            TaxWrapper::UkSipp => {
+                let taxable = withdrawal.amount * (1.0 - UK_PENSION_TAX_FREE_PORTION);
+                self.income_tax(year, ordinary_income + taxable)
+                    - self.income_tax(year, ordinary_income)
+            }
+            _ => self.capital_gains_tax(year, withdrawal.gain, ordinary_income),
+        }
+
+        // ω <fn TaxJurisdiction::withdrawal_tax for UkTaxJurisdiction>
+    }
+}
+
+/// Unit tests for `uk`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test trait tax_jurisdiction on UkTaxJurisdiction
+    pub mod test_tax_jurisdiction_on_uk_tax_jurisdiction {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn income_tax() {
+            // α <fn test TaxJurisdiction::income_tax on UkTaxJurisdiction>
+
+            let uk = UkTaxJurisdiction;
+            assert_eq!(0.0, uk.income_tax(2024, 12_570.0));
+            assert_eq!(0.2 * 37_700.0, uk.income_tax(2024, 50_270.0));
+            // Allowance is fully tapered away at 125_140
+            assert_eq!(
+                0.2 * 37_700.0 + 0.4 * (125_140.0 - 37_700.0),
+                uk.income_tax(2024, 125_140.0)
+            );
+
+            // ω <fn test TaxJurisdiction::income_tax on UkTaxJurisdiction>
+        }
+
+        #[test]
+        fn withdrawal_tax() {
+            // α <fn test TaxJurisdiction::withdrawal_tax on UkTaxJurisdiction>
+
+            let uk = UkTaxJurisdiction;
+            let withdrawal = |wrapper| WrapperWithdrawal {
+                wrapper,
+                amount: 20_000.0,
+                gain: 8_000.0,
+                years_held: 10,
+            };
+
+            assert_eq!(
+                0.0,
+                uk.withdrawal_tax(2024, &withdrawal(TaxWrapper::UkIsa), 30_000.0)
+            );
+            assert_eq!(
+                0.2 * 15_000.0,
+                uk.withdrawal_tax(2024, &withdrawal(TaxWrapper::UkSipp), 30_000.0)
+            );
+            // 30_000 income leaves 20_270 of basic band, so all 5_000 taxable gains at 18%
+            assert!(
+                (0.18 * 5_000.0
+                    - uk.withdrawal_tax(
+                        2024,
+                        &withdrawal(TaxWrapper::UkGeneralInvestment),
+                        30_000.0
+                    ))
+                .abs()
+                    < 1e-9
+            );
+
+            // ω <fn test TaxJurisdiction::withdrawal_tax on UkTaxJurisdiction>
+        }
+
+        // α <mod-def test_tax_jurisdiction_on_uk_tax_jurisdiction>
+        use super::*;
+        // ω <mod-def test_tax_jurisdiction_on_uk_tax_jurisdiction>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def uk>
+// ω <mod-def uk>
@@ -0,0 +1,262 @@
+//! US federal taxes, using the brackets of a `TaxUsFilingStatus`.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use super::TaxJurisdiction;
+use super::TaxWrapper;
+use super::WrapperWithdrawal;
+use crate::tax_brackets::tax_on_income;
+use crate::AccountType;
+use crate::Country;
+use crate::Currency;
+use crate::TaxUsFilingStatus;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// US federal taxes. There is no federal wealth tax.
+/// Foreign wrappers are not recognized and are taxed like `Taxable` accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UsTaxJurisdiction {
+    /// Filing status of the household.
+    pub filing_status: TaxUsFilingStatus,
+    /// Assumed annual indexing of brackets and deductions.
+    pub inflation: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl UsTaxJurisdiction {
+    /// Taxable income after the standard deduction.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **ordinary_income** - Gross ordinary income.
+    ///   * _return_ - The taxable income and any deduction left unused.
+    fn taxable_income(&self, year: u32, ordinary_income: f64) -> (f64, f64) {
+        // α <fn UsTaxJurisdiction::taxable_income>
+
+        let deduction = self.filing_status.standard_deduction(year, self.inflation);
+        (
+            (ordinary_income - deduction).max(0.0),
+            (deduction - ordinary_income).max(0.0),
+        )
+
+        // ω <fn UsTaxJurisdiction::taxable_income>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl TaxJurisdiction for UsTaxJurisdiction {
+    /// Country of the jurisdiction.
+    ///
+    ///   * _return_ - The country.
+    fn country(&self) -> Country {
+        // α <fn TaxJurisdiction::country for UsTaxJurisdiction>
+        Country::UnitedStates
+        // ω <fn TaxJurisdiction::country for UsTaxJurisdiction>
+    }
+
+    /// Currency taxes are computed in.
+    ///
+    ///   * _return_ - The currency.
+    fn currency(&self) -> Currency {
+        // α <fn TaxJurisdiction::currency for UsTaxJurisdiction>
+        Currency::Usd
+        // ω <fn TaxJurisdiction::currency for UsTaxJurisdiction>
+    }
+
+    /// Wrappers the jurisdiction provides.
+    ///
+    ///   * _return_ - The wrappers.
+    fn wrappers(&self) -> Vec<TaxWrapper> {
+        // α <fn TaxJurisdiction::wrappers for UsTaxJurisdiction>
+
+        [
+            AccountType::Taxable,
+            AccountType::RothIrs401K,
+            AccountType::TraditionalIrs401K,
+            AccountType::CollegeIrs529,
+            AccountType::TraditionalIra,
+            AccountType::Demand,
+            AccountType::HealthSavingsAccount,
+            AccountType::OtherAccountType,
+        ]
+        .into_iter()
+        .map(TaxWrapper::Us)
+        .collect()
+
+        // ω <fn TaxJurisdiction::wrappers for UsTaxJurisdiction>
+    }
+
+    /// Tax on ordinary income.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **ordinary_income** - Gross ordinary income.
+    ///   * _return_ - The income tax.
+    fn income_tax(&self, year: u32, ordinary_income: f64) -> f64 {
+        // α <fn TaxJurisdiction::income_tax for UsTaxJurisdiction>
+
+        tax_on_income(
+            self.taxable_income(year, ordinary_income).0,
+            &self
+                .filing_status
+                .ordinary_income_brackets(year, self.inflation),
+        )
+
+        // ω <fn TaxJurisdiction::income_tax for UsTaxJurisdiction>
+    }
+
+    /// Tax on long term capital gains, stacked on top of ordinary taxable income.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **capital_gains** - Realized gains.
+    ///   * **ordinary_income** - Ordinary income, which determines the rate.
+    ///   * _return_ - The capital gains tax.
+    fn capital_gains_tax(&self, year: u32, capital_gains: f64, ordinary_income: f64) -> f64 {
+        // α <fn TaxJurisdiction::capital_gains_tax for UsTaxJurisdiction>
+
+        let (taxable_income, unused_deduction) = self.taxable_income(year, ordinary_income);
+        let taxable_gains = (capital_gains - unused_deduction).max(0.0);
+        let brackets = self
+            .filing_status
+            .long_term_capital_gains_brackets(year, self.inflation);
+
+        tax_on_income(taxable_income + taxable_gains, &brackets)
+            - tax_on_income(taxable_income, &brackets)
+
+        // ω <fn TaxJurisdiction::capital_gains_tax for UsTaxJurisdiction>
+    }
+
+    /// There is no federal wealth tax.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **net_wealth** - Net wealth.
+    ///   * _return_ - Always 0.
+    fn wealth_tax(&self, _year: u32, _net_wealth: f64) -> f64 {
+        // α <fn TaxJurisdiction::wealth_tax for UsTaxJurisdiction>
+        0.0
+        // ω <fn TaxJurisdiction::wealth_tax for UsTaxJurisdiction>
+    }
+
+    /// Tax on a withdrawal. Traditional accounts are taxed as ordinary income, while
+    /// Roth, 529 and HSA withdrawals are assumed qualified and tax free.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **withdrawal** - The withdrawal.
+    ///   * **ordinary_income** - Other ordinary income of the year.
+    ///   * _return_ - The tax due on the withdrawal.
+    fn withdrawal_tax(
+        &self,
+        year: u32,
+        withdrawal: &WrapperWithdrawal,
+        ordinary_income: f64,
+    ) -> f64 {
+        // α <fn TaxJurisdiction::withdrawal_tax for UsTaxJurisdiction>
+
+        match withdrawal.wrapper {
+            TaxWrapper::Us(AccountType::TraditionalIrs401K | AccountType::TraditionalIra) => {
+                self.income_tax(year, ordinary_income + withdrawal.amount)
+                    - self.income_tax(year, ordinary_income)
+            }
+            TaxWrapper::Us(
+                AccountType::RothIrs401K
+                | AccountType::CollegeIrs529
+                | AccountType::HealthSavingsAccount
+                | AccountType::Demand,
+            ) => 0.0,
+            _ => self.capital_gains_tax(year, withdrawal.gain, ordinary_income),
+        }
+
+        // ω <fn TaxJurisdiction::withdrawal_tax for UsTaxJurisdiction>
+    }
+}
+
+/// Unit tests for `us`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test trait tax_jurisdiction on UsTaxJurisdiction
+    pub mod test_tax_jurisdiction_on_us_tax_jurisdiction {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn capital_gains_tax() {
+            // α <fn test TaxJurisdiction::capital_gains_tax on UsTaxJurisdiction>
+
+            let us = UsTaxJurisdiction {
+                filing_status: TaxUsFilingStatus::Single,
+                inflation: 0.0,
+            };
+
+            // Unused deduction shelters gains, then 0% up to 47_025
+            assert_eq!(0.0, us.capital_gains_tax(2024, 40_000.0, 10_000.0));
+            // 14_600 deduction leaves 45_400 ordinary, 1_625 at 0% and the rest at 15%
+            assert_eq!(
+                0.15 * 8_375.0,
+                us.capital_gains_tax(2024, 10_000.0, 60_000.0)
+            );
+
+            // ω <fn test TaxJurisdiction::capital_gains_tax on UsTaxJurisdiction>
+        }
+
+        #[test]
+        fn withdrawal_tax() {
+            // α <fn test TaxJurisdiction::withdrawal_tax on UsTaxJurisdiction>
+
+            let us = UsTaxJurisdiction {
+                filing_status: TaxUsFilingStatus::Single,
+                inflation: 0.0,
+            };
+            let withdrawal = |wrapper| WrapperWithdrawal {
+                wrapper,
+                amount: 10_000.0,
+                gain: 4_000.0,
+                years_held: 10,
+            };
+
+            assert_eq!(
+                us.income_tax(2024, 70_000.0) - us.income_tax(2024, 60_000.0),
+                us.withdrawal_tax(
+                    2024,
+                    &withdrawal(TaxWrapper::Us(AccountType::TraditionalIra)),
+                    60_000.0
+                )
+            );
+            assert_eq!(
+                0.0,
+                us.withdrawal_tax(
+                    2024,
+                    &withdrawal(TaxWrapper::Us(AccountType::RothIrs401K)),
+                    60_000.0
+                )
+            );
+            assert_eq!(
+                0.15 * 4_000.0,
+                us.withdrawal_tax(2024, &withdrawal(TaxWrapper::UkIsa), 80_000.0)
+            );
+
+            // ω <fn test TaxJurisdiction::withdrawal_tax on UsTaxJurisdiction>
+        }
+
+        // α <mod-def test_tax_jurisdiction_on_us_tax_jurisdiction>
+        use super::*;
+        // ω <mod-def test_tax_jurisdiction_on_us_tax_jurisdiction>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def us>
+// ω <mod-def us>
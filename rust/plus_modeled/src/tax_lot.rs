@@ -0,0 +1,338 @@
+//! Tax lots and cost basis of taxable holdings.
+//!
+//! Each purchase of a holding in a `Taxable` account opens a lot recording when it was
+//! acquired and its cost. Sales close lots by the chosen `CostBasisMethod`, realizing a
+//! gain that is long term (`UsLongTermCapitalGain`) when the lot was held more than one
+//! year and short term (taxed as `UsOrdinaryIncome`) otherwise. At the `Death` marker
+//! the basis of all lots steps up to market value and the lots become long term.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::Date;
+use crate::TaxUsCategory;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// How lots are chosen and their basis determined when selling.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CostBasisMethod {
+    /// Oldest lots are sold first.
+    Fifo,
+    /// The identified lots are sold, in the order given.
+    SpecificId(Vec<u32>),
+    /// Basis is the average cost of all units; lots are sold oldest first for holding periods.
+    AverageCost,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Units of a holding acquired together.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxLot {
+    /// Identifies the lot within its holding.
+    pub id: u32,
+    /// Date the units were acquired.
+    pub acquired: Date,
+    /// Number of units remaining in the lot.
+    pub quantity: f64,
+    /// Total cost basis of the remaining units.
+    pub cost_basis: f64,
+    /// Set when the basis was stepped up on inheritance, making the lot long term.
+    pub inherited: bool,
+}
+
+/// The open lots of a single holding.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxLots {
+    /// Open lots, in order of purchase.
+    pub lots: Vec<TaxLot>,
+    /// Id of the next lot opened.
+    pub next_id: u32,
+}
+
+/// Gain realized by selling units of a lot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealizedGain {
+    /// Id of the lot sold from.
+    pub lot_id: u32,
+    /// Date the lot was acquired.
+    pub acquired: Date,
+    /// Date of the sale.
+    pub sold: Date,
+    /// Number of units sold.
+    pub quantity: f64,
+    /// Proceeds of the sale.
+    pub proceeds: f64,
+    /// Cost basis of the units sold.
+    pub cost_basis: f64,
+    /// How the gain is taxed - long term capital gain or ordinary income.
+    pub tax_category: TaxUsCategory,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Determines if units held from `acquired` through `sold` are held long term,
+/// that is more than one year.
+///
+///   * **acquired** - Date acquired.
+///   * **sold** - Date sold.
+///   * _return_ - True if long term.
+pub fn is_long_term(acquired: &Date, sold: &Date) -> bool {
+    // α <fn is_long_term>
+    (sold.year, sold.month, sold.day) > (acquired.year + 1, acquired.month, acquired.day)
+    // ω <fn is_long_term>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl TaxLots {
+    /// Open a lot for a purchase.
+    ///
+    ///   * **acquired** - Date of the purchase.
+    ///   * **quantity** - Units bought.
+    ///   * **cost** - Total cost, including fees.
+    ///   * _return_ - Id of the new lot.
+    pub fn buy(&mut self, acquired: Date, quantity: f64, cost: f64) -> u32 {
+        // α <fn TaxLots::buy>
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.lots.push(TaxLot {
+            id,
+            acquired,
+            quantity,
+            cost_basis: cost,
+            inherited: false,
+        });
+        id
+
+        // ω <fn TaxLots::buy>
+    }
+
+    /// Sell units, closing lots by `method`.
+    /// At most the quantity held (or held in the identified lots) is sold.
+    ///
+    ///   * **sold** - Date of the sale.
+    ///   * **quantity** - Units to sell.
+    ///   * **price** - Sale price per unit, net of fees.
+    ///   * **method** - Method choosing lots and basis.
+    ///   * _return_ - Gains realized, one per lot sold from.
+    pub fn sell(
+        &mut self,
+        sold: &Date,
+        quantity: f64,
+        price: f64,
+        method: &CostBasisMethod,
+    ) -> Vec<RealizedGain> {
+        // α <fn TaxLots::sell>
+
+        let order = match method {
+            CostBasisMethod::Fifo => self.lots.iter().map(|lot| lot.id).collect(),
+            CostBasisMethod::SpecificId(ids) => ids.clone(),
+            CostBasisMethod::AverageCost => {
+                let average_cost = self.cost_basis() / self.quantity();
+                self.lots
+                    .iter_mut()
+                    .for_each(|lot| lot.cost_basis = lot.quantity * average_cost);
+                self.lots.iter().map(|lot| lot.id).collect::<Vec<_>>()
+            }
+        };
+
+        let mut remaining = quantity;
+        let mut gains = Vec::new();
+        for id in order {
+            if remaining <= 0.0 {
+                break;
+            }
+            if let Some(lot) = self.lots.iter_mut().find(|lot| lot.id == id) {
+                let sold_quantity = remaining.min(lot.quantity);
+                let cost_basis = lot.cost_basis * sold_quantity / lot.quantity;
+                lot.quantity -= sold_quantity;
+                lot.cost_basis -= cost_basis;
+                remaining -= sold_quantity;
+                gains.push(RealizedGain {
+                    lot_id: lot.id,
+                    acquired: lot.acquired.clone(),
+                    sold: sold.clone(),
+                    quantity: sold_quantity,
+                    proceeds: sold_quantity * price,
+                    cost_basis,
+                    tax_category: if lot.inherited || is_long_term(&lot.acquired, sold) {
+                        TaxUsCategory::UsLongTermCapitalGain
+                    } else {
+                        TaxUsCategory::UsOrdinaryIncome
+                    },
+                });
+            }
+        }
+        self.lots.retain(|lot| lot.quantity > 0.0);
+
+        gains
+
+        // ω <fn TaxLots::sell>
+    }
+
+    /// Step up the basis of all lots to market value, as at the `Death` marker.
+    ///
+    ///   * **price** - Market price per unit at the date of death.
+    pub fn step_up_basis(&mut self, price: f64) {
+        // α <fn TaxLots::step_up_basis>
+
+        self.lots.iter_mut().for_each(|lot| {
+            lot.cost_basis = lot.quantity * price;
+            lot.inherited = true;
+        });
+
+        // ω <fn TaxLots::step_up_basis>
+    }
+
+    /// Total units held.
+    ///
+    ///   * _return_ - Sum of lot quantities.
+    pub fn quantity(&self) -> f64 {
+        // α <fn TaxLots::quantity>
+        self.lots.iter().map(|lot| lot.quantity).sum()
+        // ω <fn TaxLots::quantity>
+    }
+
+    /// Total cost basis of the units held.
+    ///
+    ///   * _return_ - Sum of lot cost bases.
+    pub fn cost_basis(&self) -> f64 {
+        // α <fn TaxLots::cost_basis>
+        self.lots.iter().map(|lot| lot.cost_basis).sum()
+        // ω <fn TaxLots::cost_basis>
+    }
+
+    /// Gain that would be realized selling all units at `price`.
+    ///
+    ///   * **price** - Market price per unit.
+    ///   * _return_ - Market value less cost basis.
+    pub fn unrealized_gain(&self, price: f64) -> f64 {
+        // α <fn TaxLots::unrealized_gain>
+        self.quantity() * price - self.cost_basis()
+        // ω <fn TaxLots::unrealized_gain>
+    }
+}
+
+impl RealizedGain {
+    /// The gain (or loss if negative) realized.
+    ///
+    ///   * _return_ - Proceeds less cost basis.
+    #[inline]
+    pub fn gain(&self) -> f64 {
+        // α <fn RealizedGain::gain>
+        self.proceeds - self.cost_basis
+        // ω <fn RealizedGain::gain>
+    }
+}
+
+/// Unit tests for `tax_lot`
+#[cfg(test)]
+pub mod unit_tests {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_is_long_term() {
+        // α <fn test_is_long_term>
+        assert!(!is_long_term(&date(2023, 3, 15), &date(2024, 3, 15)));
+        assert!(is_long_term(&date(2023, 3, 15), &date(2024, 3, 16)));
+        assert!(is_long_term(&date(2023, 12, 31), &date(2025, 1, 1)));
+        // ω <fn test_is_long_term>
+    }
+
+    /// Test type TaxLots
+    mod test_tax_lots {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn sell() {
+            // α <fn test TaxLots::sell>
+
+            let sale_date = date(2024, 6, 1);
+
+            let mut fifo = sample_lots();
+            let gains = fifo.sell(&sale_date, 150.0, 30.0, &CostBasisMethod::Fifo);
+            assert_eq!(2, gains.len());
+            assert_eq!(3_000.0 - 1_000.0, gains[0].gain());
+            assert_eq!(TaxUsCategory::UsLongTermCapitalGain, gains[0].tax_category);
+            assert_eq!(1_500.0 - 1_000.0, gains[1].gain());
+            assert_eq!(TaxUsCategory::UsOrdinaryIncome, gains[1].tax_category);
+            assert_eq!(50.0, fifo.quantity());
+            assert_eq!(1_000.0, fifo.cost_basis());
+
+            let mut specific = sample_lots();
+            let gains = specific.sell(
+                &sale_date,
+                50.0,
+                30.0,
+                &CostBasisMethod::SpecificId(vec![1]),
+            );
+            assert_eq!(1, gains.len());
+            assert_eq!(1, gains[0].lot_id);
+            assert_eq!(1_500.0 - 1_000.0, gains[0].gain());
+
+            let mut average = sample_lots();
+            let gains = average.sell(&sale_date, 100.0, 30.0, &CostBasisMethod::AverageCost);
+            assert_eq!(1, gains.len());
+            assert_eq!(0, gains[0].lot_id);
+            assert_eq!(3_000.0 - 1_500.0, gains[0].gain());
+            assert_eq!(1_500.0, average.cost_basis());
+
+            // ω <fn test TaxLots::sell>
+        }
+
+        #[test]
+        fn step_up_basis() {
+            // α <fn test TaxLots::step_up_basis>
+
+            let mut lots = sample_lots();
+            assert_eq!(6_000.0 - 3_000.0, lots.unrealized_gain(30.0));
+
+            lots.step_up_basis(30.0);
+            assert_eq!(0.0, lots.unrealized_gain(30.0));
+
+            let gains = lots.sell(&date(2024, 6, 1), 200.0, 31.0, &CostBasisMethod::Fifo);
+            assert!(gains
+                .iter()
+                .all(|gain| gain.tax_category == TaxUsCategory::UsLongTermCapitalGain));
+            assert_eq!(200.0, gains.iter().map(|gain| gain.gain()).sum::<f64>());
+
+            // ω <fn test TaxLots::step_up_basis>
+        }
+
+        fn sample_lots() -> TaxLots {
+            let mut lots = TaxLots::default();
+            lots.buy(date(2020, 1, 10), 100.0, 1_000.0);
+            lots.buy(date(2024, 1, 10), 100.0, 2_000.0);
+            lots
+        }
+
+        // α <mod-def test_tax_lots>
+        use super::*;
+        // ω <mod-def test_tax_lots>
+    }
+
+    fn date(year: u32, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def tax_lot>
+// ω <mod-def tax_lot>
@@ -0,0 +1,165 @@
+//! US federal ordinary income tax brackets and standard deductions by filing status.
+//!
+//! Brackets are those of tax year 2024. Later (earlier) years are approximated by
+//! indexing the thresholds with an assumed inflation, as the IRS does annually.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::TaxBracket;
+use crate::TaxUsFilingStatus;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Tax year of the bracket thresholds and deductions.
+pub const TAX_US_BRACKETS_YEAR: u32 = 2024;
+
+/// Marginal rates of the federal ordinary income brackets.
+pub const TAX_US_ORDINARY_RATES: [f64; 7] = [0.10, 0.12, 0.22, 0.24, 0.32, 0.35, 0.37];
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Factor indexing `TAX_US_BRACKETS_YEAR` amounts to `year`.
+///
+///   * **year** - Year to index to.
+///   * **inflation** - Assumed annual inflation.
+///   * _return_ - The compounded inflation factor.
+#[inline]
+fn index_factor(year: u32, inflation: f64) -> f64 {
+    // α <fn index_factor>
+    (1.0 + inflation).powi(year as i32 - TAX_US_BRACKETS_YEAR as i32)
+    // ω <fn index_factor>
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl TaxUsFilingStatus {
+    /// Federal ordinary income brackets for `year`.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **inflation** - Assumed annual indexing of thresholds from `TAX_US_BRACKETS_YEAR`.
+    ///   * _return_ - The brackets, sorted by `start`.
+    pub fn ordinary_income_brackets(&self, year: u32, inflation: f64) -> Vec<TaxBracket> {
+        // α <fn TaxUsFilingStatus::ordinary_income_brackets>
+
+        let thresholds: [f64; 6] = match self {
+            TaxUsFilingStatus::MarriedJoint => [
+                23_200.0, 94_300.0, 201_050.0, 383_900.0, 487_450.0, 731_200.0,
+            ],
+            TaxUsFilingStatus::MarriedSeparate => [
+                11_600.0, 47_150.0, 100_525.0, 191_950.0, 243_725.0, 365_600.0,
+            ],
+            TaxUsFilingStatus::Single => [
+                11_600.0, 47_150.0, 100_525.0, 191_950.0, 243_725.0, 609_350.0,
+            ],
+            TaxUsFilingStatus::HeadOfHousehold => [
+                16_550.0, 63_100.0, 100_500.0, 191_950.0, 243_700.0, 609_350.0,
+            ],
+        };
+        let index = index_factor(year, inflation);
+
+        TAX_US_ORDINARY_RATES
+            .iter()
+            .enumerate()
+            .map(|(i, &rate)| TaxBracket {
+                start: if i == 0 {
+                    0.0
+                } else {
+                    (thresholds[i - 1] * index).round()
+                },
+                rate,
+            })
+            .collect()
+
+        // ω <fn TaxUsFilingStatus::ordinary_income_brackets>
+    }
+
+    /// Federal long term capital gains brackets for `year`, applied to taxable
+    /// income with gains stacked on top of ordinary income.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **inflation** - Assumed annual indexing of thresholds from `TAX_US_BRACKETS_YEAR`.
+    ///   * _return_ - The 0%, 15% and 20% brackets.
+    pub fn long_term_capital_gains_brackets(&self, year: u32, inflation: f64) -> Vec<TaxBracket> {
+        // α <fn TaxUsFilingStatus::long_term_capital_gains_brackets>
+
+        let (fifteen, twenty) = match self {
+            TaxUsFilingStatus::MarriedJoint => (94_050.0, 583_750.0),
+            TaxUsFilingStatus::MarriedSeparate => (47_025.0, 291_850.0),
+            TaxUsFilingStatus::Single => (47_025.0, 518_900.0),
+            TaxUsFilingStatus::HeadOfHousehold => (63_000.0, 551_350.0),
+        };
+        let index = index_factor(year, inflation);
+
+        vec![
+            TaxBracket {
+                start: 0.0,
+                rate: 0.0,
+            },
+            TaxBracket {
+                start: (fifteen * index).round(),
+                rate: 0.15,
+            },
+            TaxBracket {
+                start: (twenty * index).round(),
+                rate: 0.20,
+            },
+        ]
+
+        // ω <fn TaxUsFilingStatus::long_term_capital_gains_brackets>
+    }
+
+    /// Federal standard deduction for `year`.
+    ///
+    ///   * **year** - Tax year.
+    ///   * **inflation** - Assumed annual indexing from `TAX_US_BRACKETS_YEAR`.
+    ///   * _return_ - The standard deduction.
+    pub fn standard_deduction(&self, year: u32, inflation: f64) -> f64 {
+        // α <fn TaxUsFilingStatus::standard_deduction>
+
+        let deduction = match self {
+            TaxUsFilingStatus::MarriedJoint => 29_200.0,
+            TaxUsFilingStatus::MarriedSeparate | TaxUsFilingStatus::Single => 14_600.0,
+            TaxUsFilingStatus::HeadOfHousehold => 21_900.0,
+        };
+        (deduction * index_factor(year, inflation)).round()
+
+        // ω <fn TaxUsFilingStatus::standard_deduction>
+    }
+}
+
+/// Unit tests for `tax_us_brackets`
+#[cfg(test)]
+pub mod unit_tests {
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_ordinary_income_brackets() {
+        // α <fn test_ordinary_income_brackets>
+
+        let brackets = TaxUsFilingStatus::Single.ordinary_income_brackets(2024, 0.0);
+        assert_eq!(TAX_US_ORDINARY_RATES.len(), brackets.len());
+        assert_eq!(0.0, brackets[0].start);
+        assert_eq!(47_150.0, brackets[2].start);
+
+        let indexed = TaxUsFilingStatus::MarriedJoint.ordinary_income_brackets(2025, 0.1);
+        assert_eq!(25_520.0, indexed[1].start);
+        assert_eq!(
+            32_120.0,
+            TaxUsFilingStatus::MarriedJoint.standard_deduction(2025, 0.1)
+        );
+
+        // ω <fn test_ordinary_income_brackets>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def tax_us_brackets>
+// ω <mod-def tax_us_brackets>
@@ -0,0 +1,172 @@
+//! Time value of money over dated cash flows.
+//!
+//! Flows are `YearCurrencyValue` with positive values received and negative values paid.
+//! A flow in year `y` is discounted `y - valuation_year` years, so flows in the valuation
+//! year are taken at face value. All flows must be in one currency - convert them first.
+//! The discounting of flows by a `RateCurve` is on `RateCurve` itself.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::CurrencyValue;
+use crate::YearCurrencyValue;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Net present value of flows discounted at a constant rate.
+///
+///   * **flows** - The cash flows, in any order.
+///   * **rate** - Annual discount rate.
+///   * **valuation_year** - Year the value is stated in.
+///   * _return_ - The NPV, `None` if no flows or flows in more than one currency.
+pub fn net_present_value(
+    flows: &[YearCurrencyValue],
+    rate: f64,
+    valuation_year: u32,
+) -> Option<CurrencyValue> {
+    // α <fn net_present_value>
+
+    let currency = flows_currency(flows)?;
+    Some(CurrencyValue {
+        currency,
+        value: plus_utils::net_present_value(rate, &timed_values(flows, valuation_year)),
+    })
+
+    // ω <fn net_present_value>
+}
+
+/// Internal rate of return of flows (the XIRR of annual flows).
+///
+///   * **flows** - The cash flows, in any order.
+///   * _return_ - Annual rate at which the NPV is zero, `None` if mixed currencies or no such rate.
+pub fn internal_rate_of_return(flows: &[YearCurrencyValue]) -> Option<f64> {
+    // α <fn internal_rate_of_return>
+
+    flows_currency(flows)?;
+    let first_year = flows.iter().map(|flow| flow.year).min()?;
+    plus_utils::internal_rate_of_return(&timed_values(flows, first_year), 0.1)
+
+    // ω <fn internal_rate_of_return>
+}
+
+/// The single currency of flows.
+///
+///   * **flows** - The cash flows.
+///   * _return_ - Currency of all flows, `None` if no flows or more than one currency.
+pub fn flows_currency(flows: &[YearCurrencyValue]) -> Option<i32> {
+    // α <fn flows_currency>
+
+    let currency = flows.first()?.currency;
+    flows
+        .iter()
+        .all(|flow| flow.currency == currency)
+        .then_some(currency)
+
+    // ω <fn flows_currency>
+}
+
+/// Flows as `(years from valuation_year, value)`.
+///
+///   * **flows** - The cash flows.
+///   * **valuation_year** - Year of time zero.
+///   * _return_ - The timed values.
+fn timed_values(flows: &[YearCurrencyValue], valuation_year: u32) -> Vec<(f64, f64)> {
+    // α <fn timed_values>
+    flows
+        .iter()
+        .map(|flow| (flow.year as f64 - valuation_year as f64, flow.value))
+        .collect()
+    // ω <fn timed_values>
+}
+
+/// Unit tests for `time_value`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use crate::Currency;
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_net_present_value() {
+        // α <fn test_net_present_value>
+
+        let flows = usd_flows(&[
+            (2024, -1_000.0),
+            (2025, 300.0),
+            (2026, 400.0),
+            (2027, 500.0),
+        ]);
+        let npv = net_present_value(&flows, 0.1, 2024).unwrap();
+        assert_eq!(Currency::Usd as i32, npv.currency);
+        assert!((npv.value - (-21.0368)).abs() < 1e-4);
+
+        // Stated a year later the value is a year's growth higher
+        let npv_later = net_present_value(&flows, 0.1, 2025).unwrap();
+        assert!((npv_later.value - npv.value * 1.1).abs() < 1e-9);
+
+        let mut mixed = flows.clone();
+        mixed[1].currency = Currency::Eur as i32;
+        assert_eq!(None, net_present_value(&mixed, 0.1, 2024));
+        assert_eq!(None, net_present_value(&[], 0.1, 2024));
+
+        // ω <fn test_net_present_value>
+    }
+
+    #[test]
+    fn test_internal_rate_of_return() {
+        // α <fn test_internal_rate_of_return>
+
+        let flows = usd_flows(&[
+            (2027, 500.0),
+            (2024, -1_000.0),
+            (2026, 400.0),
+            (2025, 300.0),
+        ]);
+        let irr = internal_rate_of_return(&flows).unwrap();
+        assert!((irr - 0.0889633947).abs() < 1e-9);
+        assert!(net_present_value(&flows, irr, 2024).unwrap().value.abs() < 1e-9);
+
+        assert_eq!(None, internal_rate_of_return(&usd_flows(&[(2024, 100.0)])));
+        assert_eq!(None, internal_rate_of_return(&[]));
+
+        // ω <fn test_internal_rate_of_return>
+    }
+
+    #[test]
+    fn test_flows_currency() {
+        // α <fn test_flows_currency>
+        let flows = usd_flows(&[(2024, 1.0), (2025, 2.0)]);
+        assert_eq!(Some(Currency::Usd as i32), flows_currency(&flows));
+        assert_eq!(None, flows_currency(&[]));
+        // ω <fn test_flows_currency>
+    }
+
+    /// Flows in USD.
+    ///
+    ///   * **year_values** - Year and value of each flow.
+    ///   * _return_ - The flows.
+    fn usd_flows(year_values: &[(u32, f64)]) -> Vec<YearCurrencyValue> {
+        year_values
+            .iter()
+            .map(|(year, value)| YearCurrencyValue {
+                year: *year,
+                currency: Currency::Usd as i32,
+                value: *value,
+            })
+            .collect()
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def time_value>
+// ω <mod-def time_value>
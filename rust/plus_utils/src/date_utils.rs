@@ -26,5 +26,48 @@ pub fn next_year() -> u32 {
     // ω <fn next_year>
 }
 
+/// Number of days from the start of the common era to the date, for measuring distance between dates
+///
+///   * **year** - Year of the date.
+///   * **month** - Month of the date, 1 based.
+///   * **day** - Day of the month, 1 based.
+///   * _return_ - Day number of the date, `None` if not a valid date
+#[inline]
+pub fn day_number(year: u32, month: u32, day: u32) -> Option<i64> {
+    // α <fn day_number>
+
+    use chrono::Datelike;
+    chrono::NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)
+        .map(|date| date.num_days_from_ce() as i64)
+
+    // ω <fn day_number>
+}
+
+/// Unit tests for `date_utils`
+#[cfg(test)]
+pub mod unit_tests {
+    use test_log::test;
+
+    #[test]
+    fn test_day_number() {
+        // α <fn test_day_number>
+
+        assert_eq!(Some(1), day_number(1, 1, 1));
+        assert_eq!(
+            Some(366),
+            day_number(2024, 12, 31)
+                .zip(day_number(2023, 12, 31))
+                .map(|(end, start)| end - start)
+        );
+        assert_eq!(None, day_number(2023, 2, 29));
+
+        // ω <fn test_day_number>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
 // α <mod-def date_utils>
 // ω <mod-def date_utils>
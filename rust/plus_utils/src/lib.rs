@@ -10,13 +10,37 @@ extern crate serde_derive;
 ////////////////////////////////////////////////////////////////////////////////////
 // --- pub module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+pub use self::date_utils::day_number;
 pub use self::date_utils::next_year;
 pub use self::date_utils::this_year;
+pub use self::math::correlated_normals::cholesky_decomposition;
+pub use self::math::correlated_normals::correlate_normals;
 pub use self::math::incremental_pearson::IncrementalPearson;
 pub use self::math::incremental_pearson::PearsonRowEntry;
 pub use self::math::incremental_pearson::PearsonTriangularEntry;
 pub use self::math::incremental_stats::IncrementalStats;
 pub use self::math::measured_stats::MeasuredStats;
+pub use self::math::normal_distribution::erfc;
+pub use self::math::normal_distribution::normal_cdf;
+pub use self::math::normal_distribution::normal_quantile;
+pub use self::math::normal_distribution::standard_normal_cdf;
+pub use self::math::normal_distribution::standard_normal_inverse_cdf;
+pub use self::math::quantile_sketch::Percentiles;
+pub use self::math::quantile_sketch::QuantileSketch;
+pub use self::math::student_t_distribution::student_t_cdf;
+pub use self::math::student_t_distribution::student_t_inverse_cdf;
+pub use self::math::student_t_distribution::student_t_pdf;
+pub use self::math::tvm::annuity_future_value;
+pub use self::math::tvm::annuity_payment;
+pub use self::math::tvm::annuity_present_value;
+pub use self::math::tvm::future_value;
+pub use self::math::tvm::internal_rate_of_return;
+pub use self::math::tvm::net_present_value;
+pub use self::math::tvm::present_value;
+pub use self::math::tvm::savings_payment;
+pub use self::math::value_at_risk::normal_value_at_risk;
+pub use self::math::value_at_risk::sample_value_at_risk;
+pub use self::math::value_at_risk::ValueAtRisk;
 pub use self::scale_by::scale_by;
 pub use self::svg::histogram::DescriptivePoint;
 pub use self::svg::histogram::HistogramEntry;
@@ -0,0 +1,131 @@
+//! Correlated draws from the standard normal.
+//!
+//! A correlation matrix `C` is factored as `C = L·Lᵀ` (the _Cholesky_ decomposition) with `L`
+//! lower triangular. For independent standard normals `z` the vector `L·z` has correlation `C`,
+//! so simulations can draw independent normals and correlate them with one multiplication.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use ndarray::Array1;
+use ndarray::Array2;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Cholesky decomposition of a symmetric positive semi-definite matrix.
+/// Pivots that round to (or slightly below) zero, as in a matrix of perfectly correlated
+/// items, are taken as zero so the factor still reproduces the matrix.
+///
+///   * **matrix** - Square symmetric matrix, e.g. correlations.
+///   * _return_ - Lower triangular `L` with `L·Lᵀ = matrix`, `None` if not square or not positive semi-definite.
+pub fn cholesky_decomposition(matrix: &Array2<f64>) -> Option<Array2<f64>> {
+    // α <fn cholesky_decomposition>
+
+    const PIVOT_TOLERANCE: f64 = 1e-10;
+
+    let (rows, columns) = matrix.dim();
+    if rows != columns {
+        return None;
+    }
+
+    let mut lower = Array2::<f64>::zeros((rows, rows));
+    for i in 0..rows {
+        for j in 0..=i {
+            let dot = (0..j).map(|k| lower[[i, k]] * lower[[j, k]]).sum::<f64>();
+            if i == j {
+                let pivot = matrix[[i, i]] - dot;
+                if pivot < -PIVOT_TOLERANCE || !pivot.is_finite() {
+                    return None;
+                }
+                lower[[i, i]] = pivot.max(0.0).sqrt();
+            } else if lower[[j, j]] > PIVOT_TOLERANCE {
+                lower[[i, j]] = (matrix[[i, j]] - dot) / lower[[j, j]];
+            } else if (matrix[[i, j]] - dot).abs() > PIVOT_TOLERANCE.sqrt() {
+                // A zero pivot requires the rest of its column to vanish
+                return None;
+            }
+        }
+    }
+
+    Some(lower)
+
+    // ω <fn cholesky_decomposition>
+}
+
+/// Correlate independent standard normal draws.
+///
+///   * **cholesky_lower** - Cholesky factor of the correlation matrix.
+///   * **standard_normals** - Independent draws from the standard normal, one per row.
+///   * _return_ - Standard normal draws with the factored correlation.
+#[inline]
+pub fn correlate_normals(
+    cholesky_lower: &Array2<f64>,
+    standard_normals: &Array1<f64>,
+) -> Array1<f64> {
+    // α <fn correlate_normals>
+    cholesky_lower.dot(standard_normals)
+    // ω <fn correlate_normals>
+}
+
+/// Unit tests for `correlated_normals`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use ndarray::array;
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_cholesky_decomposition() {
+        // α <fn test_cholesky_decomposition>
+
+        let correlations = array![[1.0, 0.5, -0.2], [0.5, 1.0, 0.3], [-0.2, 0.3, 1.0]];
+        let lower = cholesky_decomposition(&correlations).unwrap();
+        for i in 0..3 {
+            for j in (i + 1)..3 {
+                assert_eq!(0.0, lower[[i, j]]);
+            }
+        }
+        let product = lower.dot(&lower.t());
+        for (actual, expected) in product.iter().zip(correlations.iter()) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+
+        // Perfectly correlated items are semi-definite
+        let perfect = array![[1.0, 1.0], [1.0, 1.0]];
+        let lower = cholesky_decomposition(&perfect).unwrap();
+        assert!((lower.dot(&lower.t())[[0, 1]] - 1.0).abs() < 1e-12);
+
+        // Not a valid correlation matrix
+        assert_eq!(
+            None,
+            cholesky_decomposition(&array![[1.0, 0.9, 0.9], [0.9, 1.0, -0.9], [0.9, -0.9, 1.0]])
+        );
+        assert_eq!(None, cholesky_decomposition(&array![[1.0, 0.0]]));
+
+        // ω <fn test_cholesky_decomposition>
+    }
+
+    #[test]
+    fn test_correlate_normals() {
+        // α <fn test_correlate_normals>
+        let lower = cholesky_decomposition(&array![[1.0, 0.6], [0.6, 1.0]]).unwrap();
+        let correlated = correlate_normals(&lower, &array![1.0, 2.0]);
+        assert!((correlated[0] - 1.0).abs() < 1e-12);
+        assert!((correlated[1] - (0.6 + 0.8 * 2.0)).abs() < 1e-12);
+        // ω <fn test_correlate_normals>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def correlated_normals>
+// ω <mod-def correlated_normals>
@@ -8,6 +8,7 @@
 ////////////////////////////////////////////////////////////////////////////////////
 use crate::MeasuredStats;
 use ndarray::Array1;
+use ndarray::Array2;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- structs ---
@@ -23,6 +24,10 @@ use ndarray::Array1;
 ///
 /// Also for triangular storage this is useful:
 /// <https://www.geeksforgeeks.org/efficient-method-to-store-a-lower-triangular-matrix-using-row-major-mapping/>
+///
+/// For short histories (e.g. 20-30 annual returns) the sample covariance is too noisy to
+/// use directly. Created with `with_shrinkage` the rows are retained so a _Ledoit-Wolf_
+/// shrinkage estimate is available as well.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct IncrementalPearson {
     /// One row for each pair of indices in the mix
@@ -31,6 +36,8 @@ pub struct IncrementalPearson {
     pearson_triangular: Vec<PearsonTriangularEntry>,
     /// Number of rows tracked/added
     rows_tracked: usize,
+    /// Rows retained for the shrinkage estimate, if requested.
+    observations: Option<Vec<Array1<f64>>>,
 }
 
 /// Row indexed summary data.
@@ -57,6 +64,30 @@ pub struct PearsonTriangularEntry {
     pub cov: f64,
 }
 
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Correlation matrix of a covariance matrix.
+///
+///   * **covariance** - Square covariance matrix.
+///   * _return_ - Correlations, 1 on the diagonal and 0 with items that have no variance.
+fn correlation_of_covariance(covariance: &Array2<f64>) -> Array2<f64> {
+    // α <fn correlation_of_covariance>
+
+    Array2::from_shape_fn(covariance.dim(), |(i, j)| {
+        let t = (covariance[(i, i)] * covariance[(j, j)]).sqrt();
+        if i == j {
+            1.0
+        } else if t > 0.0 {
+            covariance[(i, j)] / t
+        } else {
+            0.0
+        }
+    })
+
+    // ω <fn correlation_of_covariance>
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 // --- type impls ---
 ////////////////////////////////////////////////////////////////////////////////////
@@ -75,11 +106,28 @@ impl IncrementalPearson {
                 (row_dim * (row_dim + 1)) / 2
             ],
             rows_tracked: 0,
+            observations: None,
         }
 
         // ω <fn IncrementalPearson::new>
     }
 
+    /// Create new [IncrementalPearson] that retains the rows for a _Ledoit-Wolf_ shrinkage
+    /// estimate. Intended for short histories, as every row tracked is kept in memory.
+    ///
+    ///   * **row_dim** - Length defining number of rows in a matrix and number of entries in lower triangular matrix.
+    ///   * _return_ - New properly sized [IncrementalPearson]
+    pub fn with_shrinkage(row_dim: usize) -> IncrementalPearson {
+        // α <fn IncrementalPearson::with_shrinkage>
+
+        IncrementalPearson {
+            observations: Some(Vec::new()),
+            ..IncrementalPearson::new(row_dim)
+        }
+
+        // ω <fn IncrementalPearson::with_shrinkage>
+    }
+
     /// Update data with new values.
     ///
     ///   * **values** - One set of returns in the series to add to summary stats.
@@ -121,11 +169,78 @@ impl IncrementalPearson {
             }
         }
 
+        if let Some(observations) = self.observations.as_mut() {
+            observations.push(values.clone());
+        }
+
         self.rows_tracked += 1;
 
         // ω <fn IncrementalPearson::track_row>
     }
 
+    /// Merge summary data accumulated separately (e.g. on another thread) into this one.
+    /// Means, variances and co-moments are combined exactly with the parallel algorithm
+    /// of _Chan, Golub and LeVeque_, so the result matches tracking all rows in one instance.
+    /// As with `track_row`, matching dimensions are the caller's responsibility and are only
+    /// checked in debug builds. Merging in data that does not retain its rows ends the
+    /// shrinkage estimate of `self`.
+    ///
+    ///   * **other** - Summary data of the same dimension to merge in.
+    pub fn merge(&mut self, other: &IncrementalPearson) {
+        // α <fn IncrementalPearson::merge>
+
+        debug_assert_eq!(self.pearson_rows.len(), other.pearson_rows.len());
+
+        if other.rows_tracked == 0 {
+            return;
+        }
+
+        let n_a = self.rows_tracked as f64;
+        let n_b = other.rows_tracked as f64;
+        let n = n_a + n_b;
+        let deltas = self
+            .pearson_rows
+            .iter()
+            .zip(other.pearson_rows.iter())
+            .map(|(a, b)| b.mean - a.mean)
+            .collect::<Vec<_>>();
+
+        for (i, delta_i) in deltas.iter().enumerate() {
+            for (j, delta_j) in deltas.iter().enumerate().take(i + 1) {
+                let index = (i + 1) * i / 2 + j;
+                self.pearson_triangular[index].cov +=
+                    other.pearson_triangular[index].cov + delta_i * delta_j * n_a * n_b / n;
+            }
+        }
+
+        for ((row, other_row), delta) in self
+            .pearson_rows
+            .iter_mut()
+            .zip(other.pearson_rows.iter())
+            .zip(deltas.iter())
+        {
+            row.mean += delta * n_b / n;
+            row.prior_mean = row.mean;
+            row.variance += other_row.variance + delta * delta * n_a * n_b / n;
+            row.min = row.min.min(other_row.min);
+            row.max = row.max.max(other_row.max);
+        }
+
+        // Rows merged without their observations would leave the shrinkage estimate
+        // using only part of the rows, so it is dropped
+        match (self.observations.as_mut(), other.observations.as_ref()) {
+            (Some(observations), Some(other_observations)) => {
+                observations.extend(other_observations.iter().cloned())
+            }
+            (Some(_), None) => self.observations = None,
+            (None, _) => (),
+        }
+
+        self.rows_tracked += other.rows_tracked;
+
+        // ω <fn IncrementalPearson::merge>
+    }
+
     /// Get the incremental coefficient (correlation).
     ///
     ///   * **index** - The 2D index to desired correlation.
@@ -155,6 +270,151 @@ impl IncrementalPearson {
         // ω <fn IncrementalPearson::get_pearson_coefficient>
     }
 
+    /// Get the sample covariance matrix.
+    ///
+    ///   * _return_ - The covariance matrix, `None` if fewer than 2 rows tracked.
+    pub fn covariance_matrix(&self) -> Option<Array2<f64>> {
+        // α <fn IncrementalPearson::covariance_matrix>
+
+        if self.rows_tracked > 1 {
+            let dim = self.pearson_rows.len();
+            let n = self.rows_tracked as f64;
+            Some(Array2::from_shape_fn((dim, dim), |(i, j)| {
+                let (i, j) = if i > j { (i, j) } else { (j, i) };
+                self.pearson_triangular[(i + 1) * i / 2 + j].cov / (n - 1.0)
+            }))
+        } else {
+            None
+        }
+
+        // ω <fn IncrementalPearson::covariance_matrix>
+    }
+
+    /// Get the sample correlation matrix.
+    /// Correlations with an item that did not vary are reported as 0.
+    ///
+    ///   * _return_ - The correlation matrix, `None` if fewer than 2 rows tracked.
+    pub fn correlation_matrix(&self) -> Option<Array2<f64>> {
+        // α <fn IncrementalPearson::correlation_matrix>
+        self.covariance_matrix()
+            .map(|covariance| correlation_of_covariance(&covariance))
+        // ω <fn IncrementalPearson::correlation_matrix>
+    }
+
+    /// Get the _Ledoit-Wolf_ shrinkage estimate of the covariance matrix.
+    /// The sample covariance is shrunk toward a _constant correlation_ target, which keeps
+    /// the sample variances and replaces each correlation by the average correlation.
+    /// The shrinkage intensity is the estimated optimal one of
+    /// <http://www.ledoit.net/honey.pdf>, clamped to [0, 1].
+    ///
+    /// The estimate is scaled like `covariance_matrix`. Without `with_shrinkage` or with
+    /// fewer than 2 rows tracked there is no estimate.
+    ///
+    ///   * _return_ - The shrunk covariance matrix and the shrinkage intensity.
+    pub fn shrunk_covariance_matrix(&self) -> Option<(Array2<f64>, f64)> {
+        // α <fn IncrementalPearson::shrunk_covariance_matrix>
+
+        let observations = self.observations.as_ref()?;
+        if observations.len() < 2 {
+            return None;
+        }
+
+        let dim = self.pearson_rows.len();
+        let t = observations.len() as f64;
+        let means = Array1::from_iter(self.pearson_rows.iter().map(|row| row.mean));
+        let centered = observations
+            .iter()
+            .map(|observation| observation - &means)
+            .collect::<Vec<_>>();
+
+        // Maximum likelihood (divide by `t`) sample covariance, as in the paper
+        let sample = Array2::from_shape_fn((dim, dim), |(i, j)| {
+            centered.iter().map(|y| y[i] * y[j]).sum::<f64>() / t
+        });
+        let std_devs = Array1::from_iter((0..dim).map(|i| sample[(i, i)].sqrt()));
+
+        let mut correlation_sum = 0.0;
+        let mut correlation_count = 0;
+        for i in 0..dim {
+            for j in 0..i {
+                if std_devs[i] > 0.0 && std_devs[j] > 0.0 {
+                    correlation_sum += sample[(i, j)] / (std_devs[i] * std_devs[j]);
+                    correlation_count += 1;
+                }
+            }
+        }
+        let average_correlation = if correlation_count > 0 {
+            correlation_sum / correlation_count as f64
+        } else {
+            0.0
+        };
+
+        let target = Array2::from_shape_fn((dim, dim), |(i, j)| {
+            if i == j {
+                sample[(i, i)]
+            } else {
+                average_correlation * std_devs[i] * std_devs[j]
+            }
+        });
+
+        // Sum of asymptotic variances of the sample covariance entries
+        let mut pi = 0.0;
+        // Sum of asymptotic covariances of target and sample covariance entries
+        let mut rho = 0.0;
+        for i in 0..dim {
+            for j in 0..dim {
+                let pi_ij = centered
+                    .iter()
+                    .map(|y| (y[i] * y[j] - sample[(i, j)]).powi(2))
+                    .sum::<f64>()
+                    / t;
+                pi += pi_ij;
+                if i == j {
+                    rho += pi_ij;
+                } else if std_devs[i] > 0.0 && std_devs[j] > 0.0 {
+                    let theta = |k: usize| {
+                        centered
+                            .iter()
+                            .map(|y| {
+                                (y[k] * y[k] - sample[(k, k)]) * (y[i] * y[j] - sample[(i, j)])
+                            })
+                            .sum::<f64>()
+                            / t
+                    };
+                    rho += average_correlation / 2.0
+                        * (std_devs[j] / std_devs[i] * theta(i)
+                            + std_devs[i] / std_devs[j] * theta(j));
+                }
+            }
+        }
+        // Misspecification of the target
+        let gamma = (&target - &sample).mapv(|d| d * d).sum();
+
+        let shrinkage = if gamma > 0.0 {
+            ((pi - rho) / gamma / t).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let unbiased = t / (t - 1.0);
+        Some((
+            (&target * shrinkage + &sample * (1.0 - shrinkage)) * unbiased,
+            shrinkage,
+        ))
+
+        // ω <fn IncrementalPearson::shrunk_covariance_matrix>
+    }
+
+    /// Get the correlation matrix of the _Ledoit-Wolf_ shrinkage estimate.
+    ///
+    ///   * _return_ - The shrunk correlation matrix, if `shrunk_covariance_matrix` is available.
+    pub fn shrunk_correlation_matrix(&self) -> Option<Array2<f64>> {
+        // α <fn IncrementalPearson::shrunk_correlation_matrix>
+        self.shrunk_covariance_matrix()
+            .map(|(covariance, _)| correlation_of_covariance(&covariance))
+        // ω <fn IncrementalPearson::shrunk_correlation_matrix>
+    }
+
     /// Get the mean of indexed item.
     ///
     ///   * **index** - The index of desired mean.
@@ -225,6 +485,11 @@ impl IncrementalPearson {
                 mean: est_normal.map(|ns| ns.0),
                 median: None,
                 std_dev: est_normal.map(|ns| ns.1),
+                percentiles: None,
+                skewness: None,
+                excess_kurtosis: None,
+                semi_deviation: None,
+                max_drawdown: None,
             })
         } else {
             None
@@ -363,6 +628,157 @@ pub mod unit_tests {
             // ω <fn test IncrementalPearson::track_row_basic_stats>
         }
 
+        #[test]
+        fn merge() {
+            // α <fn test IncrementalPearson::merge>
+
+            use ndarray::arr1;
+
+            let data = [
+                [10.32, 103.0, 97.0],
+                [10.22, 105.0, 95.3],
+                [9.31, 96.0, 102.0],
+                [9.93, 101.0, 98.5],
+                [10.1, 100.5, 100.0],
+                [7.93, 82.0, 115.0],
+                [11.23, 108.0, 90.3],
+            ];
+
+            let mut all = IncrementalPearson::new(3);
+            let mut first = IncrementalPearson::new(3);
+            let mut second = IncrementalPearson::new(3);
+            for (i, row) in data.iter().enumerate() {
+                all.track_row(&arr1(row));
+                if i < 4 {
+                    first.track_row(&arr1(row))
+                } else {
+                    second.track_row(&arr1(row))
+                }
+            }
+
+            first.merge(&second);
+            first.merge(&IncrementalPearson::new(3));
+
+            assert_eq!(all.get_rows_tracked(), first.get_rows_tracked());
+            for i in 0..3 {
+                assert!((all.get_mean(i).unwrap() - first.get_mean(i).unwrap()).abs() < 1e-12);
+                assert!(
+                    (all.get_std_dev(i).unwrap() - first.get_std_dev(i).unwrap()).abs() < 1e-12
+                );
+                assert_eq!(
+                    all.get_pearson_rows()[i].min,
+                    first.get_pearson_rows()[i].min
+                );
+                assert_eq!(
+                    all.get_pearson_rows()[i].max,
+                    first.get_pearson_rows()[i].max
+                );
+                for j in 0..3 {
+                    assert!(
+                        (all.get_pearson_coefficient((i, j)).unwrap()
+                            - first.get_pearson_coefficient((i, j)).unwrap())
+                        .abs()
+                            < 1e-12
+                    );
+                }
+            }
+
+            // ω <fn test IncrementalPearson::merge>
+        }
+
+        #[test]
+        fn covariance_matrix() {
+            // α <fn test IncrementalPearson::covariance_matrix>
+
+            use ndarray::arr1;
+
+            let data = [
+                [10.32, 103.0, 97.0],
+                [10.22, 105.0, 95.3],
+                [9.31, 96.0, 102.0],
+                [9.93, 101.0, 98.5],
+                [10.1, 100.5, 100.0],
+            ];
+
+            let mut incremental = IncrementalPearson::new(3);
+            assert_eq!(None, incremental.covariance_matrix());
+            for row in data.iter() {
+                incremental.track_row(&arr1(row))
+            }
+
+            let covariance = incremental.covariance_matrix().unwrap();
+            let correlation = incremental.correlation_matrix().unwrap();
+            assert_eq!((3, 3), correlation.dim());
+            for i in 0..3 {
+                let std_dev = incremental.get_std_dev(i).unwrap();
+                assert!((std_dev * std_dev - covariance[(i, i)]).abs() < 1e-12);
+                for j in 0..3 {
+                    assert_eq!(covariance[(i, j)], covariance[(j, i)]);
+                    assert!(
+                        (incremental.get_pearson_coefficient((i, j)).unwrap()
+                            - correlation[(i, j)])
+                            .abs()
+                            < 1e-12
+                    );
+                }
+            }
+
+            // Shrinkage requires the rows retained
+            assert_eq!(None, incremental.shrunk_covariance_matrix());
+
+            // ω <fn test IncrementalPearson::covariance_matrix>
+        }
+
+        #[test]
+        fn shrunk_covariance_matrix() {
+            // α <fn test IncrementalPearson::shrunk_covariance_matrix>
+
+            use ndarray::arr1;
+
+            let data = [
+                [10.32, 103.0, 97.0],
+                [10.22, 105.0, 95.3],
+                [9.31, 96.0, 102.0],
+                [9.93, 101.0, 98.5],
+                [10.1, 100.5, 100.0],
+                [7.93, 82.0, 115.0],
+                [11.23, 108.0, 90.3],
+            ];
+
+            let mut first = IncrementalPearson::with_shrinkage(3);
+            let mut second = IncrementalPearson::with_shrinkage(3);
+            for (i, row) in data.iter().enumerate() {
+                if i < 4 {
+                    first.track_row(&arr1(row))
+                } else {
+                    second.track_row(&arr1(row))
+                }
+            }
+            first.merge(&second);
+
+            let (shrunk, shrinkage) = first.shrunk_covariance_matrix().unwrap();
+            assert!((0.5325848198 - shrinkage).abs() < 1e-8);
+
+            // Variances are kept, correlations are pulled toward their average
+            let sample = first.covariance_matrix().unwrap();
+            let correlation = first.shrunk_correlation_matrix().unwrap();
+            for i in 0..3 {
+                assert!((sample[(i, i)] - shrunk[(i, i)]).abs() < 1e-9);
+                assert_eq!(1.0, correlation[(i, i)]);
+            }
+            assert!((0.2800999734 - correlation[(0, 1)]).abs() < 1e-8);
+            assert!((-0.6356037112 - correlation[(0, 2)]).abs() < 1e-8);
+            assert!((-0.6403286231 - correlation[(1, 2)]).abs() < 1e-8);
+
+            // Rows merged without their observations end the estimate
+            let mut without_rows = IncrementalPearson::new(3);
+            without_rows.track_row(&arr1(&data[0]));
+            first.merge(&without_rows);
+            assert_eq!(None, first.shrunk_covariance_matrix());
+
+            // ω <fn test IncrementalPearson::shrunk_covariance_matrix>
+        }
+
         // α <mod-def test_incremental_pearson>
         use super::*;
         // ω <mod-def test_incremental_pearson>
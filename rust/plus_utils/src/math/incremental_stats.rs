@@ -1,6 +1,7 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use crate::math::quantile_sketch::QuantileSketch;
 use crate::MeasuredStats;
 
 ////////////////////////////////////////////////////////////////////////////////////
@@ -15,6 +16,19 @@ pub struct IncrementalStats {
     mean: f64,
     /// Sum of squared diff of values
     sum_squared_diff: f64,
+    /// Sum of cubed diff of values
+    sum_cubed_diff: f64,
+    /// Sum of fourth power diff of values
+    sum_fourth_power_diff: f64,
+    /// Target below which values count toward the semi-deviation
+    downside_target: f64,
+    /// Sum of squared shortfalls below `downside_target`
+    sum_squared_shortfall: f64,
+    /// Each new running peak of values treated as levels of an ordered series,
+    /// with the values up to the next peak, if merging max drawdown exactly is required
+    peak_segments: Option<Vec<PeakSegment>>,
+    /// Largest decline from the running peak, as a fraction of the peak
+    max_drawdown: f64,
     /// Min of values
     min: f64,
     /// Max of values
@@ -23,6 +37,20 @@ pub struct IncrementalStats {
     values: Option<Vec<f64>>,
     /// Median, only on call to `finalize_median`.
     pub median: Option<f64>,
+    /// Bounded memory sketch if tracking quantiles is required
+    sketch: Option<QuantileSketch>,
+}
+
+/// A new running peak of a series and the values following it up to the next peak,
+/// enough to combine the drawdowns of consecutive series exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PeakSegment {
+    /// The peak
+    peak: f64,
+    /// Min of the values before the peak
+    prior_min: f64,
+    /// Largest decline from the peak before the next peak, as a fraction of the peak
+    drawdown: f64,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
@@ -61,8 +89,39 @@ impl IncrementalStats {
 
         let delta = value - self.mean;
         let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * prior_n;
         self.mean += delta_n;
-        self.sum_squared_diff += delta * delta_n * prior_n;
+        self.sum_fourth_power_diff += term * delta_n2 * (n * n - 3.0 * n + 3.0)
+            + 6.0 * delta_n2 * self.sum_squared_diff
+            - 4.0 * delta_n * self.sum_cubed_diff;
+        self.sum_cubed_diff += term * delta_n * (n - 2.0) - 3.0 * delta_n * self.sum_squared_diff;
+        self.sum_squared_diff += term;
+
+        let shortfall = (self.downside_target - value).max(0.0);
+        self.sum_squared_shortfall += shortfall * shortfall;
+
+        // The running peak is the max of the values before this one
+        if value > self.max {
+            if let Some(peak_segments) = self.peak_segments.as_mut() {
+                peak_segments.push(PeakSegment {
+                    peak: value,
+                    prior_min: self.min,
+                    drawdown: 0.0,
+                });
+            }
+        } else if self.max > 0.0 {
+            let drawdown = (self.max - value) / self.max;
+            self.max_drawdown = self.max_drawdown.max(drawdown);
+            if let Some(segment) = self
+                .peak_segments
+                .as_mut()
+                .and_then(|peak_segments| peak_segments.last_mut())
+            {
+                segment.drawdown = segment.drawdown.max(drawdown);
+            }
+        }
+
         if value < self.min {
             self.min = value;
         }
@@ -74,6 +133,10 @@ impl IncrementalStats {
             values.push(value);
         }
 
+        if let Some(sketch) = self.sketch.as_mut() {
+            sketch.push_value(value);
+        }
+
         // ω <fn IncrementalStats::push_value>
     }
 
@@ -91,6 +154,107 @@ impl IncrementalStats {
         // ω <fn IncrementalStats::push_values>
     }
 
+    /// Merge stats accumulated separately (e.g. on another thread) into these stats.
+    /// Mean and variance are combined exactly with the parallel algorithm of
+    /// _Chan, Golub and LeVeque_, so the result matches pushing all values into one instance.
+    /// Higher moments use the pairwise formulas of _Pébay_. Tracked values are appended
+    /// (requiring a new `finalize_median`) and quantile sketches are merged. Values or a
+    /// sketch not tracked by both are no longer tracked, as they would cover only part of
+    /// the data.
+    ///
+    /// For drawdown `other` is treated as continuing this series, its values declining from
+    /// this series' peak until they rise above it. The merged max drawdown is an upper bound,
+    /// exact when the values of `other` are not negative. When both stats were created with
+    /// `with_exact_drawdown` it is always exact.
+    ///
+    ///   * **other** - Stats to merge in.
+    pub fn merge(&mut self, other: &IncrementalStats) {
+        // α <fn IncrementalStats::merge>
+
+        if other.count == 0 {
+            return;
+        }
+
+        let n_a = self.count as f64;
+        let n_b = other.count as f64;
+        let n = n_a + n_b;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        self.mean += delta * n_b / n;
+        self.sum_fourth_power_diff += other.sum_fourth_power_diff
+            + delta2 * delta2 * n_a * n_b * (n_a * n_a - n_a * n_b + n_b * n_b) / (n * n * n)
+            + 6.0
+                * delta2
+                * (n_a * n_a * other.sum_squared_diff + n_b * n_b * self.sum_squared_diff)
+                / (n * n)
+            + 4.0 * delta * (n_a * other.sum_cubed_diff - n_b * self.sum_cubed_diff) / n;
+        self.sum_cubed_diff += other.sum_cubed_diff
+            + delta2 * delta * n_a * n_b * (n_a - n_b) / (n * n)
+            + 3.0 * delta * (n_a * other.sum_squared_diff - n_b * self.sum_squared_diff) / n;
+        self.sum_squared_diff += other.sum_squared_diff + delta2 * n_a * n_b / n;
+
+        debug_assert!(self.downside_target == other.downside_target);
+        self.sum_squared_shortfall += other.sum_squared_shortfall;
+
+        match (self.peak_segments.as_mut(), other.peak_segments.as_ref()) {
+            (Some(peak_segments), Some(other_peak_segments)) => {
+                let peak = peak_segments
+                    .last()
+                    .map_or(f64::MIN, |segment| segment.peak);
+                let rising = other_peak_segments
+                    .iter()
+                    .position(|segment| segment.peak > peak)
+                    .unwrap_or(other_peak_segments.len());
+                // Min of the values of `other` before it rises above the peak
+                let prior_min = other_peak_segments
+                    .get(rising)
+                    .map_or(other.min, |segment| segment.prior_min);
+                if let Some(segment) = peak_segments.last_mut() {
+                    if segment.peak > 0.0 && prior_min < segment.peak {
+                        segment.drawdown = segment
+                            .drawdown
+                            .max((segment.peak - prior_min) / segment.peak);
+                        self.max_drawdown = self.max_drawdown.max(segment.drawdown);
+                    }
+                }
+                for segment in &other_peak_segments[rising..] {
+                    self.max_drawdown = self.max_drawdown.max(segment.drawdown);
+                    peak_segments.push(PeakSegment {
+                        prior_min: self.min.min(segment.prior_min),
+                        ..*segment
+                    });
+                }
+            }
+            _ => {
+                // Declines of `other` after rising above the peak are within its own drawdown
+                // unless its values turn negative
+                if self.max > 0.0 && other.min < self.max {
+                    self.max_drawdown = self.max_drawdown.max((self.max - other.min) / self.max);
+                }
+                self.max_drawdown = self.max_drawdown.max(other.max_drawdown);
+                self.peak_segments = None;
+            }
+        }
+
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+
+        // Values or a sketch covering only part of the merged data are dropped
+        match (self.values.as_mut(), other.values.as_ref()) {
+            (Some(values), Some(other_values)) => values.extend_from_slice(other_values),
+            _ => self.values = None,
+        }
+        self.median = None;
+
+        match (self.sketch.as_mut(), other.sketch.as_ref()) {
+            (Some(sketch), Some(other_sketch)) => sketch.merge(other_sketch),
+            _ => self.sketch = None,
+        }
+
+        // ω <fn IncrementalStats::merge>
+    }
+
     /// Count of items pushed
     ///
     ///   * _return_ - Count of items pushed.
@@ -181,6 +345,90 @@ impl IncrementalStats {
         // ω <fn IncrementalStats::std_dev>
     }
 
+    /// Skewness (population, _g1_) of values pushed
+    ///
+    ///   * _return_ - Skewness of pushed data.
+    #[inline]
+    pub fn skewness(&self) -> Option<f64> {
+        // α <fn IncrementalStats::skewness>
+
+        if self.count > 2 && self.sum_squared_diff > 0.0 {
+            Some((self.count as f64).sqrt() * self.sum_cubed_diff / self.sum_squared_diff.powf(1.5))
+        } else {
+            None
+        }
+
+        // ω <fn IncrementalStats::skewness>
+    }
+
+    /// Excess kurtosis (population, _g2_) of values pushed, 0 for a normal distribution
+    ///
+    ///   * _return_ - Excess kurtosis of pushed data.
+    #[inline]
+    pub fn excess_kurtosis(&self) -> Option<f64> {
+        // α <fn IncrementalStats::excess_kurtosis>
+
+        if self.count > 3 && self.sum_squared_diff > 0.0 {
+            Some(
+                self.count as f64 * self.sum_fourth_power_diff
+                    / (self.sum_squared_diff * self.sum_squared_diff)
+                    - 3.0,
+            )
+        } else {
+            None
+        }
+
+        // ω <fn IncrementalStats::excess_kurtosis>
+    }
+
+    /// Semi-deviation of values pushed: root mean squared shortfall below the downside target
+    ///
+    ///   * _return_ - Semi-deviation of pushed data.
+    #[inline]
+    pub fn semi_deviation(&self) -> Option<f64> {
+        // α <fn IncrementalStats::semi_deviation>
+
+        if self.count > 0 {
+            Some((self.sum_squared_shortfall / self.count as f64).sqrt())
+        } else {
+            None
+        }
+
+        // ω <fn IncrementalStats::semi_deviation>
+    }
+
+    /// Maximum drawdown of values pushed, treated as levels of a series (e.g. balances)
+    /// in the order pushed. Only positive peaks are considered.
+    ///
+    ///   * _return_ - Largest decline from a prior peak, as a fraction of that peak.
+    #[inline]
+    pub fn max_drawdown(&self) -> Option<f64> {
+        // α <fn IncrementalStats::max_drawdown>
+
+        if self.count > 0 {
+            Some(self.max_drawdown)
+        } else {
+            None
+        }
+
+        // ω <fn IncrementalStats::max_drawdown>
+    }
+
+    /// Set the target below which values count toward the semi-deviation.
+    /// Must be set before values are pushed; defaults to 0.
+    ///
+    ///   * **downside_target** - Target, e.g. 0 for losses or a required return.
+    ///   * _return_ - The stats with the target set.
+    pub fn with_downside_target(mut self, downside_target: f64) -> IncrementalStats {
+        // α <fn IncrementalStats::with_downside_target>
+
+        debug_assert!(self.count == 0);
+        self.downside_target = downside_target;
+        self
+
+        // ω <fn IncrementalStats::with_downside_target>
+    }
+
     /// Create a new instance of [IncrementalStats]
     ///
     ///   * **median_capacity** - Cardinality of forecasts to size vector for medians, or 0 to not track medians.
@@ -200,6 +448,50 @@ impl IncrementalStats {
         // ω <fn IncrementalStats::new>
     }
 
+    /// Create a new instance of [IncrementalStats] estimating quantiles in bounded memory.
+    /// Unlike tracking the median, values are not stored, making this suitable for large
+    /// numbers of forecasts. See [QuantileSketch] for the error bound.
+    ///
+    ///   * **compression** - Compression of the sketch, e.g. `DEFAULT_SKETCH_COMPRESSION`.
+    ///   * _return_ - A new [IncrementalStats] instance.
+    pub fn with_quantiles(compression: f64) -> IncrementalStats {
+        // α <fn IncrementalStats::with_quantiles>
+
+        IncrementalStats {
+            sketch: Some(QuantileSketch::new(compression)),
+            ..Default::default()
+        }
+
+        // ω <fn IncrementalStats::with_quantiles>
+    }
+
+    /// Create a new instance of [IncrementalStats] merging max drawdown exactly, whatever
+    /// the sign of the values. Each new running peak is kept, so memory grows with the
+    /// number of new peaks, up to the number of values on a rising series.
+    ///
+    ///   * _return_ - A new [IncrementalStats] instance.
+    pub fn with_exact_drawdown() -> IncrementalStats {
+        // α <fn IncrementalStats::with_exact_drawdown>
+
+        IncrementalStats {
+            peak_segments: Some(Vec::new()),
+            ..Default::default()
+        }
+
+        // ω <fn IncrementalStats::with_exact_drawdown>
+    }
+
+    /// Estimated value at quantile `q`.
+    ///
+    ///   * **q** - Quantile in [0, 1].
+    ///   * _return_ - Estimate, `None` if quantiles not tracked or no values pushed.
+    #[inline]
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        // α <fn IncrementalStats::quantile>
+        self.sketch.as_ref().and_then(|sketch| sketch.quantile(q))
+        // ω <fn IncrementalStats::quantile>
+    }
+
     /// All data has been pushed and this will sort the values making _median_ available.
     /// **Note** This clears the values after saving the median to save memory.
     ///
@@ -237,13 +529,21 @@ impl IncrementalStats {
         // α <fn IncrementalStats::get_measured_stats>
 
         if self.count > 0 {
+            let percentiles = self.sketch.as_ref().and_then(|sketch| sketch.percentiles());
             MeasuredStats {
                 count: self.count,
                 min: Some(self.min),
                 max: Some(self.max),
                 mean: self.mean(),
-                median: self.median,
+                median: self
+                    .median
+                    .or(percentiles.map(|percentiles| percentiles.p50)),
                 std_dev: self.std_dev(),
+                percentiles,
+                skewness: self.skewness(),
+                excess_kurtosis: self.excess_kurtosis(),
+                semi_deviation: self.semi_deviation(),
+                max_drawdown: self.max_drawdown(),
             }
         } else {
             MeasuredStats::default()
@@ -280,10 +580,17 @@ impl Default for IncrementalStats {
             count: 0,
             mean: 0.0,
             sum_squared_diff: 0.0,
+            sum_cubed_diff: 0.0,
+            sum_fourth_power_diff: 0.0,
+            downside_target: 0.0,
+            sum_squared_shortfall: 0.0,
+            peak_segments: None,
+            max_drawdown: 0.0,
             min: std::f64::MAX,
             max: std::f64::MIN,
             values: None,
             median: None,
+            sketch: None,
         }
 
         // ω <fn Default::default for IncrementalStats>
@@ -358,6 +665,112 @@ pub mod unit_tests {
             // ω <fn test IncrementalStats::push_values>
         }
 
+        #[test]
+        fn merge() {
+            // α <fn test IncrementalStats::merge>
+
+            let values = [10.32, 10.22, 9.31, 9.93, 10.1, 7.93, 11.23];
+            let all = IncrementalStats::from_values(&values, true);
+
+            let mut merged = IncrementalStats::from_values(&values[..3], true);
+            merged.merge(&IncrementalStats::from_values(&values[3..], true));
+            merged.merge(&IncrementalStats::default());
+
+            assert_eq!(all.count(), merged.count());
+            assert_eq!(all.min(), merged.min());
+            assert_eq!(all.max(), merged.max());
+            assert!((all.mean().unwrap() - merged.mean().unwrap()).abs() < 1e-12);
+            assert!((all.variance().unwrap() - merged.variance().unwrap()).abs() < 1e-12);
+            assert_eq!(all.get_values(), merged.get_values());
+            assert!((all.skewness().unwrap() - merged.skewness().unwrap()).abs() < 1e-9);
+            assert!(
+                (all.excess_kurtosis().unwrap() - merged.excess_kurtosis().unwrap()).abs() < 1e-9
+            );
+            assert_eq!(all.semi_deviation(), merged.semi_deviation());
+
+            let levels = [100.0, 120.0, 90.0, 110.0, 80.0, 115.0];
+            let mut drawdown = IncrementalStats::from_values(&levels[..3], false);
+            drawdown.merge(&IncrementalStats::from_values(&levels[3..], false));
+            assert_eq!(
+                IncrementalStats::from_values(&levels, false).max_drawdown(),
+                drawdown.max_drawdown()
+            );
+
+            // Peaks in the second half, rising above the first half's peak in steps,
+            // including negative levels, split at every point and in three
+            let exact = |levels: &[f64]| {
+                let mut stats = IncrementalStats::with_exact_drawdown();
+                stats.push_values(levels);
+                stats
+            };
+            for levels in [
+                vec![100.0, 90.0, 95.0, 60.0, 110.0, 85.0, 200.0, 70.0, 150.0],
+                vec![50.0, 20.0, 100.0, 45.0, 80.0, 5.0, 300.0, 240.0],
+                vec![100.0, 80.0, 50.0, -10.0, 200.0, 150.0],
+                vec![-20.0, -5.0, 10.0, 8.0, 30.0, -2.0, 25.0],
+            ] {
+                let sequential = exact(&levels);
+                for split in 0..=levels.len() {
+                    let mut merged = exact(&levels[..split]);
+                    merged.merge(&exact(&levels[split..]));
+                    assert_eq!(sequential.max_drawdown(), merged.max_drawdown());
+                    assert_eq!(sequential.peak_segments, merged.peak_segments);
+
+                    for second_split in split..=levels.len() {
+                        let mut merged = exact(&levels[..split]);
+                        let mut rest = exact(&levels[split..second_split]);
+                        rest.merge(&exact(&levels[second_split..]));
+                        merged.merge(&rest);
+                        assert_eq!(sequential.max_drawdown(), merged.max_drawdown());
+                    }
+
+                    // Without peak segments the merge bounds the drawdown, exactly when
+                    // the merged in levels are not negative
+                    let mut bounded = IncrementalStats::from_values(&levels[..split], false);
+                    bounded.merge(&IncrementalStats::from_values(&levels[split..], false));
+                    assert!(bounded.max_drawdown() >= sequential.max_drawdown());
+                    if levels[split..].iter().all(|&level| level >= 0.0) {
+                        assert!(
+                            (bounded.max_drawdown().unwrap() - sequential.max_drawdown().unwrap())
+                                .abs()
+                                < 1e-12
+                        );
+                    }
+                    assert_eq!(None, bounded.peak_segments);
+                }
+            }
+
+            // Merging in stats without values or a sketch drops them and the median
+            let mut tracking = IncrementalStats::from_values(&values[..3], true);
+            tracking.finalize_median(true);
+            tracking.merge(&IncrementalStats::from_values(&values[3..], false));
+            assert_eq!(None, tracking.get_values().as_ref());
+            assert_eq!(None, tracking.median);
+            assert_eq!(all.count(), tracking.count());
+
+            let mut sketched = IncrementalStats::with_quantiles(100.0);
+            sketched.push_values(&values[..3]);
+            sketched.merge(&IncrementalStats::from_values(&values[3..], false));
+            assert_eq!(None, sketched.quantile(0.5));
+
+            let mut untracked = IncrementalStats::default();
+            untracked.push_values(&values[..3]);
+            untracked.merge(&all);
+            assert_eq!(None, untracked.get_values().as_ref());
+
+            // Merging in stats without peak segments drops them
+            let mut merged = exact(&levels[..3]);
+            merged.merge(&IncrementalStats::from_values(&levels[3..], false));
+            assert_eq!(None, merged.peak_segments);
+
+            let mut empty = IncrementalStats::default();
+            empty.merge(&all);
+            assert!((all.mean().unwrap() - empty.mean().unwrap()).abs() < 1e-12);
+            assert!((all.variance().unwrap() - empty.variance().unwrap()).abs() < 1e-12);
+
+            // ω <fn test IncrementalStats::merge>
+        }
+
         #[test]
         fn count() {
             // α <fn test IncrementalStats::count>
@@ -406,6 +819,67 @@ pub mod unit_tests {
             // ω <fn test IncrementalStats::std_dev>
         }
 
+        #[test]
+        fn skewness() {
+            // α <fn test IncrementalStats::skewness>
+
+            let stats = IncrementalStats::from_values(&[1.0, 2.0, 3.0], false);
+            assert_eq!(0.0, stats.skewness().unwrap());
+
+            // Population g1 of [1, 2, 3, 10]
+            let stats = IncrementalStats::from_values(&[1.0, 2.0, 3.0, 10.0], false);
+            assert!((1.0182337649 - stats.skewness().unwrap()).abs() < 1e-9);
+
+            // ω <fn test IncrementalStats::skewness>
+        }
+
+        #[test]
+        fn excess_kurtosis() {
+            // α <fn test IncrementalStats::excess_kurtosis>
+
+            let stats = IncrementalStats::from_values(&[1.0, 2.0, 3.0, 4.0], false);
+            assert!((-1.36 - stats.excess_kurtosis().unwrap()).abs() < 1e-12);
+
+            let stats = IncrementalStats::from_values(&[1.0, 2.0, 3.0, 10.0], false);
+            assert!((-0.7696 - stats.excess_kurtosis().unwrap()).abs() < 1e-9);
+
+            // ω <fn test IncrementalStats::excess_kurtosis>
+        }
+
+        #[test]
+        fn semi_deviation() {
+            // α <fn test IncrementalStats::semi_deviation>
+
+            let stats = IncrementalStats::from_values(&[0.1, -0.2, 0.05, -0.1], false);
+            assert!(
+                (((0.04 + 0.01) / 4.0_f64).sqrt() - stats.semi_deviation().unwrap()).abs() < 1e-12
+            );
+
+            let mut stats = IncrementalStats::default().with_downside_target(0.06);
+            stats.push_values(&[0.1, 0.02, 0.06]);
+            assert!(((0.0016 / 3.0_f64).sqrt() - stats.semi_deviation().unwrap()).abs() < 1e-12);
+
+            // ω <fn test IncrementalStats::semi_deviation>
+        }
+
+        #[test]
+        fn max_drawdown() {
+            // α <fn test IncrementalStats::max_drawdown>
+
+            let stats = IncrementalStats::from_values(&[100.0, 120.0, 90.0, 130.0, 117.0], false);
+            assert!((0.25 - stats.max_drawdown().unwrap()).abs() < 1e-12);
+            assert_eq!(None, IncrementalStats::default().max_drawdown());
+
+            // A rising series keeps no state for its peaks unless merging exactly
+            let rising: Vec<f64> = (1..=100).map(|level| level as f64).collect();
+            assert_eq!(
+                None,
+                IncrementalStats::from_values(&rising, false).peak_segments
+            );
+
+            // ω <fn test IncrementalStats::max_drawdown>
+        }
+
         #[test]
         fn new() {
             // α <fn test IncrementalStats::new>
@@ -415,6 +889,48 @@ pub mod unit_tests {
             // ω <fn test IncrementalStats::new>
         }
 
+        #[test]
+        fn with_quantiles() {
+            // α <fn test IncrementalStats::with_quantiles>
+
+            let mut stats = IncrementalStats::with_quantiles(100.0);
+            stats.push_values(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+            assert_eq!(None, stats.get_values().as_ref());
+
+            let measured_stats = stats.get_measured_stats();
+            let percentiles = measured_stats.percentiles.expect("testing");
+            assert_eq!(3.0, percentiles.p50);
+            assert_eq!(Some(3.0), measured_stats.median);
+
+            // ω <fn test IncrementalStats::with_quantiles>
+        }
+
+        #[test]
+        fn with_exact_drawdown() {
+            // α <fn test IncrementalStats::with_exact_drawdown>
+
+            let mut stats = IncrementalStats::with_exact_drawdown();
+            stats.push_values(&[100.0, 120.0, 90.0, 130.0, 117.0]);
+            assert!((0.25 - stats.max_drawdown().unwrap()).abs() < 1e-12);
+            assert_eq!(3, stats.peak_segments.unwrap().len());
+
+            // ω <fn test IncrementalStats::with_exact_drawdown>
+        }
+
+        #[test]
+        fn quantile() {
+            // α <fn test IncrementalStats::quantile>
+
+            let stats = IncrementalStats::from_values(&[1.0, 2.0, 3.0], false);
+            assert_eq!(None, stats.quantile(0.5));
+
+            let mut stats = IncrementalStats::with_quantiles(100.0);
+            stats.push_values(&[1.0, 2.0, 3.0]);
+            assert_eq!(Some(2.0), stats.quantile(0.5));
+
+            // ω <fn test IncrementalStats::quantile>
+        }
+
         #[test]
         fn finalize_median() {
             // α <fn test IncrementalStats::finalize_median>
@@ -1,13 +1,14 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use crate::math::quantile_sketch::Percentiles;
 use ::core::fmt::Display;
 use ::core::fmt::Formatter;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- structs ---
 ////////////////////////////////////////////////////////////////////////////////////
-/// Bundled statistics measures (count, min, max, mean, std_dev).
+/// Bundled statistics measures (count, min, max, mean, std_dev, percentiles and downside measures).
 #[derive(Debug, Default, Copy, Clone, Serialize)]
 pub struct MeasuredStats {
     /// Count of data points included.
@@ -22,6 +23,16 @@ pub struct MeasuredStats {
     pub median: Option<f64>,
     /// Standard deviation.
     pub std_dev: Option<f64>,
+    /// Estimated percentiles, if quantiles are tracked.
+    pub percentiles: Option<Percentiles>,
+    /// Skewness.
+    pub skewness: Option<f64>,
+    /// Excess kurtosis.
+    pub excess_kurtosis: Option<f64>,
+    /// Semi-deviation below the downside target.
+    pub semi_deviation: Option<f64>,
+    /// Maximum drawdown of values as an ordered series.
+    pub max_drawdown: Option<f64>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
@@ -44,7 +55,7 @@ impl Display for MeasuredStats {
 
         write!(
             f,
-            "(N={}, min={}, max={},{} mean={}, SD={})",
+            "(N={}, min={}, max={},{} mean={}, SD={}{}{})",
             self.count,
             format_item(&self.min),
             format_item(&self.max),
@@ -54,7 +65,24 @@ impl Display for MeasuredStats {
                 String::default()
             },
             format_item(&self.mean),
-            format_item(&self.std_dev)
+            format_item(&self.std_dev),
+            if let Some(percentiles) = self.percentiles {
+                format!(
+                    ", p5={:.prec$}, p25={:.prec$}, p75={:.prec$}, p95={:.prec$}",
+                    percentiles.p5, percentiles.p25, percentiles.p75, percentiles.p95
+                )
+            } else {
+                String::default()
+            },
+            [
+                ("skew", self.skewness),
+                ("kurt", self.excess_kurtosis),
+                ("semiSD", self.semi_deviation),
+                ("maxDD", self.max_drawdown),
+            ]
+            .iter()
+            .filter_map(|(label, value)| value.map(|value| format!(", {label}={value:.prec$}")))
+            .collect::<String>()
         )
 
         // ω <fn Display::fmt for MeasuredStats>
@@ -3,9 +3,15 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- mod decls ---
 ////////////////////////////////////////////////////////////////////////////////////
+pub mod correlated_normals;
 pub mod incremental_pearson;
 pub mod incremental_stats;
 pub mod measured_stats;
+pub mod normal_distribution;
+pub mod quantile_sketch;
+pub mod student_t_distribution;
+pub mod tvm;
+pub mod value_at_risk;
 
 // α <mod-def math>
 // ω <mod-def math>
@@ -0,0 +1,410 @@
+//! Cumulative distribution function and its inverse for the normal distribution.
+//!
+//! The _cdf_ is computed from the complementary error function, using the rational
+//! approximations of W. J. Cody (_Rational Chebyshev Approximations for the Error Function_,
+//! 1969) which are accurate to about 1e-16 relative error. Computing `Φ(z)` as
+//! `erfc(-z/√2)/2` keeps that relative accuracy far into the lower tail, where
+//! `(1 + erf(z/√2))/2` would cancel to 0.
+//!
+//! The inverse _cdf_ (quantile) uses algorithm _AS241_ `PPND16` of M. J. Wichura
+//! (_The Percentage Points of the Normal Distribution_, 1988), accurate to about 1e-16.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use std::f64::consts::FRAC_1_SQRT_2;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// `1/√π`
+const FRAC_1_SQRT_PI: f64 = 0.564_189_583_547_756_3;
+
+/// Beyond this `erfc` underflows to 0.
+const ERFC_X_BIG: f64 = 26.543;
+
+/// Cody: numerator of `erf` for `|x| <= 0.46875`.
+const ERF_A: [f64; 5] = [
+    3.161_123_743_870_565_6,
+    1.138_641_541_510_501_6e2,
+    3.774_852_376_853_02e2,
+    3.209_377_589_138_469_5e3,
+    1.857_777_061_846_031_5e-1,
+];
+
+/// Cody: denominator of `erf` for `|x| <= 0.46875`.
+const ERF_B: [f64; 4] = [
+    2.360_129_095_234_412e1,
+    2.440_246_379_344_441_7e2,
+    1.282_616_526_077_372_3e3,
+    2.844_236_833_439_171e3,
+];
+
+/// Cody: numerator of `erfc` for `0.46875 < x <= 4`.
+const ERFC_C: [f64; 9] = [
+    5.641_884_969_886_701e-1,
+    8.883_149_794_388_376,
+    6.611_919_063_714_163e1,
+    2.986_351_381_974_001e2,
+    8.819_522_212_417_69e2,
+    1.712_047_612_634_070_6e3,
+    2.051_078_377_826_071_5e3,
+    1.230_339_354_797_997_2e3,
+    2.153_115_354_744_038_5e-8,
+];
+
+/// Cody: denominator of `erfc` for `0.46875 < x <= 4`.
+const ERFC_D: [f64; 8] = [
+    1.574_492_611_070_983_5e1,
+    1.176_939_508_913_125e2,
+    5.371_811_018_620_099e2,
+    1.621_389_574_566_690_2e3,
+    3.290_799_235_733_459_6e3,
+    4.362_619_090_143_247e3,
+    3.439_367_674_143_721_6e3,
+    1.230_339_354_803_749_4e3,
+];
+
+/// Cody: numerator of `erfc` for `x > 4`.
+const ERFC_P: [f64; 6] = [
+    3.053_266_349_612_323_4e-1,
+    3.603_448_999_498_044_4e-1,
+    1.257_817_261_112_292_5e-1,
+    1.608_378_514_874_228e-2,
+    6.587_491_615_298_378e-4,
+    1.631_538_713_730_209_8e-2,
+];
+
+/// Cody: denominator of `erfc` for `x > 4`.
+const ERFC_Q: [f64; 5] = [
+    2.568_520_192_289_822,
+    1.872_952_849_923_467_3,
+    5.279_051_029_514_284e-1,
+    6.051_834_131_244_132e-2,
+    2.335_204_976_268_691_8e-3,
+];
+
+/// AS241: numerator for `|p - 0.5| <= 0.425`.
+const PPND_A: [f64; 8] = [
+    3.387_132_872_796_366_5,
+    1.331_416_678_917_843_8e2,
+    1.971_590_950_306_551_3e3,
+    1.373_169_376_550_946e4,
+    4.592_195_393_154_987e4,
+    6.726_577_092_700_87e4,
+    3.343_057_558_358_813e4,
+    2.509_080_928_730_122_7e3,
+];
+
+/// AS241: denominator for `|p - 0.5| <= 0.425`.
+const PPND_B: [f64; 8] = [
+    1.0,
+    4.231_333_070_160_091e1,
+    6.871_870_074_920_579e2,
+    5.394_196_021_424_751e3,
+    2.121_379_430_158_659_7e4,
+    3.930_789_580_009_271e4,
+    2.872_908_573_572_194_3e4,
+    5.226_495_278_852_546e3,
+];
+
+/// AS241: numerator for `r = sqrt(-ln(min(p, 1-p))) <= 5`.
+const PPND_C: [f64; 8] = [
+    1.423_437_110_749_683_6,
+    4.630_337_846_156_545,
+    5.769_497_221_460_691,
+    3.647_848_324_763_204_5,
+    1.270_458_252_452_368_4,
+    2.417_807_251_774_506e-1,
+    2.272_384_498_926_918_4e-2,
+    7.745_450_142_783_414e-4,
+];
+
+/// AS241: denominator for `r <= 5`.
+const PPND_D: [f64; 8] = [
+    1.0,
+    2.053_191_626_637_759,
+    1.676_384_830_183_803_8,
+    6.897_673_349_851e-1,
+    1.481_039_764_274_800_8e-1,
+    1.519_866_656_361_645_7e-2,
+    5.475_938_084_995_345e-4,
+    1.050_750_071_644_416_8e-9,
+];
+
+/// AS241: numerator for `r > 5`.
+const PPND_E: [f64; 8] = [
+    6.657_904_643_501_103,
+    5.463_784_911_164_114,
+    1.784_826_539_917_291_3,
+    2.965_605_718_285_049e-1,
+    2.653_218_952_657_612_4e-2,
+    1.242_660_947_388_078_4e-3,
+    2.711_555_568_743_487_6e-5,
+    2.010_334_399_292_288_1e-7,
+];
+
+/// AS241: denominator for `r > 5`.
+const PPND_F: [f64; 8] = [
+    1.0,
+    5.998_322_065_558_88e-1,
+    1.369_298_809_227_358e-1,
+    1.487_536_129_085_061_5e-2,
+    7.868_691_311_456_133e-4,
+    1.846_318_317_510_054_8e-5,
+    1.421_511_758_316_446e-7,
+    2.044_263_103_389_939_7e-15,
+];
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The complementary error function `erfc(x) = 1 - erf(x)`.
+///
+///   * **x** - Value to evaluate at.
+///   * _return_ - `erfc(x)`, in [0, 2].
+pub fn erfc(x: f64) -> f64 {
+    // α <fn erfc>
+
+    let y = x.abs();
+
+    if y <= 0.468_75 {
+        let y_sq = y * y;
+        let (numerator, denominator) = ERF_B.iter().zip(ERF_A.iter()).take(3).fold(
+            (ERF_A[4] * y_sq, y_sq),
+            |(numerator, denominator), (b, a)| ((numerator + a) * y_sq, (denominator + b) * y_sq),
+        );
+        return 1.0 - x * (numerator + ERF_A[3]) / (denominator + ERF_B[3]);
+    }
+
+    let upper_tail = if y >= ERFC_X_BIG {
+        0.0
+    } else {
+        let ratio = if y <= 4.0 {
+            let (numerator, denominator) = ERFC_D
+                .iter()
+                .zip(ERFC_C.iter())
+                .take(7)
+                .fold((ERFC_C[8] * y, y), |(numerator, denominator), (d, c)| {
+                    ((numerator + c) * y, (denominator + d) * y)
+                });
+            (numerator + ERFC_C[7]) / (denominator + ERFC_D[7])
+        } else {
+            let y_sq_inv = 1.0 / (y * y);
+            let (numerator, denominator) = ERFC_Q.iter().zip(ERFC_P.iter()).take(4).fold(
+                (ERFC_P[5] * y_sq_inv, y_sq_inv),
+                |(numerator, denominator), (q, p)| {
+                    ((numerator + p) * y_sq_inv, (denominator + q) * y_sq_inv)
+                },
+            );
+            (FRAC_1_SQRT_PI - y_sq_inv * (numerator + ERFC_P[4]) / (denominator + ERFC_Q[4])) / y
+        };
+
+        // Split `exp(-y²)` to avoid the rounding error of squaring `y`
+        let y_trunc = (y * 16.0).trunc() / 16.0;
+        let del = (y - y_trunc) * (y + y_trunc);
+        (-y_trunc * y_trunc).exp() * (-del).exp() * ratio
+    };
+
+    if x < 0.0 {
+        2.0 - upper_tail
+    } else {
+        upper_tail
+    }
+
+    // ω <fn erfc>
+}
+
+/// Cumulative distribution function of the standard normal, `Φ(z)`.
+///
+///   * **z** - Value to evaluate at.
+///   * _return_ - Probability a standard normal is at most `z`.
+#[inline]
+pub fn standard_normal_cdf(z: f64) -> f64 {
+    // α <fn standard_normal_cdf>
+    0.5 * erfc(-z * FRAC_1_SQRT_2)
+    // ω <fn standard_normal_cdf>
+}
+
+/// Inverse cumulative distribution function of the standard normal, `Φ⁻¹(p)`.
+///
+///   * **p** - Probability.
+///   * _return_ - The `z` with `Φ(z) = p`, `None` if `p` is not in (0, 1).
+pub fn standard_normal_inverse_cdf(p: f64) -> Option<f64> {
+    // α <fn standard_normal_inverse_cdf>
+
+    let rational = |coefficients_n: &[f64; 8], coefficients_d: &[f64; 8], r: f64| {
+        coefficients_n.iter().rev().fold(0.0, |acc, c| acc * r + c)
+            / coefficients_d.iter().rev().fold(0.0, |acc, c| acc * r + c)
+    };
+
+    if p.is_nan() || p <= 0.0 || p >= 1.0 {
+        return None;
+    }
+
+    let q = p - 0.5;
+    if q.abs() <= 0.425 {
+        let r = 0.180_625 - q * q;
+        Some(q * rational(&PPND_A, &PPND_B, r))
+    } else {
+        let r = (-(if q < 0.0 { p } else { 1.0 - p }).ln()).sqrt();
+        let z = if r <= 5.0 {
+            rational(&PPND_C, &PPND_D, r - 1.6)
+        } else {
+            rational(&PPND_E, &PPND_F, r - 5.0)
+        };
+        Some(if q < 0.0 { -z } else { z })
+    }
+
+    // ω <fn standard_normal_inverse_cdf>
+}
+
+/// Cumulative distribution function of a normal.
+///
+///   * **x** - Value to evaluate at.
+///   * **mean** - Mean of the normal.
+///   * **std_dev** - Standard deviation of the normal.
+///   * _return_ - Probability the normal is at most `x`, `None` if `std_dev` is not positive.
+#[inline]
+pub fn normal_cdf(x: f64, mean: f64, std_dev: f64) -> Option<f64> {
+    // α <fn normal_cdf>
+    if std_dev > 0.0 {
+        Some(standard_normal_cdf((x - mean) / std_dev))
+    } else {
+        None
+    }
+    // ω <fn normal_cdf>
+}
+
+/// Quantile (inverse cumulative distribution function) of a normal.
+///
+///   * **p** - Probability.
+///   * **mean** - Mean of the normal.
+///   * **std_dev** - Standard deviation of the normal.
+///   * _return_ - The `x` with `normal_cdf(x) = p`, `None` if `p` not in (0, 1) or `std_dev` not positive.
+#[inline]
+pub fn normal_quantile(p: f64, mean: f64, std_dev: f64) -> Option<f64> {
+    // α <fn normal_quantile>
+    if std_dev > 0.0 {
+        standard_normal_inverse_cdf(p).map(|z| mean + z * std_dev)
+    } else {
+        None
+    }
+    // ω <fn normal_quantile>
+}
+
+/// Unit tests for `normal_distribution`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_erfc() {
+        // α <fn test_erfc>
+
+        let relative_error = |expected: f64, x: f64| ((erfc(x) - expected) / expected).abs();
+
+        for (x, expected) in [
+            (-6.0, 2.0),
+            (-1.5, 1.9661051464753108),
+            (-0.3, 1.3286267594591274),
+            (0.0, 1.0),
+            (0.3, 0.6713732405408726),
+            (0.46875, 0.507386526782062),
+            (0.5, 0.4795001221869535),
+            (1.0, 0.15729920705028513),
+            (2.5, 0.0004069520174449589),
+            (4.0, 1.541725790028002e-08),
+            (4.5, 1.9661604415428873e-10),
+            (10.0, 2.088487583762545e-45),
+        ] {
+            assert!(relative_error(expected, x) < 1e-14, "erfc({x})");
+        }
+        assert_eq!(0.0, erfc(27.0));
+
+        // ω <fn test_erfc>
+    }
+
+    #[test]
+    fn test_standard_normal_cdf() {
+        // α <fn test_standard_normal_cdf>
+
+        assert_eq!(0.5, standard_normal_cdf(0.0));
+        // Rounding of `z/√2` is amplified by `z²` in the relative error of the tail
+        for (z, expected) in [
+            (-8.0, 6.220960574271819e-16),
+            (-3.0, 0.0013498980316300957),
+            (-1.0, 0.15865525393145707),
+            (1.0, 0.8413447460685429),
+            (1.96, 0.9750021048517795),
+        ] {
+            assert!(
+                ((standard_normal_cdf(z) - expected) / expected).abs() < 1e-13,
+                "cdf({z})"
+            );
+        }
+
+        // ω <fn test_standard_normal_cdf>
+    }
+
+    #[test]
+    fn test_standard_normal_inverse_cdf() {
+        // α <fn test_standard_normal_inverse_cdf>
+
+        assert_eq!(Some(0.0), standard_normal_inverse_cdf(0.5));
+        assert_eq!(None, standard_normal_inverse_cdf(0.0));
+        assert_eq!(None, standard_normal_inverse_cdf(1.0));
+        assert_eq!(None, standard_normal_inverse_cdf(f64::NAN));
+
+        for (p, expected) in [
+            (1e-10, -6.361340902404056),
+            (0.001, -3.090232306167813),
+            (0.025, -1.9599639845400538),
+            (0.3, -0.5244005127080407),
+            (0.975, 1.9599639845400536),
+            (0.999999, 4.753424308817089),
+        ] {
+            let z = standard_normal_inverse_cdf(p).unwrap();
+            assert!((z - expected).abs() < 1e-12, "inverse_cdf({p})");
+            assert!(((standard_normal_cdf(z) - p) / p).abs() < 1e-12);
+        }
+
+        // ω <fn test_standard_normal_inverse_cdf>
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        // α <fn test_normal_cdf>
+
+        assert_eq!(Some(0.5), normal_cdf(0.07, 0.07, 0.15));
+        assert!((normal_cdf(-0.08, 0.07, 0.15).unwrap() - 0.15865525393145707).abs() < 1e-15);
+        assert_eq!(None, normal_cdf(0.0, 0.07, 0.0));
+
+        // ω <fn test_normal_cdf>
+    }
+
+    #[test]
+    fn test_normal_quantile() {
+        // α <fn test_normal_quantile>
+
+        assert!((normal_quantile(0.975, 0.07, 0.15).unwrap() - 0.3639945977).abs() < 1e-9);
+        assert_eq!(None, normal_quantile(0.975, 0.07, -0.15));
+        assert_eq!(None, normal_quantile(1.5, 0.07, 0.15));
+
+        // ω <fn test_normal_quantile>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def normal_distribution>
+// ω <mod-def normal_distribution>
@@ -0,0 +1,394 @@
+//! Bounded memory estimation of quantiles from a stream of values.
+//!
+//! Implements the merging _t-digest_ of Dunning and Ertl with the `k1` scale function
+//! `k(q) = δ/(2π)·asin(2q-1)`. Values are buffered and periodically merged into at most
+//! `δ` centroids (`δ` being the _compression_), with centroids near the tails kept small.
+//!
+//! A centroid may span at most `Δq = 2π·sqrt(q(1-q))/δ` of the distribution, so
+//! interpolating within it gives an estimate whose rank error `|F(estimate) - q|` is
+//! bounded by about `π·sqrt(q(1-q))/δ`. With the default compression of 200 that is
+//! within 0.8% of rank at the median and 0.35% at the 5th and 95th percentiles,
+//! regardless of how many values are pushed. The min and max are exact.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use std::f64::consts::PI;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Default compression, bounding the number of centroids.
+pub const DEFAULT_SKETCH_COMPRESSION: f64 = 200.0;
+
+/// Percentiles reported in `MeasuredStats`.
+pub const REPORTED_PERCENTILES: [f64; 5] = [0.05, 0.25, 0.5, 0.75, 0.95];
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A cluster of values summarized by their mean and count.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SketchCentroid {
+    /// Mean of the values in the centroid.
+    pub mean: f64,
+    /// Number of values in the centroid.
+    pub weight: f64,
+}
+
+/// A _t-digest_ quantile sketch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuantileSketch {
+    /// Compression (δ) - larger is more accurate and uses more memory.
+    compression: f64,
+    /// Merged centroids, sorted by mean.
+    centroids: Vec<SketchCentroid>,
+    /// Values pushed since the last merge.
+    buffer: Vec<f64>,
+    /// Count of values pushed.
+    count: usize,
+    /// Min of values pushed.
+    min: f64,
+    /// Max of values pushed.
+    max: f64,
+}
+
+/// Percentiles of a distribution.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize)]
+pub struct Percentiles {
+    /// 5th percentile.
+    pub p5: f64,
+    /// 25th percentile.
+    pub p25: f64,
+    /// 50th percentile (median).
+    pub p50: f64,
+    /// 75th percentile.
+    pub p75: f64,
+    /// 95th percentile.
+    pub p95: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl QuantileSketch {
+    /// Create an empty sketch.
+    ///
+    ///   * **compression** - Compression (δ), e.g. `DEFAULT_SKETCH_COMPRESSION`.
+    ///   * _return_ - The new sketch.
+    pub fn new(compression: f64) -> QuantileSketch {
+        // α <fn QuantileSketch::new>
+
+        QuantileSketch {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::with_capacity(Self::buffer_capacity(compression)),
+            count: 0,
+            min: f64::MAX,
+            max: f64::MIN,
+        }
+
+        // ω <fn QuantileSketch::new>
+    }
+
+    /// Push value into the sketch.
+    ///
+    ///   * **value** - Value to push.
+    #[inline]
+    pub fn push_value(&mut self, value: f64) {
+        // α <fn QuantileSketch::push_value>
+
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() >= Self::buffer_capacity(self.compression) {
+            self.compress();
+        }
+
+        // ω <fn QuantileSketch::push_value>
+    }
+
+    /// Merge buffered values into the centroids.
+    pub fn compress(&mut self) {
+        // α <fn QuantileSketch::compress>
+
+        if !self.buffer.is_empty() {
+            self.centroids = self.merged_centroids();
+            self.buffer.clear();
+        }
+
+        // ω <fn QuantileSketch::compress>
+    }
+
+    /// Merge a sketch accumulated separately into this one.
+    /// The merged centroids obey the same size bound, so the error bound is preserved.
+    ///
+    ///   * **other** - Sketch to merge in.
+    pub fn merge(&mut self, other: &QuantileSketch) {
+        // α <fn QuantileSketch::merge>
+
+        if other.count == 0 {
+            return;
+        }
+
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.centroids.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.centroids = self.merged_centroids();
+        self.buffer.clear();
+
+        // ω <fn QuantileSketch::merge>
+    }
+
+    /// Estimate the value at quantile `q`.
+    ///
+    ///   * **q** - Quantile in [0, 1].
+    ///   * _return_ - Estimated value, `None` if no values pushed.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        // α <fn QuantileSketch::quantile>
+
+        if self.count == 0 {
+            return None;
+        }
+
+        let centroids = if self.buffer.is_empty() {
+            std::borrow::Cow::Borrowed(&self.centroids)
+        } else {
+            std::borrow::Cow::Owned(self.merged_centroids())
+        };
+        let total = self.count as f64;
+        let target = q.clamp(0.0, 1.0) * total;
+
+        // Centroid means are placed at the middle of their weight, anchored by exact min/max
+        let mut prior = (0.0, self.min);
+        let mut cumulative = 0.0;
+        for centroid in centroids.iter() {
+            let center = cumulative + centroid.weight / 2.0;
+            if target < center {
+                let (prior_rank, prior_value) = prior;
+                let fraction = (target - prior_rank) / (center - prior_rank);
+                return Some(prior_value + fraction * (centroid.mean - prior_value));
+            }
+            prior = (center, centroid.mean);
+            cumulative += centroid.weight;
+        }
+
+        let (prior_rank, prior_value) = prior;
+        if total > prior_rank {
+            let fraction = (target - prior_rank) / (total - prior_rank);
+            Some(prior_value + fraction * (self.max - prior_value))
+        } else {
+            Some(self.max)
+        }
+
+        // ω <fn QuantileSketch::quantile>
+    }
+
+    /// The `REPORTED_PERCENTILES`.
+    ///
+    ///   * _return_ - The percentiles, `None` if no values pushed.
+    pub fn percentiles(&self) -> Option<Percentiles> {
+        // α <fn QuantileSketch::percentiles>
+
+        let [p5, p25, p50, p75, p95] = REPORTED_PERCENTILES.map(|q| self.quantile(q));
+        Some(Percentiles {
+            p5: p5?,
+            p25: p25?,
+            p50: p50?,
+            p75: p75?,
+            p95: p95?,
+        })
+
+        // ω <fn QuantileSketch::percentiles>
+    }
+
+    /// Count of values pushed.
+    ///
+    ///   * _return_ - Count of values pushed.
+    #[inline]
+    pub fn count(&self) -> usize {
+        // α <fn QuantileSketch::count>
+        self.count
+        // ω <fn QuantileSketch::count>
+    }
+
+    /// Number of centroids currently held, bounded by the compression.
+    ///
+    ///   * _return_ - Number of merged centroids.
+    #[inline]
+    pub fn centroid_count(&self) -> usize {
+        // α <fn QuantileSketch::centroid_count>
+        self.centroids.len()
+        // ω <fn QuantileSketch::centroid_count>
+    }
+
+    /// Centroids with the buffered values merged in.
+    ///
+    ///   * _return_ - The merged centroids, sorted by mean.
+    fn merged_centroids(&self) -> Vec<SketchCentroid> {
+        // α <fn QuantileSketch::merged_centroids>
+
+        let mut incoming = self
+            .buffer
+            .iter()
+            .map(|&value| SketchCentroid {
+                mean: value,
+                weight: 1.0,
+            })
+            .chain(self.centroids.iter().copied())
+            .collect::<Vec<_>>();
+        incoming.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = incoming.iter().map(|centroid| centroid.weight).sum();
+        let scale = self.compression / (2.0 * PI);
+        let k = |q: f64| scale * (2.0 * q.clamp(0.0, 1.0) - 1.0).asin();
+
+        let mut merged = Vec::with_capacity(self.compression as usize);
+        let mut incoming = incoming.into_iter();
+        let Some(mut current) = incoming.next() else {
+            return merged;
+        };
+        let mut weight_before = 0.0;
+        for centroid in incoming {
+            let q_right = (weight_before + current.weight + centroid.weight) / total;
+            if k(q_right) - k(weight_before / total) <= 1.0 {
+                let weight = current.weight + centroid.weight;
+                current.mean += (centroid.mean - current.mean) * centroid.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_before += current.weight;
+                merged.push(current);
+                current = centroid;
+            }
+        }
+        merged.push(current);
+        merged
+
+        // ω <fn QuantileSketch::merged_centroids>
+    }
+
+    /// Values buffered before merging.
+    ///
+    ///   * **compression** - Compression of the sketch.
+    ///   * _return_ - The buffer capacity.
+    #[inline]
+    fn buffer_capacity(compression: f64) -> usize {
+        // α <fn QuantileSketch::buffer_capacity>
+        (5.0 * compression).ceil() as usize
+        // ω <fn QuantileSketch::buffer_capacity>
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl Default for QuantileSketch {
+    /// A sketch with `DEFAULT_SKETCH_COMPRESSION`.
+    ///
+    ///   * _return_ - The new default instance
+    fn default() -> Self {
+        // α <fn Default::default for QuantileSketch>
+        QuantileSketch::new(DEFAULT_SKETCH_COMPRESSION)
+        // ω <fn Default::default for QuantileSketch>
+    }
+}
+
+/// Unit tests for `quantile_sketch`
+#[cfg(test)]
+pub mod unit_tests {
+
+    /// Test type QuantileSketch
+    mod test_quantile_sketch {
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- module uses ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        use test_log::test;
+
+        ////////////////////////////////////////////////////////////////////////////////////
+        // --- functions ---
+        ////////////////////////////////////////////////////////////////////////////////////
+        #[test]
+        fn quantile() {
+            // α <fn test QuantileSketch::quantile>
+
+            let mut sketch = QuantileSketch::default();
+            assert_eq!(None, sketch.quantile(0.5));
+
+            // Shuffled 0..100_000 so ranks are known exactly
+            let n = 100_000_u64;
+            (0..n).for_each(|i| sketch.push_value(((i * 7_919) % n) as f64));
+            sketch.compress();
+
+            assert_eq!(n as usize, sketch.count());
+            assert!(sketch.centroid_count() <= DEFAULT_SKETCH_COMPRESSION as usize);
+            assert_eq!(Some(0.0), sketch.quantile(0.0));
+            assert_eq!(Some((n - 1) as f64), sketch.quantile(1.0));
+
+            for q in REPORTED_PERCENTILES {
+                let estimate = sketch.quantile(q).unwrap();
+                let rank_error = (estimate / n as f64 - q).abs();
+                let bound = PI * (q * (1.0 - q)).sqrt() / DEFAULT_SKETCH_COMPRESSION;
+                assert!(rank_error <= bound, "q={q} estimate={estimate}");
+            }
+
+            // ω <fn test QuantileSketch::quantile>
+        }
+
+        #[test]
+        fn merge() {
+            // α <fn test QuantileSketch::merge>
+
+            let n = 100_000_u64;
+            let mut shards = (0..4)
+                .map(|_| QuantileSketch::default())
+                .collect::<Vec<_>>();
+            (0..n).for_each(|i| shards[(i % 4) as usize].push_value(((i * 7_919) % n) as f64));
+
+            let mut sketch = QuantileSketch::default();
+            shards.iter().for_each(|shard| sketch.merge(shard));
+
+            assert_eq!(n as usize, sketch.count());
+            for q in REPORTED_PERCENTILES {
+                let estimate = sketch.quantile(q).unwrap();
+                let bound = PI * (q * (1.0 - q)).sqrt() / DEFAULT_SKETCH_COMPRESSION;
+                assert!(
+                    (estimate / n as f64 - q).abs() <= bound,
+                    "q={q} estimate={estimate}"
+                );
+            }
+
+            // ω <fn test QuantileSketch::merge>
+        }
+
+        #[test]
+        fn percentiles() {
+            // α <fn test QuantileSketch::percentiles>
+
+            let mut sketch = QuantileSketch::new(100.0);
+            (1..=5).for_each(|i| sketch.push_value(i as f64));
+            let percentiles = sketch.percentiles().unwrap();
+
+            // Few values are kept exactly, interpolating between them
+            assert_eq!(3.0, percentiles.p50);
+            assert_eq!(1.0, percentiles.p5);
+            assert_eq!(5.0, percentiles.p95);
+            assert!(percentiles.p25 < percentiles.p50 && percentiles.p50 < percentiles.p75);
+
+            // ω <fn test QuantileSketch::percentiles>
+        }
+
+        // α <mod-def test_quantile_sketch>
+        use super::*;
+        // ω <mod-def test_quantile_sketch>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def quantile_sketch>
+// ω <mod-def quantile_sketch>
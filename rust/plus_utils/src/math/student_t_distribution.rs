@@ -0,0 +1,320 @@
+//! Density, cumulative distribution function and its inverse for _Student's t_ distribution.
+//!
+//! The _cdf_ is computed from the regularized incomplete beta function, evaluated by its
+//! continued fraction with the modified Lentz method, and `ln Γ` uses the Lanczos
+//! approximation (g = 7). The inverse _cdf_ has no closed form and is found by Newton
+//! iterations on the _cdf_, safeguarded by bisection, starting at the normal quantile.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::math::normal_distribution::standard_normal_inverse_cdf;
+use std::f64::consts::PI;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Lanczos coefficients for g = 7, n = 9.
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Convergence tolerance of the iterative evaluations.
+const TOLERANCE: f64 = 1e-15;
+
+/// Bound on iterations of the iterative evaluations.
+const MAX_ITERATIONS: usize = 300;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Natural log of the gamma function.
+///
+///   * **x** - Positive value to evaluate at.
+///   * _return_ - `ln Γ(x)`.
+pub fn ln_gamma(x: f64) -> f64 {
+    // α <fn ln_gamma>
+
+    if x < 0.5 {
+        // Reflection: Γ(x)Γ(1-x) = π/sin(πx)
+        (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x)
+    } else {
+        let x = x - 1.0;
+        let t = x + 7.5;
+        let series = LANCZOS_COEFFICIENTS
+            .iter()
+            .enumerate()
+            .skip(1)
+            .fold(LANCZOS_COEFFICIENTS[0], |acc, (i, c)| {
+                acc + c / (x + i as f64)
+            });
+        0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+    }
+
+    // ω <fn ln_gamma>
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`.
+///
+///   * **a** - First shape parameter, positive.
+///   * **b** - Second shape parameter, positive.
+///   * **x** - Value in [0, 1].
+///   * _return_ - `I_x(a, b)`.
+pub fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    // α <fn regularized_incomplete_beta>
+
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+
+    // The continued fraction converges quickly for x < (a+1)/(a+b+2), use symmetry otherwise
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+
+    // ω <fn regularized_incomplete_beta>
+}
+
+/// Probability density function of the standard _Student's t_.
+///
+///   * **t** - Value to evaluate at.
+///   * **degrees_of_freedom** - Degrees of freedom, positive.
+///   * _return_ - The density at `t`.
+pub fn student_t_pdf(t: f64, degrees_of_freedom: f64) -> f64 {
+    // α <fn student_t_pdf>
+
+    let nu = degrees_of_freedom;
+    (ln_gamma((nu + 1.0) / 2.0)
+        - ln_gamma(nu / 2.0)
+        - 0.5 * (nu * PI).ln()
+        - (nu + 1.0) / 2.0 * (1.0 + t * t / nu).ln())
+    .exp()
+
+    // ω <fn student_t_pdf>
+}
+
+/// Cumulative distribution function of the standard _Student's t_.
+///
+///   * **t** - Value to evaluate at.
+///   * **degrees_of_freedom** - Degrees of freedom, positive.
+///   * _return_ - Probability the variable is at most `t`.
+pub fn student_t_cdf(t: f64, degrees_of_freedom: f64) -> f64 {
+    // α <fn student_t_cdf>
+
+    let nu = degrees_of_freedom;
+    let tail = 0.5 * regularized_incomplete_beta(nu / 2.0, 0.5, nu / (nu + t * t));
+    if t > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+
+    // ω <fn student_t_cdf>
+}
+
+/// Inverse cumulative distribution function of the standard _Student's t_.
+///
+///   * **p** - Probability.
+///   * **degrees_of_freedom** - Degrees of freedom, positive.
+///   * _return_ - The `t` with `student_t_cdf(t) = p`, `None` if `p` or `degrees_of_freedom` invalid.
+pub fn student_t_inverse_cdf(p: f64, degrees_of_freedom: f64) -> Option<f64> {
+    // α <fn student_t_inverse_cdf>
+
+    if degrees_of_freedom.is_nan() || degrees_of_freedom <= 0.0 {
+        return None;
+    }
+    let z = standard_normal_inverse_cdf(p)?;
+    if p == 0.5 {
+        return Some(0.0);
+    }
+
+    // Bracket the root - tails of the t are heavier than the normal's
+    let (mut low, mut high) = if z < 0.0 {
+        (2.0 * z, 0.0)
+    } else {
+        (0.0, 2.0 * z)
+    };
+    while student_t_cdf(low, degrees_of_freedom) > p {
+        high = low;
+        low *= 2.0;
+    }
+    while student_t_cdf(high, degrees_of_freedom) < p {
+        low = high;
+        high *= 2.0;
+    }
+
+    let mut t = z.clamp(low, high);
+    for _ in 0..MAX_ITERATIONS {
+        let error = student_t_cdf(t, degrees_of_freedom) - p;
+        if error < 0.0 {
+            low = t;
+        } else {
+            high = t;
+        }
+
+        let newton = t - error / student_t_pdf(t, degrees_of_freedom);
+        let next = if newton > low && newton < high {
+            newton
+        } else {
+            0.5 * (low + high)
+        };
+
+        if (next - t).abs() <= TOLERANCE * t.abs().max(1.0) {
+            return Some(next);
+        }
+        t = next;
+    }
+
+    Some(t)
+
+    // ω <fn student_t_inverse_cdf>
+}
+
+/// Continued fraction of the incomplete beta function, by the modified Lentz method.
+///
+///   * **a** - First shape parameter.
+///   * **b** - Second shape parameter.
+///   * **x** - Value in (0, 1).
+///   * _return_ - Value of the continued fraction.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    // α <fn beta_continued_fraction>
+
+    let tiny = 1e-300;
+    let guard = |value: f64| if value.abs() < tiny { tiny } else { value };
+
+    let mut c = 1.0;
+    let mut d = 1.0 / guard(1.0 - (a + b) * x / (a + 1.0));
+    let mut fraction = d;
+
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        // Even step
+        let numerator = m * (b - m) * x / ((a + m2 - 1.0) * (a + m2));
+        d = 1.0 / guard(1.0 + numerator * d);
+        c = guard(1.0 + numerator / c);
+        fraction *= d * c;
+
+        // Odd step
+        let numerator = -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0));
+        d = 1.0 / guard(1.0 + numerator * d);
+        c = guard(1.0 + numerator / c);
+        let delta = d * c;
+        fraction *= delta;
+
+        if (delta - 1.0).abs() < TOLERANCE {
+            break;
+        }
+    }
+
+    fraction
+
+    // ω <fn beta_continued_fraction>
+}
+
+/// Unit tests for `student_t_distribution`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_ln_gamma() {
+        // α <fn test_ln_gamma>
+        assert!(ln_gamma(1.0).abs() < 1e-14);
+        assert!(ln_gamma(2.0).abs() < 1e-14);
+        assert!((ln_gamma(5.0) - 24f64.ln()).abs() < 1e-13);
+        assert!((ln_gamma(0.5) - PI.sqrt().ln()).abs() < 1e-14);
+        assert!((ln_gamma(0.1) - 2.252712651734206).abs() < 1e-13);
+        // ω <fn test_ln_gamma>
+    }
+
+    #[test]
+    fn test_regularized_incomplete_beta() {
+        // α <fn test_regularized_incomplete_beta>
+        assert_eq!(0.0, regularized_incomplete_beta(2.0, 3.0, 0.0));
+        assert_eq!(1.0, regularized_incomplete_beta(2.0, 3.0, 1.0));
+        // I_x(1, 1) = x and I_x(2, 3) = 6x² - 8x³ + 3x⁴
+        assert!((regularized_incomplete_beta(1.0, 1.0, 0.3) - 0.3).abs() < 1e-14);
+        let x: f64 = 0.4;
+        let expected = 6.0 * x.powi(2) - 8.0 * x.powi(3) + 3.0 * x.powi(4);
+        assert!((regularized_incomplete_beta(2.0, 3.0, x) - expected).abs() < 1e-14);
+        // ω <fn test_regularized_incomplete_beta>
+    }
+
+    #[test]
+    fn test_student_t_pdf() {
+        // α <fn test_student_t_pdf>
+        // One degree of freedom is Cauchy
+        assert!((student_t_pdf(0.0, 1.0) - 1.0 / PI).abs() < 1e-14);
+        assert!((student_t_pdf(1.0, 1.0) - 0.5 / PI).abs() < 1e-14);
+        // Large degrees of freedom approach the normal
+        assert!((student_t_pdf(0.0, 1e7) - 0.3989422804014327).abs() < 1e-7);
+        // ω <fn test_student_t_pdf>
+    }
+
+    #[test]
+    fn test_student_t_cdf() {
+        // α <fn test_student_t_cdf>
+        assert_eq!(0.5, student_t_cdf(0.0, 4.0));
+        assert!((student_t_cdf(1.0, 1.0) - 0.75).abs() < 1e-14);
+        assert!((student_t_cdf(2.570581836, 5.0) - 0.975).abs() < 1e-9);
+        assert!((student_t_cdf(-2.353363435, 3.0) - 0.05).abs() < 1e-9);
+        assert!((student_t_cdf(2.763769458, 10.0) - 0.99).abs() < 1e-9);
+        // ω <fn test_student_t_cdf>
+    }
+
+    #[test]
+    fn test_student_t_inverse_cdf() {
+        // α <fn test_student_t_inverse_cdf>
+
+        assert_eq!(Some(0.0), student_t_inverse_cdf(0.5, 4.0));
+        assert_eq!(None, student_t_inverse_cdf(0.0, 4.0));
+        assert_eq!(None, student_t_inverse_cdf(0.5, 0.0));
+
+        for (p, nu, expected) in [
+            (0.975, 5.0, 2.570581836),
+            (0.05, 3.0, -2.353363435),
+            (0.99, 10.0, 2.763769458),
+            (0.75, 1.0, 1.0),
+        ] {
+            let t = student_t_inverse_cdf(p, nu).unwrap();
+            assert!((t - expected).abs() < 1e-8, "inverse_cdf({p}, {nu}) = {t}");
+        }
+
+        let t = student_t_inverse_cdf(1e-6, 3.0).unwrap();
+        assert!((student_t_cdf(t, 3.0) - 1e-6).abs() < 1e-18);
+
+        // ω <fn test_student_t_inverse_cdf>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def student_t_distribution>
+// ω <mod-def student_t_distribution>
@@ -0,0 +1,345 @@
+//! Time value of money: present and future values, annuities, NPV and IRR.
+//!
+//! Rates are per period as fractions (0.05 is 5%) and compound once per period.
+//! Annuity payments are made at the end of each period (_ordinary annuity_) unless
+//! `due` is set, in which case they are made at the start (_annuity due_). Cash flows
+//! are `(time, value)` pairs with `time` in periods from the valuation date, which may
+//! be fractional - NPV and IRR of irregularly timed flows are the XNPV and XIRR.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Convergence tolerance on the rate found by `internal_rate_of_return`.
+const IRR_TOLERANCE: f64 = 1e-12;
+
+/// Bound on iterations of `internal_rate_of_return`.
+const IRR_MAX_ITERATIONS: usize = 200;
+
+/// Lowest rate searched by `internal_rate_of_return` - just above a total loss.
+const IRR_MIN_RATE: f64 = -0.999_999;
+
+/// Highest rate searched by `internal_rate_of_return`.
+const IRR_MAX_RATE: f64 = 1e6;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Present value of a single amount.
+///
+///   * **future_value** - Amount received after `periods`.
+///   * **rate** - Discount rate per period.
+///   * **periods** - Number of periods until the amount is received.
+///   * _return_ - Value today of `future_value`.
+#[inline]
+pub fn present_value(future_value: f64, rate: f64, periods: f64) -> f64 {
+    // α <fn present_value>
+    future_value / (1.0 + rate).powf(periods)
+    // ω <fn present_value>
+}
+
+/// Future value of a single amount.
+///
+///   * **present_value** - Amount invested today.
+///   * **rate** - Growth rate per period.
+///   * **periods** - Number of periods invested.
+///   * _return_ - Value of `present_value` after `periods`.
+#[inline]
+pub fn future_value(present_value: f64, rate: f64, periods: f64) -> f64 {
+    // α <fn future_value>
+    present_value * (1.0 + rate).powf(periods)
+    // ω <fn future_value>
+}
+
+/// Present value of a level annuity, e.g. the value today of a pension.
+///
+///   * **payment** - Payment each period.
+///   * **rate** - Discount rate per period.
+///   * **periods** - Number of payments.
+///   * **due** - If set payments are at the start of each period, otherwise at the end.
+///   * _return_ - Value today of the payments.
+pub fn annuity_present_value(payment: f64, rate: f64, periods: u32, due: bool) -> f64 {
+    // α <fn annuity_present_value>
+    payment * annuity_present_value_factor(rate, periods, due)
+    // ω <fn annuity_present_value>
+}
+
+/// Future value of a level annuity, e.g. the balance of regular savings.
+///
+///   * **payment** - Payment each period.
+///   * **rate** - Growth rate per period.
+///   * **periods** - Number of payments.
+///   * **due** - If set payments are at the start of each period, otherwise at the end.
+///   * _return_ - Value of the payments at the end of the last period.
+pub fn annuity_future_value(payment: f64, rate: f64, periods: u32, due: bool) -> f64 {
+    // α <fn annuity_future_value>
+    future_value(
+        annuity_present_value(payment, rate, periods, due),
+        rate,
+        periods as f64,
+    )
+    // ω <fn annuity_future_value>
+}
+
+/// Level payment of an annuity with a given present value, e.g. a loan payment or
+/// the income a lump sum can buy.
+///
+///   * **present_value** - Value today of the payments.
+///   * **rate** - Discount rate per period.
+///   * **periods** - Number of payments.
+///   * **due** - If set payments are at the start of each period, otherwise at the end.
+///   * _return_ - Payment each period, `None` if `periods` is 0.
+pub fn annuity_payment(present_value: f64, rate: f64, periods: u32, due: bool) -> Option<f64> {
+    // α <fn annuity_payment>
+    if periods == 0 {
+        None
+    } else {
+        Some(present_value / annuity_present_value_factor(rate, periods, due))
+    }
+    // ω <fn annuity_payment>
+}
+
+/// Level payment required to reach a target, e.g. how much to save per year to reach X.
+///
+///   * **target** - Value required at the end of the last period.
+///   * **rate** - Growth rate per period.
+///   * **periods** - Number of payments.
+///   * **due** - If set payments are at the start of each period, otherwise at the end.
+///   * _return_ - Payment each period, `None` if `periods` is 0.
+pub fn savings_payment(target: f64, rate: f64, periods: u32, due: bool) -> Option<f64> {
+    // α <fn savings_payment>
+    annuity_payment(
+        present_value(target, rate, periods as f64),
+        rate,
+        periods,
+        due,
+    )
+    // ω <fn savings_payment>
+}
+
+/// Net present value of cash flows.
+///
+///   * **rate** - Discount rate per period.
+///   * **flows** - Cash flows as `(time, value)`, `time` in periods from today.
+///   * _return_ - Sum of the discounted values.
+pub fn net_present_value(rate: f64, flows: &[(f64, f64)]) -> f64 {
+    // α <fn net_present_value>
+    flows
+        .iter()
+        .map(|(time, value)| present_value(*value, rate, *time))
+        .sum()
+    // ω <fn net_present_value>
+}
+
+/// Internal rate of return of cash flows - the rate at which their NPV is zero.
+///
+/// Uses Newton iterations on the NPV safeguarded by bisection. When the flows change
+/// sign more than once there may be several rates and the one nearest `guess` tends to
+/// be found.
+///
+///   * **flows** - Cash flows as `(time, value)`, `time` in periods from today.
+///   * **guess** - Starting rate, e.g. 0.1.
+///   * _return_ - The rate, `None` if flows are not of both signs or no root is bracketed.
+pub fn internal_rate_of_return(flows: &[(f64, f64)], guess: f64) -> Option<f64> {
+    // α <fn internal_rate_of_return>
+
+    let has_positive = flows.iter().any(|(_, value)| *value > 0.0);
+    let has_negative = flows.iter().any(|(_, value)| *value < 0.0);
+    if !(has_positive && has_negative) {
+        return None;
+    }
+
+    let npv = |rate: f64| net_present_value(rate, flows);
+    let npv_derivative = |rate: f64| {
+        flows
+            .iter()
+            .map(|(time, value)| -time * value / (1.0 + rate).powf(time + 1.0))
+            .sum::<f64>()
+    };
+
+    // Bracket a sign change, widening out from the guess
+    let mut rate = guess.clamp(IRR_MIN_RATE, IRR_MAX_RATE);
+    let (mut low, mut high) = (IRR_MIN_RATE, IRR_MAX_RATE);
+    let mut step = 0.1;
+    let mut bracketed = false;
+    while !bracketed && step < IRR_MAX_RATE {
+        let candidates = [
+            ((rate - step).max(IRR_MIN_RATE), rate),
+            (rate, (rate + step).min(IRR_MAX_RATE)),
+        ];
+        for (candidate_low, candidate_high) in candidates {
+            if npv(candidate_low).signum() != npv(candidate_high).signum() {
+                (low, high) = (candidate_low, candidate_high);
+                bracketed = true;
+                break;
+            }
+        }
+        step *= 2.0;
+    }
+    if !bracketed {
+        return None;
+    }
+
+    let low_sign = npv(low).signum();
+    rate = rate.clamp(low, high);
+    for _ in 0..IRR_MAX_ITERATIONS {
+        let value = npv(rate);
+        if value == 0.0 {
+            return Some(rate);
+        }
+        if value.signum() == low_sign {
+            low = rate;
+        } else {
+            high = rate;
+        }
+
+        let newton = rate - value / npv_derivative(rate);
+        let next = if newton > low && newton < high {
+            newton
+        } else {
+            0.5 * (low + high)
+        };
+
+        if (next - rate).abs() <= IRR_TOLERANCE * rate.abs().max(1.0) {
+            return Some(next);
+        }
+        rate = next;
+    }
+
+    Some(rate)
+
+    // ω <fn internal_rate_of_return>
+}
+
+/// Present value of 1 paid each period.
+///
+///   * **rate** - Discount rate per period.
+///   * **periods** - Number of payments.
+///   * **due** - If set payments are at the start of each period, otherwise at the end.
+///   * _return_ - The annuity factor.
+fn annuity_present_value_factor(rate: f64, periods: u32, due: bool) -> f64 {
+    // α <fn annuity_present_value_factor>
+
+    let periods_f64 = periods as f64;
+    let factor = if rate == 0.0 {
+        periods_f64
+    } else {
+        (1.0 - (1.0 + rate).powf(-periods_f64)) / rate
+    };
+
+    if due {
+        factor * (1.0 + rate)
+    } else {
+        factor
+    }
+
+    // ω <fn annuity_present_value_factor>
+}
+
+/// Unit tests for `tvm`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_present_value() {
+        // α <fn test_present_value>
+        assert!((present_value(1000.0, 0.05, 10.0) - 613.9132535).abs() < 1e-6);
+        assert_eq!(1000.0, present_value(1000.0, 0.05, 0.0));
+        // ω <fn test_present_value>
+    }
+
+    #[test]
+    fn test_future_value() {
+        // α <fn test_future_value>
+        assert!((future_value(1000.0, 0.05, 10.0) - 1628.8946268).abs() < 1e-6);
+        assert!((present_value(future_value(250.0, 0.07, 3.5), 0.07, 3.5) - 250.0).abs() < 1e-12);
+        // ω <fn test_future_value>
+    }
+
+    #[test]
+    fn test_annuity_present_value() {
+        // α <fn test_annuity_present_value>
+        // 20 years of 10,000 at 4%
+        assert!((annuity_present_value(10000.0, 0.04, 20, false) - 135903.2634).abs() < 1e-4);
+        assert!((annuity_present_value(10000.0, 0.04, 20, true) - 141339.3940).abs() < 1e-4);
+        assert_eq!(200000.0, annuity_present_value(10000.0, 0.0, 20, false));
+        // ω <fn test_annuity_present_value>
+    }
+
+    #[test]
+    fn test_annuity_future_value() {
+        // α <fn test_annuity_future_value>
+        assert!((annuity_future_value(1000.0, 0.05, 10, false) - 12577.8925).abs() < 1e-4);
+        assert!((annuity_future_value(1000.0, 0.05, 10, true) - 13206.7872).abs() < 1e-4);
+        // ω <fn test_annuity_future_value>
+    }
+
+    #[test]
+    fn test_annuity_payment() {
+        // α <fn test_annuity_payment>
+        // 30 year mortgage of 300,000 at 0.5% monthly
+        assert!((annuity_payment(300000.0, 0.005, 360, false).unwrap() - 1798.6516).abs() < 1e-4);
+        assert_eq!(Some(1000.0), annuity_payment(12000.0, 0.0, 12, false));
+        assert_eq!(None, annuity_payment(12000.0, 0.05, 0, false));
+        // ω <fn test_annuity_payment>
+    }
+
+    #[test]
+    fn test_savings_payment() {
+        // α <fn test_savings_payment>
+        let payment = savings_payment(1000000.0, 0.06, 30, false).unwrap();
+        assert!((payment - 12648.9114900).abs() < 1e-6);
+        assert!((annuity_future_value(payment, 0.06, 30, false) - 1000000.0).abs() < 1e-6);
+        // ω <fn test_savings_payment>
+    }
+
+    #[test]
+    fn test_net_present_value() {
+        // α <fn test_net_present_value>
+        let flows = [(0.0, -1000.0), (1.0, 300.0), (2.0, 400.0), (3.0, 500.0)];
+        assert!((net_present_value(0.1, &flows) - (-21.0368)).abs() < 1e-4);
+        assert_eq!(200.0, net_present_value(0.0, &flows));
+        assert_eq!(0.0, net_present_value(0.1, &[]));
+        // ω <fn test_net_present_value>
+    }
+
+    #[test]
+    fn test_internal_rate_of_return() {
+        // α <fn test_internal_rate_of_return>
+
+        let flows = [(0.0, -1000.0), (1.0, 300.0), (2.0, 400.0), (3.0, 500.0)];
+        let irr = internal_rate_of_return(&flows, 0.1).unwrap();
+        assert!((irr - 0.0889633947).abs() < 1e-9);
+        assert!(net_present_value(irr, &flows).abs() < 1e-9);
+
+        // Irregular timing - doubling over 1.5 years
+        let irr = internal_rate_of_return(&[(0.0, -100.0), (1.5, 200.0)], 0.1).unwrap();
+        assert!((irr - (2f64.powf(1.0 / 1.5) - 1.0)).abs() < 1e-10);
+
+        // Losing investment
+        let irr = internal_rate_of_return(&[(0.0, -100.0), (2.0, 81.0)], 0.1).unwrap();
+        assert!((irr - (-0.1)).abs() < 1e-10);
+
+        assert_eq!(
+            None,
+            internal_rate_of_return(&[(0.0, 100.0), (1.0, 50.0)], 0.1)
+        );
+        assert_eq!(None, internal_rate_of_return(&[], 0.1));
+
+        // ω <fn test_internal_rate_of_return>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def tvm>
+// ω <mod-def tvm>
@@ -0,0 +1,176 @@
+//! Value-at-Risk (VaR) and Conditional Value-at-Risk (CVaR, a.k.a. _expected shortfall_).
+//!
+//! At confidence `c` the _VaR_ is the loss that is not exceeded with probability `c` and
+//! the _CVaR_ is the expected loss given the loss is at least the _VaR_. Losses are
+//! positive, so for returns a 95% VaR of 0.17 is the 1-in-20 bad year losing 17%.
+//! For a normal both are computed exactly. For a sample (historical returns or
+//! Monte Carlo outcomes) the worst `ceil((1-c)·n)` values form the tail: the VaR is
+//! the best of them and the CVaR their average.
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::math::normal_distribution::standard_normal_inverse_cdf;
+use std::f64::consts::PI;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// VaR and CVaR at a confidence level.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueAtRisk {
+    /// Confidence level in (0, 1), e.g. 0.95.
+    pub confidence: f64,
+    /// Loss not exceeded with probability `confidence`.
+    pub value_at_risk: f64,
+    /// Expected loss in the tail beyond `value_at_risk`.
+    pub conditional_value_at_risk: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Parametric VaR and CVaR of a normal.
+///
+///   * **confidence** - Confidence level in (0, 1).
+///   * **mean** - Mean of the normal.
+///   * **std_dev** - Standard deviation of the normal.
+///   * _return_ - The VaR and CVaR, `None` if `confidence` not in (0, 1) or `std_dev` not positive.
+pub fn normal_value_at_risk(confidence: f64, mean: f64, std_dev: f64) -> Option<ValueAtRisk> {
+    // α <fn normal_value_at_risk>
+
+    if std_dev <= 0.0 {
+        return None;
+    }
+
+    let tail_probability = 1.0 - confidence;
+    standard_normal_inverse_cdf(tail_probability).map(|z| {
+        let density = (-0.5 * z * z).exp() / (2.0 * PI).sqrt();
+        ValueAtRisk {
+            confidence,
+            value_at_risk: -(mean + z * std_dev),
+            conditional_value_at_risk: -(mean - std_dev * density / tail_probability),
+        }
+    })
+
+    // ω <fn normal_value_at_risk>
+}
+
+/// Historical (empirical) VaR and CVaR of a sample.
+///
+///   * **values** - The sample, e.g. historic returns or Monte Carlo outcomes, in any order.
+///   * **confidence** - Confidence level in (0, 1).
+///   * _return_ - The VaR and CVaR, `None` if no values or `confidence` not in (0, 1).
+pub fn sample_value_at_risk<I>(values: I, confidence: f64) -> Option<ValueAtRisk>
+where
+    I: IntoIterator<Item = f64>,
+{
+    // α <fn sample_value_at_risk>
+
+    if confidence.is_nan() || confidence <= 0.0 || confidence >= 1.0 {
+        return None;
+    }
+
+    let mut values = values.into_iter().collect::<Vec<_>>();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+
+    let tail_count = tail_count(values.len(), confidence);
+    let tail = &values[..tail_count];
+
+    Some(ValueAtRisk {
+        confidence,
+        value_at_risk: -tail[tail_count - 1],
+        conditional_value_at_risk: -tail.iter().sum::<f64>() / tail_count as f64,
+    })
+
+    // ω <fn sample_value_at_risk>
+}
+
+/// Number of values in the tail of a sorted sample at a confidence level.
+///
+///   * **len** - Number of values in the sample.
+///   * **confidence** - Confidence level in (0, 1).
+///   * _return_ - `ceil((1-confidence)·len)`, at least 1.
+#[inline]
+pub fn tail_count(len: usize, confidence: f64) -> usize {
+    // α <fn tail_count>
+    // Round away representation error so e.g. 0.05 of 100 is 5, not 6
+    let tail = ((1.0 - confidence) * len as f64 * 1e9).round() / 1e9;
+    (tail.ceil() as usize).clamp(1, len.max(1))
+    // ω <fn tail_count>
+}
+
+/// Unit tests for `value_at_risk`
+#[cfg(test)]
+pub mod unit_tests {
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- module uses ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    use test_log::test;
+
+    ////////////////////////////////////////////////////////////////////////////////////
+    // --- functions ---
+    ////////////////////////////////////////////////////////////////////////////////////
+    #[test]
+    fn test_normal_value_at_risk() {
+        // α <fn test_normal_value_at_risk>
+
+        // Standard normal 95%: VaR = 1.6448536270, CVaR = φ(1.645)/0.05 = 2.0627128075
+        let standard = normal_value_at_risk(0.95, 0.0, 1.0).unwrap();
+        assert!((standard.value_at_risk - 1.6448536270).abs() < 1e-9);
+        assert!((standard.conditional_value_at_risk - 2.0627128075).abs() < 1e-9);
+
+        // 1-in-20 bad year of returns N(7%, 15%) loses 17.67%
+        let returns = normal_value_at_risk(0.95, 0.07, 0.15).unwrap();
+        assert!((returns.value_at_risk - 0.1767280441).abs() < 1e-9);
+        assert!((returns.conditional_value_at_risk - 0.2394069211).abs() < 1e-9);
+        assert!(returns.conditional_value_at_risk > returns.value_at_risk);
+
+        assert_eq!(None, normal_value_at_risk(0.95, 0.07, 0.0));
+        assert_eq!(None, normal_value_at_risk(1.0, 0.07, 0.15));
+
+        // ω <fn test_normal_value_at_risk>
+    }
+
+    #[test]
+    fn test_sample_value_at_risk() {
+        // α <fn test_sample_value_at_risk>
+
+        // Returns -0.50, -0.49, ... 0.49
+        let values = (0..100).rev().map(|i| (i as f64 - 50.0) / 100.0);
+        let risk = sample_value_at_risk(values, 0.95).unwrap();
+        assert!((risk.value_at_risk - 0.46).abs() < 1e-12);
+        assert!((risk.conditional_value_at_risk - 0.48).abs() < 1e-12);
+
+        // Fewer values than one in the tail - tail is the worst value
+        let risk = sample_value_at_risk([0.1, -0.2, 0.05], 0.99).unwrap();
+        assert_eq!(0.2, risk.value_at_risk);
+        assert_eq!(0.2, risk.conditional_value_at_risk);
+
+        assert_eq!(None, sample_value_at_risk([], 0.95));
+        assert_eq!(None, sample_value_at_risk([0.1], 1.5));
+
+        // ω <fn test_sample_value_at_risk>
+    }
+
+    #[test]
+    fn test_tail_count() {
+        // α <fn test_tail_count>
+        assert_eq!(5, tail_count(100, 0.95));
+        assert_eq!(6, tail_count(101, 0.95));
+        assert_eq!(1, tail_count(10, 0.99));
+        assert_eq!(1, tail_count(0, 0.95));
+        // ω <fn test_tail_count>
+    }
+
+    // α <mod-def unit_tests>
+    use super::*;
+    // ω <mod-def unit_tests>
+}
+
+// α <mod-def value_at_risk>
+// ω <mod-def value_at_risk>
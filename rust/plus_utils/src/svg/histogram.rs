@@ -7,13 +7,14 @@ use crate::IncrementalStats;
 use crate::SvgArea;
 use crate::SvgDim;
 use crate::SvgPoint;
+use crate::ValueAtRisk;
 use std::collections::HashMap;
 use std::ops::RangeInclusive;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- enums ---
 ////////////////////////////////////////////////////////////////////////////////////
-/// Enumerates the quartiles, min, max, and mean
+/// Enumerates the quartiles, min, max, mean and value at risk
 #[derive(Debug, Copy, Clone)]
 pub enum DescriptivePoint {
     /// Minimum value point
@@ -28,6 +29,8 @@ pub enum DescriptivePoint {
     Max,
     /// Point closest to the arithmetic mean
     Mean,
+    /// Point at the _historical_ value at risk for the confidence level (e.g. 0.95)
+    ValueAtRisk(f64),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
@@ -283,6 +286,9 @@ impl HistogramPlot {
                     Err(i) => i,
                 }
             }
+            DescriptivePoint::ValueAtRisk(confidence) => {
+                crate::math::value_at_risk::tail_count(len, confidence) - 1
+            }
         };
 
         (position as u32, &self.sorted_points[position])
@@ -290,6 +296,19 @@ impl HistogramPlot {
         // ω <fn HistogramPlot::get_descriptive_point>
     }
 
+    /// Get the _historical_ VaR and CVaR of the plotted values.
+    ///
+    ///   * **confidence** - Confidence level in (0, 1).
+    ///   * _return_ - The VaR and CVaR, `None` if no values plotted.
+    pub fn get_value_at_risk(&self, confidence: f64) -> Option<ValueAtRisk> {
+        // α <fn HistogramPlot::get_value_at_risk>
+        crate::sample_value_at_risk(
+            self.sorted_points.iter().map(|plot_point| plot_point.value),
+            confidence,
+        )
+        // ω <fn HistogramPlot::get_value_at_risk>
+    }
+
     /// Get two shading areas, one for the row of the selected item and one for the column(bin).
     /// The idea is to show the selection by styling a background row and column rectangle that
     /// intersect at the selected point.
@@ -521,6 +540,7 @@ pub mod unit_tests {
         ////////////////////////////////////////////////////////////////////////////////////
         // --- module uses ---
         ////////////////////////////////////////////////////////////////////////////////////
+        use crate::sample_value_at_risk;
         use test_log::test;
 
         ////////////////////////////////////////////////////////////////////////////////////
@@ -536,10 +556,70 @@ pub mod unit_tests {
         #[test]
         fn get_descriptive_point() {
             // α <fn test HistogramPlot::get_descriptive_point>
-            todo!("Test get_descriptive_point")
+
+            let histogram_plot = sample_plot();
+            assert_eq!(
+                0,
+                histogram_plot
+                    .get_descriptive_point(DescriptivePoint::Min)
+                    .0
+            );
+            assert_eq!(
+                19,
+                histogram_plot
+                    .get_descriptive_point(DescriptivePoint::Max)
+                    .0
+            );
+
+            // The point at the VaR is the last of the tail, the VaR its loss
+            for (confidence, position) in [(0.5, 9), (0.9, 1), (0.95, 0), (0.999, 0)] {
+                let (point_position, plot_point) =
+                    histogram_plot.get_descriptive_point(DescriptivePoint::ValueAtRisk(confidence));
+                assert_eq!(position, point_position);
+                assert_eq!(
+                    histogram_plot
+                        .get_value_at_risk(confidence)
+                        .unwrap()
+                        .value_at_risk,
+                    -plot_point.value
+                );
+            }
+
             // ω <fn test HistogramPlot::get_descriptive_point>
         }
 
+        #[test]
+        fn get_value_at_risk() {
+            // α <fn test HistogramPlot::get_value_at_risk>
+
+            let histogram_plot = sample_plot();
+            let values = (-10..10).map(|value| value as f64);
+            for confidence in [0.5, 0.9, 0.95, 0.999] {
+                assert_eq!(
+                    sample_value_at_risk(values.clone(), confidence),
+                    histogram_plot.get_value_at_risk(confidence)
+                );
+            }
+
+            // Losses of -10 and -9 in the 10% tail
+            let value_at_risk = histogram_plot.get_value_at_risk(0.9).unwrap();
+            assert_eq!(9.0, value_at_risk.value_at_risk);
+            assert_eq!(9.5, value_at_risk.conditional_value_at_risk);
+
+            // Losses of -10 through -1 in the 50% tail
+            let value_at_risk = histogram_plot.get_value_at_risk(0.5).unwrap();
+            assert_eq!(1.0, value_at_risk.value_at_risk);
+            assert_eq!(5.5, value_at_risk.conditional_value_at_risk);
+
+            assert_eq!(None, histogram_plot.get_value_at_risk(1.0));
+            assert_eq!(
+                None,
+                HistogramPlot::new(HistogramSpans::with_defaults(), 10).get_value_at_risk(0.95)
+            );
+
+            // ω <fn test HistogramPlot::get_value_at_risk>
+        }
+
         #[test]
         fn get_selector_areas() {
             // α <fn test HistogramPlot::get_selector_areas>
@@ -582,11 +662,23 @@ pub mod unit_tests {
             // ω <fn test HistogramPlot::place_points>
         }
 
+        /// Plot of the values -10 through 9.
+        fn sample_plot() -> HistogramPlot {
+            let entries = (-10..10)
+                .map(|value| HistogramEntry::new((value + 10) as u32, value as f64))
+                .collect::<Vec<_>>();
+            let mut histogram_plot = HistogramPlot::new(HistogramSpans::with_defaults(), 10);
+            histogram_plot.add_sorted_values(entries.iter());
+            histogram_plot
+        }
+
         // α <mod-def test_histogram_plot>
+        use super::*;
         // ω <mod-def test_histogram_plot>
     }
 
     // α <mod-def unit_tests>
+    use super::*;
     // ω <mod-def unit_tests>
 }
 